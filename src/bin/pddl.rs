@@ -0,0 +1,173 @@
+//! Command-line tools for PDDL files.
+
+use pddl::format::{format, FormatOptions, SectionOrder};
+use std::io::{Read, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: pddl fmt [OPTIONS] [FILES]...
+
+Formats PDDL files in place. Reads from standard input and writes to
+standard output if no files are given.
+
+Options:
+  --check              Report unformatted files instead of rewriting them
+  --indent <N>         Number of spaces per indentation level [default: 2]
+  --line-width <N>     Maximum line width [default: 80]
+  --sort-sections      Sort sections into the order given by the PDDL BNF
+  -h, --help           Print this help";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("fmt") => match FmtArgs::parse(&args[1..]) {
+            Ok(Some(args)) => args.run(),
+            Ok(None) => {
+                println!("{USAGE}");
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("error: {e}\n\n{USAGE}");
+                ExitCode::from(2)
+            }
+        },
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+struct FmtArgs {
+    check: bool,
+    options: FormatOptions,
+    files: Vec<String>,
+}
+
+impl FmtArgs {
+    /// Parses the arguments of the `fmt` command; returns `None` if help was requested.
+    fn parse(args: &[String]) -> Result<Option<Self>, String> {
+        let mut result = FmtArgs {
+            check: false,
+            options: FormatOptions::default(),
+            files: Vec::new(),
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--check" => result.check = true,
+                "--sort-sections" => {
+                    result.options.section_order = SectionOrder::Sorted;
+                }
+                "--indent" => result.options.indent = Self::number(arg, args.next())?,
+                "--line-width" => result.options.line_width = Self::number(arg, args.next())?,
+                flag if flag.starts_with('-') && flag != "-" => {
+                    return Err(format!("unknown option '{flag}'"));
+                }
+                file => result.files.push(file.to_string()),
+            }
+        }
+
+        Ok(Some(result))
+    }
+
+    fn number(flag: &str, value: Option<&String>) -> Result<usize, String> {
+        let value = value.ok_or_else(|| format!("missing value for '{flag}'"))?;
+        value
+            .parse()
+            .map_err(|_| format!("invalid value '{value}' for '{flag}'"))
+    }
+
+    fn run(&self) -> ExitCode {
+        if self.files.is_empty() {
+            return self.run_stdin();
+        }
+
+        let mut status = ExitCode::SUCCESS;
+        for file in &self.files {
+            let result = std::fs::read_to_string(file)
+                .map_err(|e| e.to_string())
+                .and_then(|input| Ok((format_checked(&input, &self.options)?, input)));
+
+            match result {
+                Ok((formatted, input)) if formatted == input => {}
+                Ok(_) if self.check => {
+                    println!("{file}: not formatted");
+                    status = ExitCode::FAILURE;
+                }
+                Ok((formatted, _)) => {
+                    if let Err(e) = std::fs::write(file, formatted) {
+                        eprintln!("{file}: {e}");
+                        status = ExitCode::from(2);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{file}: {e}");
+                    status = ExitCode::from(2);
+                }
+            }
+        }
+        status
+    }
+
+    fn run_stdin(&self) -> ExitCode {
+        let mut input = String::new();
+        if let Err(e) = std::io::stdin().read_to_string(&mut input) {
+            eprintln!("<stdin>: {e}");
+            return ExitCode::from(2);
+        }
+
+        match format_checked(&input, &self.options) {
+            Ok(formatted) if self.check => {
+                if formatted == input {
+                    ExitCode::SUCCESS
+                } else {
+                    println!("<stdin>: not formatted");
+                    ExitCode::FAILURE
+                }
+            }
+            Ok(formatted) => {
+                if let Err(e) = std::io::stdout().write_all(formatted.as_bytes()) {
+                    eprintln!("<stdout>: {e}");
+                    return ExitCode::from(2);
+                }
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("<stdin>: {e}");
+                ExitCode::from(2)
+            }
+        }
+    }
+}
+
+/// Formats the input and, if it is a valid domain or problem, ensures that the
+/// formatted output still describes the same domain or problem.
+fn format_checked(input: &str, options: &FormatOptions) -> Result<String, String> {
+    let formatted = format(input, options).map_err(|e| e.to_string())?;
+
+    #[cfg(feature = "parser")]
+    {
+        use pddl::{Domain, Parser, Problem};
+
+        let unchanged = if let Ok(domain) = Domain::from_str(input) {
+            Domain::from_str(&formatted).map_or(false, |formatted| formatted == domain)
+        } else if let Ok(problem) = Problem::from_str(input) {
+            Problem::from_str(&formatted).map_or(false, |formatted| formatted == problem)
+        } else {
+            true
+        };
+
+        if !unchanged {
+            return Err("formatting would change the meaning of the file".to_string());
+        }
+    }
+
+    Ok(formatted)
+}
@@ -0,0 +1,619 @@
+//! Compiles away conditional effects of actions.
+
+use crate::compile::{action_names, add_requirement, remove_requirement, FreshNames};
+use crate::types::{
+    ActionDefinition, AtomicFormula, AtomicFormulaSkeleton, CEffect, Domain,
+    DurativeActionDefinition, DurativeActionGoalDefinition, Effects, GoalDefinition, InitElement,
+    Literal, PEffect, PreconditionGoalDefinition, PreconditionGoalDefinitions, Predicate,
+    PrefTimedGD, Problem, Requirement, StructureDef, StructureDefs, Term, TimeSpecifier, TimedGD,
+    TypedVariables,
+};
+use crate::validate::{infer_problem_requirements, infer_requirements};
+
+/// Replaces each action with conditional effects by one action per combination of
+/// effect conditions.
+///
+/// For an action with `k` conditional effects, up to `2^k` actions named `<action>-1`,
+/// `<action>-2` and so on are created, one per subset of the conditions. Each of them
+/// requires the conditions of its subset to hold and all other conditions to be false,
+/// and has the unconditional effects along with the effects of its subset. This compilation
+/// is exact, but the number of actions grows exponentially with the number of conditional
+/// effects.
+///
+/// Conditional effects nested in universally quantified effects are not compiled, so such
+/// actions are kept as-is; see [`expand_quantifiers`](crate::compile::expand_quantifiers)
+/// to remove these quantifiers first. Durative actions are not modified either.
+///
+/// [`:conditional-effects`](Requirement::ConditionalEffects) is removed from the requirements
+/// unless conditional effects remain. Since the negated conditions may require
+/// [`:negative-preconditions`](Requirement::NegativePreconditions),
+/// [`:disjunctive-preconditions`](Requirement::DisjunctivePreconditions) or, for quantified
+/// conditions, [`:existential-preconditions`](Requirement::ExistentialPreconditions) and
+/// [`:universal-preconditions`](Requirement::UniversalPreconditions), these are added
+/// as needed; see [`split_disjunctive_preconditions`](crate::compile::split_disjunctive_preconditions)
+/// to remove the latter again.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser};
+/// use pddl::compile::split_conditional_effects;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :conditional-effects)
+///     (:predicates (p) (q) (r))
+///     (:action a
+///         :parameters ()
+///         :precondition (p)
+///         :effect (and (not (p)) (when (q) (r)))))"#).unwrap();
+///
+/// let domain = split_conditional_effects(domain);
+/// let actions: Vec<_> = domain.structure().iter().map(ToString::to_string).collect();
+/// assert_eq!(actions, [
+///     "(:action a-1\n  :parameters ()\n  :precondition (and (p) (not (q)))\n  :effect (not (p)))",
+///     "(:action a-2\n  :parameters ()\n  :precondition (and (p) (q))\n  :effect (and (not (p)) (r)))",
+/// ]);
+/// ```
+pub fn split_conditional_effects(mut domain: Domain) -> Domain {
+    let mut names = FreshNames::new(action_names(&domain));
+
+    let mut structure = Vec::with_capacity(domain.structure().len());
+    for def in std::mem::take(&mut domain.structure).0 {
+        let StructureDef::Action(action) = def else {
+            structure.push(def);
+            continue;
+        };
+        let Some(effects) = SplitEffects::new(&action) else {
+            structure.push(StructureDef::Action(action));
+            continue;
+        };
+
+        let negated: Vec<_> = effects
+            .conditional
+            .iter()
+            .map(|(condition, _)| GoalDefinition::new_not(condition.clone()).to_nnf())
+            .collect();
+
+        let mut index = 0;
+        for subset in 0..1usize << effects.conditional.len() {
+            let mut precondition = action.precondition().clone().0;
+            let mut effect = effects.unconditional.clone();
+            for (i, (condition, conditional)) in effects.conditional.iter().enumerate() {
+                if subset & (1 << i) != 0 {
+                    precondition.push(goal(condition.clone()));
+                    effect.extend(conditional.iter().cloned().map(CEffect::Effect));
+                } else {
+                    precondition.push(goal(negated[i].clone()));
+                }
+            }
+
+            let precondition = PreconditionGoalDefinitions::new(precondition).simplify();
+            if precondition.iter().any(is_false) {
+                continue;
+            }
+
+            index += 1;
+            structure.push(StructureDef::Action(ActionDefinition {
+                symbol: names.fresh(&format!("{}-{index}", action.symbol())).into(),
+                parameters: action.parameters().clone(),
+                precondition,
+                effect: Some(Effects::new_and(effect)),
+                span: action.span,
+            }));
+        }
+    }
+    domain.structure = StructureDefs::new(structure);
+
+    update_requirements(&mut domain);
+    domain
+}
+
+/// Replaces the conditional effects of each action by a sequence of auxiliary actions that
+/// first evaluate all effect conditions and then apply the effects.
+///
+/// A new `normal-mode` predicate holds initially and is required by every action. Applying
+/// an action with `k` conditional effects ends the normal mode and starts a fixed sequence
+/// of auxiliary actions, each of which has the same parameters as the original action:
+///
+/// - `<action>-when-<i>` or `<action>-unless-<i>` evaluate the `i`-th condition and record
+///   the outcome in `<action>-fired-<i>` or `<action>-unfired-<i>`,
+/// - `<action>-delete-<i>` or `<action>-keep-<i>` then apply the negative effects of the
+///   `i`-th conditional effect, depending on its outcome,
+/// - `<action>-add-<i>` or `<action>-skip-<i>` finally apply its other effects, the last
+///   one returning to the normal mode.
+///
+/// The progress through the sequence is tracked by `<action>-stage-<j>` predicates. The
+/// unconditional negative effects are applied once all conditions are evaluated, and the
+/// other unconditional effects at the very end, so that all conditions are evaluated in
+/// the state before the action and negative effects precede positive ones. Numeric
+/// effects are applied one after another. The number of actions and predicates grows
+/// linearly with the number of conditional effects, while plans become longer.
+///
+/// `(normal-mode)` is added to the initial state and the goal of the `problem`, so that
+/// plans cannot end in the middle of a sequence. Names receive a numeric suffix if they
+/// are already taken.
+///
+/// Durative actions are gated on `(normal-mode)` through `at start` and `at end`
+/// conditions, so that they neither start nor end in the middle of a sequence and never
+/// see or change a half-applied state. A sequence may still run while a durative action
+/// is executing, just like the compiled action could.
+///
+/// As for [`split_conditional_effects`], actions with conditional effects nested in
+/// universally quantified effects are kept as-is, and the requirements are updated
+/// accordingly. If no action has conditional effects to compile, the domain and problem
+/// are returned unchanged.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::compile::sequence_conditional_effects;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :conditional-effects)
+///     (:predicates (p ?x) (q ?x))
+///     (:action a
+///         :parameters (?x)
+///         :precondition (p ?x)
+///         :effect (when (q ?x) (not (p ?x)))))"#).unwrap();
+/// let problem = Problem::from_str(r#"(define (problem p)
+///     (:domain d)
+///     (:objects o)
+///     (:init (p o))
+///     (:goal (q o)))"#).unwrap();
+///
+/// let (domain, problem) = sequence_conditional_effects(domain, problem);
+/// assert_eq!(domain.predicates().len(), 8);
+/// assert_eq!(domain.structure().len(), 7);
+/// assert_eq!(problem.goal().to_string(), "(:goal (and (q o) (normal-mode)))");
+/// ```
+pub fn sequence_conditional_effects(mut domain: Domain, mut problem: Problem) -> (Domain, Problem) {
+    let compiled = domain.structure().iter().any(
+        |def| matches!(def, StructureDef::Action(action) if SplitEffects::new(action).is_some()),
+    );
+    if !compiled {
+        return (domain, problem);
+    }
+
+    let mut predicates =
+        FreshNames::new(domain.predicates().iter().map(AtomicFormulaSkeleton::name));
+    let mut actions = FreshNames::new(action_names(&domain));
+    let normal_mode_predicate = Predicate::from(predicates.fresh("normal-mode"));
+    let normal_mode = AtomicFormula::new_predicate(normal_mode_predicate.clone(), []);
+
+    let mut structure = Vec::with_capacity(domain.structure().len());
+    for def in std::mem::take(&mut domain.structure).0 {
+        let mut action = match def {
+            StructureDef::Action(action) => action,
+            StructureDef::DurativeAction(mut action) => {
+                gate_durative_action(&mut action, &normal_mode);
+                structure.push(StructureDef::DurativeAction(action));
+                continue;
+            }
+            StructureDef::Derived(_) => {
+                structure.push(def);
+                continue;
+            }
+        };
+        action.precondition.0.push(goal(holds(&normal_mode)));
+        let Some(effects) = SplitEffects::new(&action) else {
+            structure.push(StructureDef::Action(action));
+            continue;
+        };
+
+        let (skeletons, sequence) = sequence(
+            &action,
+            &effects,
+            &normal_mode,
+            &mut predicates,
+            &mut actions,
+        );
+        domain.predicates.0.extend(skeletons);
+        structure.extend(sequence.into_iter().map(StructureDef::Action));
+    }
+    domain.structure = StructureDefs::new(structure);
+
+    domain.predicates.0.push(AtomicFormulaSkeleton::new(
+        normal_mode_predicate.clone(),
+        TypedVariables::default(),
+    ));
+    let fact = AtomicFormula::new_predicate(normal_mode_predicate, []);
+    problem
+        .init
+        .0
+        .push(InitElement::Literal(Literal::new(fact)));
+    problem.goal.0 .0.push(goal(holds(&normal_mode)));
+
+    update_requirements(&mut domain);
+    if !infer_problem_requirements(&problem).contains(&Requirement::ConditionalEffects) {
+        problem.requires =
+            remove_requirement(problem.requirements(), Requirement::ConditionalEffects);
+    }
+    (domain, problem)
+}
+
+/// Requires `normal_mode` to hold at the start and at the end of the durative `action`.
+fn gate_durative_action(action: &mut DurativeActionDefinition, normal_mode: &AtomicFormula<Term>) {
+    let mut condition: Vec<_> = [TimeSpecifier::Start, TimeSpecifier::End]
+        .into_iter()
+        .map(|time| {
+            DurativeActionGoalDefinition::new_timed(PrefTimedGD::new_required(TimedGD::new_at(
+                time,
+                holds(normal_mode),
+            )))
+        })
+        .collect();
+    match action.condition.take() {
+        Some(DurativeActionGoalDefinition::And(gds)) => condition.extend(gds),
+        Some(gd) => condition.push(gd),
+        None => {}
+    }
+    action.condition = Some(DurativeActionGoalDefinition::new_and(condition));
+}
+
+/// Builds the auxiliary predicates and the sequence of actions replacing the `action`,
+/// see [`sequence_conditional_effects`].
+fn sequence(
+    action: &ActionDefinition,
+    effects: &SplitEffects,
+    normal_mode: &AtomicFormula<Term>,
+    predicates: &mut FreshNames,
+    actions: &mut FreshNames,
+) -> (Vec<AtomicFormulaSkeleton>, Vec<ActionDefinition>) {
+    let symbol = action.symbol();
+    let arguments: Vec<_> = action
+        .parameters()
+        .iter()
+        .map(|parameter| Term::Variable(parameter.value().clone()))
+        .collect();
+
+    let mut skeletons = Vec::new();
+    let mut declare = |name: String| {
+        let name = predicates.fresh(&name);
+        skeletons.push(AtomicFormulaSkeleton::new(
+            name.clone().into(),
+            action.parameters().clone(),
+        ));
+        AtomicFormula::new_predicate(name.into(), arguments.iter().cloned())
+    };
+
+    let k = effects.conditional.len();
+    let mut stages: Vec<_> = (1..=3 * k)
+        .map(|j| declare(format!("{symbol}-stage-{j}")))
+        .collect();
+    let fired: Vec<_> = (1..=k)
+        .map(|i| declare(format!("{symbol}-fired-{i}")))
+        .collect();
+    let unfired: Vec<_> = (1..=k)
+        .map(|i| declare(format!("{symbol}-unfired-{i}")))
+        .collect();
+    stages.push(normal_mode.clone());
+
+    let (deletes, others): (Vec<_>, Vec<_>) = effects
+        .unconditional
+        .iter()
+        .cloned()
+        .partition(|effect| matches!(effect, CEffect::Effect(PEffect::NotAtomicFormula(_))));
+
+    let mut sequence = vec![ActionDefinition {
+        symbol: symbol.clone(),
+        parameters: action.parameters().clone(),
+        precondition: action.precondition().clone(),
+        effect: Some(Effects::new_and(vec![delete(normal_mode), add(&stages[0])])),
+        span: action.span,
+    }];
+    let mut push = |name: String, conditions: [GoalDefinition; 2], effect: Vec<CEffect>| {
+        sequence.push(ActionDefinition {
+            symbol: actions.fresh(&name).into(),
+            parameters: action.parameters().clone(),
+            precondition: PreconditionGoalDefinitions::new(conditions.map(goal).to_vec())
+                .simplify(),
+            effect: Some(Effects::new_and(effect)),
+            span: action.span,
+        })
+    };
+
+    // Evaluate all conditions in the state before the action.
+    for (i, (condition, _)) in effects.conditional.iter().enumerate() {
+        let negated = GoalDefinition::new_not(condition.clone()).to_nnf();
+        for (name, condition, outcome) in [
+            ("when", condition.clone(), &fired[i]),
+            ("unless", negated, &unfired[i]),
+        ] {
+            let mut effect = vec![delete(&stages[i]), add(&stages[i + 1]), add(outcome)];
+            if i + 1 == k {
+                effect.extend(deletes.iter().cloned());
+            }
+            let name = format!("{symbol}-{name}-{}", i + 1);
+            push(name, [holds(&stages[i]), condition], effect);
+        }
+    }
+
+    // Apply the negative effects first, and then all others.
+    let negative = |effect: &PEffect| matches!(effect, PEffect::NotAtomicFormula(_));
+    for (i, (_, conditional)) in effects.conditional.iter().enumerate() {
+        let (stage, next) = (&stages[k + i], &stages[k + i + 1]);
+        let deletes = conditional.iter().filter(|effect| negative(effect));
+        let mut effect: Vec<_> = deletes.cloned().map(CEffect::Effect).collect();
+        effect.extend([delete(stage), add(next)]);
+        let name = format!("{symbol}-delete-{}", i + 1);
+        push(name, [holds(stage), holds(&fired[i])], effect);
+
+        let name = format!("{symbol}-keep-{}", i + 1);
+        let effect = vec![delete(stage), add(next)];
+        push(name, [holds(stage), holds(&unfired[i])], effect);
+    }
+    for (i, (_, conditional)) in effects.conditional.iter().enumerate() {
+        let (stage, next) = (&stages[2 * k + i], &stages[2 * k + i + 1]);
+        let adds = conditional.iter().filter(|effect| !negative(effect));
+        for (name, outcome, effect) in [
+            (
+                "add",
+                &fired[i],
+                adds.cloned().map(CEffect::Effect).collect(),
+            ),
+            ("skip", &unfired[i], Vec::new()),
+        ] {
+            let mut effect = effect;
+            effect.extend([delete(stage), delete(outcome), add(next)]);
+            if i + 1 == k {
+                effect.extend(others.iter().cloned());
+            }
+            let name = format!("{symbol}-{name}-{}", i + 1);
+            push(name, [holds(stage), holds(outcome)], effect);
+        }
+    }
+
+    (skeletons, sequence)
+}
+
+/// The unconditional and conditional effects of an action.
+struct SplitEffects {
+    unconditional: Vec<CEffect>,
+    conditional: Vec<(GoalDefinition, Vec<PEffect>)>,
+}
+
+impl SplitEffects {
+    /// Splits the effects of the `action`, or returns `None` if it has no conditional
+    /// effects or conditional effects nested in universally quantified effects.
+    fn new(action: &ActionDefinition) -> Option<Self> {
+        let mut effects = Self {
+            unconditional: Vec::new(),
+            conditional: Vec::new(),
+        };
+        for effect in action.effect().iter().flat_map(|effects| effects.iter()) {
+            match effect {
+                CEffect::When(when) => effects.conditional.push((
+                    when.condition.clone(),
+                    when.effect.clone().into_iter().collect(),
+                )),
+                CEffect::Forall(forall) if has_conditional_effects(&forall.effects) => return None,
+                effect => effects.unconditional.push(effect.clone()),
+            }
+        }
+        (!effects.conditional.is_empty()).then_some(effects)
+    }
+}
+
+fn has_conditional_effects(effects: &Effects) -> bool {
+    effects.iter().any(|effect| match effect {
+        CEffect::When(_) => true,
+        CEffect::Forall(forall) => has_conditional_effects(&forall.effects),
+        CEffect::Effect(_) => false,
+    })
+}
+
+fn holds(atom: &AtomicFormula<Term>) -> GoalDefinition {
+    GoalDefinition::new_atomic_formula(atom.clone())
+}
+
+fn add(atom: &AtomicFormula<Term>) -> CEffect {
+    CEffect::Effect(PEffect::new(atom.clone()))
+}
+
+fn delete(atom: &AtomicFormula<Term>) -> CEffect {
+    CEffect::Effect(PEffect::new_not(atom.clone()))
+}
+
+fn goal(gd: GoalDefinition) -> PreconditionGoalDefinition {
+    PreconditionGoalDefinition::new_preference(gd.into())
+}
+
+fn is_false(gd: &PreconditionGoalDefinition) -> bool {
+    *gd == goal(GoalDefinition::Or(Vec::new()))
+}
+
+/// Removes [`:conditional-effects`](Requirement::ConditionalEffects) if it is no longer
+/// used and adds the requirements of negated conditions.
+fn update_requirements(domain: &mut Domain) {
+    let used = infer_requirements(domain);
+    if !used.contains(&Requirement::ConditionalEffects) {
+        domain.requirements =
+            remove_requirement(domain.requirements(), Requirement::ConditionalEffects);
+    }
+    for requirement in [
+        Requirement::NegativePreconditions,
+        Requirement::DisjunctivePreconditions,
+        Requirement::ExistentialPreconditions,
+        Requirement::UniversalPreconditions,
+    ] {
+        if used.contains(&requirement) {
+            domain.requirements = add_requirement(domain.requirements(), requirement);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::types::Requirements;
+    use crate::Parser;
+
+    const DOMAIN: &str = r#"(define (domain d)
+        (:requirements :conditional-effects :universal-preconditions)
+        (:predicates (p ?x) (q ?x) (r))
+        (:action a
+            :parameters (?x)
+            :precondition (p ?x)
+            :effect (and (r) (not (p ?x)) (when (and (q ?x) (r)) (p ?x)) (when (r) (not (r)))))
+        (:action b
+            :parameters ()
+            :effect (forall (?x) (when (q ?x) (p ?x)))))"#;
+
+    fn preconditions(domain: &Domain) -> Vec<String> {
+        domain
+            .structure()
+            .iter()
+            .map(|def| match def {
+                StructureDef::Action(action) => {
+                    format!("{} {}", action.symbol(), action.precondition())
+                }
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_on_effect_conditions() {
+        let domain = split_conditional_effects(Domain::from_str(DOMAIN).unwrap());
+        assert_eq!(
+            preconditions(&domain),
+            [
+                "a-1 (and (p ?x) (or (not (q ?x)) (not (r))) (not (r)))",
+                "a-2 (and (p ?x) (q ?x) (r) (not (r)))",
+                "a-3 (and (p ?x) (or (not (q ?x)) (not (r))) (r))",
+                "a-4 (and (p ?x) (q ?x) (r) (r))",
+                "b (and)",
+            ]
+        );
+        assert_eq!(
+            domain.structure()[3].to_string(),
+            "(:action a-4\n  :parameters (?x)\n  :precondition (and (p ?x) (q ?x) (r) (r))\n  \
+             :effect (and (r) (not (p ?x)) (p ?x) (not (r))))"
+        );
+
+        // The conditional effect of `b` is nested in a quantified effect.
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([
+                Requirement::ConditionalEffects,
+                Requirement::UniversalPreconditions,
+                Requirement::NegativePreconditions,
+                Requirement::DisjunctivePreconditions,
+            ])
+        );
+    }
+
+    #[test]
+    fn adds_requirements_for_quantified_conditions() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :conditional-effects)
+                (:predicates (p) (q ?x))
+                (:action a
+                    :parameters ()
+                    :effect (when (forall (?x) (q ?x)) (p))))"#,
+        )
+        .unwrap();
+
+        let domain = split_conditional_effects(domain);
+        assert_eq!(
+            preconditions(&domain),
+            ["a-1 (exists (?x) (not (q ?x)))", "a-2 (forall (?x) (q ?x))",]
+        );
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([
+                Requirement::NegativePreconditions,
+                Requirement::ExistentialPreconditions,
+                Requirement::UniversalPreconditions,
+            ])
+        );
+    }
+
+    #[test]
+    fn sequences_effect_evaluation_and_application() {
+        let problem = Problem::from_str(
+            "(define (problem p) (:domain d) (:requirements :conditional-effects) (:init) (:goal (r)))",
+        )
+        .unwrap();
+        let (domain, problem) =
+            sequence_conditional_effects(Domain::from_str(DOMAIN).unwrap(), problem);
+
+        assert_eq!(
+            preconditions(&domain),
+            [
+                "a (and (p ?x) (normal-mode))",
+                "a-when-1 (and (a-stage-1 ?x) (q ?x) (r))",
+                "a-unless-1 (and (a-stage-1 ?x) (or (not (q ?x)) (not (r))))",
+                "a-when-2 (and (a-stage-2 ?x) (r))",
+                "a-unless-2 (and (a-stage-2 ?x) (not (r)))",
+                "a-delete-1 (and (a-stage-3 ?x) (a-fired-1 ?x))",
+                "a-keep-1 (and (a-stage-3 ?x) (a-unfired-1 ?x))",
+                "a-delete-2 (and (a-stage-4 ?x) (a-fired-2 ?x))",
+                "a-keep-2 (and (a-stage-4 ?x) (a-unfired-2 ?x))",
+                "a-add-1 (and (a-stage-5 ?x) (a-fired-1 ?x))",
+                "a-skip-1 (and (a-stage-5 ?x) (a-unfired-1 ?x))",
+                "a-add-2 (and (a-stage-6 ?x) (a-fired-2 ?x))",
+                "a-skip-2 (and (a-stage-6 ?x) (a-unfired-2 ?x))",
+                "b (normal-mode)",
+            ]
+        );
+
+        let effects: Vec<_> = domain
+            .structure()
+            .iter()
+            .filter_map(|def| match def {
+                StructureDef::Action(action) => action.effect().as_ref().map(ToString::to_string),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(effects[0], "(and (not (normal-mode)) (a-stage-1 ?x))");
+        assert_eq!(
+            effects[4],
+            "(and (not (a-stage-2 ?x)) (a-stage-3 ?x) (a-unfired-2 ?x) (not (p ?x)))"
+        );
+        assert_eq!(
+            effects[7],
+            "(and (not (r)) (not (a-stage-4 ?x)) (a-stage-5 ?x))"
+        );
+        assert_eq!(
+            effects[9],
+            "(and (p ?x) (not (a-stage-5 ?x)) (not (a-fired-1 ?x)) (a-stage-6 ?x))"
+        );
+        assert_eq!(
+            effects[11],
+            "(and (not (a-stage-6 ?x)) (not (a-fired-2 ?x)) (normal-mode) (r))"
+        );
+
+        assert_eq!(domain.predicates().len(), 3 + 6 + 4 + 1);
+        assert!(problem.requirements().is_empty());
+        assert_eq!(problem.init().to_string(), "(:init\n  (normal-mode))");
+        assert_eq!(
+            problem.goal().to_string(),
+            "(:goal (and (r) (normal-mode)))"
+        );
+    }
+
+    #[test]
+    fn gates_durative_actions_on_normal_mode() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :conditional-effects :durative-actions)
+                (:predicates (p) (q))
+                (:action a :parameters () :effect (when (p) (q)))
+                (:durative-action b :parameters () :duration (= ?duration 1)
+                    :condition (at start (p)) :effect (at end (not (p)))))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (q)))").unwrap();
+        let (domain, _) = sequence_conditional_effects(domain, problem);
+
+        let Some(StructureDef::DurativeAction(action)) = domain.structure().last() else {
+            panic!("the durative action is kept");
+        };
+        assert_eq!(
+            action.condition().as_ref().unwrap().to_string(),
+            "(and (at start (normal-mode)) (at end (normal-mode)) (at start (p)))"
+        );
+    }
+}
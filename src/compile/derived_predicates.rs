@@ -0,0 +1,452 @@
+//! Compiles away derived predicates by inlining their definitions.
+
+use crate::compile::{add_requirement, remove_requirement, FreshNames};
+use crate::fold::{self, Fold};
+use crate::types::{
+    ActionDefinition, AtomicFormula, DerivedPredicate, DerivedPredicateGraph, Domain,
+    DurativeActionDefinition, GoalDefinition, Literal, Name, PredicateAtomicFormula, Problem,
+    Requirement, StructureDef, StructureDefs, Term, Type, TypeHierarchy, Typed, TypedVariables,
+    Variable,
+};
+use crate::validate::{infer_problem_requirements, infer_requirements};
+use crate::visit::Visit;
+use std::collections::HashMap;
+
+/// Replaces each use of a non-recursive derived predicate in the `domain` and `problem`
+/// by its definition.
+///
+/// Every atomic formula of a derived predicate that does not depend on itself, see
+/// [`DerivedPredicateGraph::is_recursive`], is replaced by the body of its `:derived`
+/// definition with the parameters substituted by the arguments, or by the disjunction of
+/// these bodies if the predicate has several definitions. This applies to preconditions,
+/// goals, effect conditions, constraints and the definitions of other derived predicates.
+/// Quantified variables of the bodies are renamed where they would capture a variable of
+/// the arguments. The inlined definitions and their predicate declarations are then removed.
+///
+/// A derived predicate only holds for arguments of its parameter types. Unless an argument
+/// is known to have a suitable type, i.e. it is a parameter, a quantified variable of a goal,
+/// a constant or an object of such a type, the body is conjoined with a type guard
+/// `(exists (?x - <type>) (= ?x <argument>))`.
+///
+/// Recursive derived predicates cannot be inlined and are kept as-is, along with the
+/// [`:derived-predicates`](Requirement::DerivedPredicates) requirement. Otherwise, the
+/// requirement is removed. Since the inlined definitions may use negations, disjunctions,
+/// quantifiers or equality, the corresponding requirements are added as needed.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::compile::inline_derived_predicates;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :derived-predicates)
+///     (:predicates (on ?x ?y) (clear ?x))
+///     (:derived (clear ?x) (not (exists (?y) (on ?y ?x))))
+///     (:action pick
+///         :parameters (?y)
+///         :precondition (clear ?y)
+///         :effect (and)))"#).unwrap();
+/// let problem = Problem::from_str(
+///     "(define (problem p) (:domain d) (:objects a) (:init) (:goal (clear a)))"
+/// ).unwrap();
+///
+/// let (domain, problem) = inline_derived_predicates(domain, problem);
+/// assert_eq!(domain.predicates().to_string(), "(:predicates (on ?x ?y))");
+/// assert!(domain.structure()[0].to_string().contains(
+///     ":precondition (not (exists (?y-1) (on ?y-1 ?y)))"
+/// ));
+/// assert_eq!(problem.goal().to_string(), "(:goal (not (exists (?y) (on ?y a))))");
+/// ```
+pub fn inline_derived_predicates(mut domain: Domain, problem: Problem) -> (Domain, Problem) {
+    let graph = DerivedPredicateGraph::new(domain.structure());
+    let mut inliner = Inliner {
+        rules: HashMap::new(),
+        hierarchy: TypeHierarchy::new(domain.types()),
+        constants: domain
+            .constants()
+            .iter()
+            .chain(problem.objects().iter())
+            .map(|constant| (constant.value().clone(), constant.type_().clone()))
+            .collect(),
+        bound: Vec::new(),
+    };
+    let mut structure = Vec::with_capacity(domain.structure().len());
+    for def in std::mem::take(&mut domain.structure).0 {
+        match def {
+            StructureDef::Derived(derived) if !graph.is_recursive(derived.predicate().name()) => {
+                let name = derived.predicate().name().clone();
+                inliner.rules.entry(name).or_default().push(derived);
+            }
+            def => structure.push(def),
+        }
+    }
+    domain.structure = StructureDefs::new(structure);
+
+    let mut domain = inliner.fold_domain(domain);
+    let mut problem = inliner.fold_problem(problem);
+    domain
+        .predicates
+        .0
+        .retain(|skeleton| !inliner.rules.contains_key(skeleton.name()));
+
+    let used = infer_requirements(&domain);
+    if !used.contains(&Requirement::DerivedPredicates) {
+        domain.requirements =
+            remove_requirement(domain.requirements(), Requirement::DerivedPredicates);
+    }
+    let problem_used = infer_problem_requirements(&problem);
+    for requirement in [
+        Requirement::NegativePreconditions,
+        Requirement::DisjunctivePreconditions,
+        Requirement::Equality,
+        Requirement::ExistentialPreconditions,
+        Requirement::UniversalPreconditions,
+    ] {
+        if used.contains(&requirement) {
+            domain.requirements = add_requirement(domain.requirements(), requirement);
+        }
+        if problem_used.contains(&requirement) {
+            problem.requires = add_requirement(problem.requirements(), requirement);
+        }
+    }
+    (domain, problem)
+}
+
+/// Replaces atomic formulas of derived predicates by their definitions.
+struct Inliner {
+    /// The definitions of each inlined derived predicate.
+    rules: HashMap<Name, Vec<DerivedPredicate>>,
+    hierarchy: TypeHierarchy,
+    /// The types of the constants and objects.
+    constants: HashMap<Name, Type>,
+    /// The variables in scope, innermost last.
+    bound: Vec<Typed<Variable>>,
+}
+
+impl Inliner {
+    /// Folds `node` with the `variables` in scope.
+    fn with_bound<T>(
+        &mut self,
+        variables: &TypedVariables,
+        node: T,
+        fold: impl FnOnce(&mut Self, T) -> T,
+    ) -> T {
+        let depth = self.bound.len();
+        self.bound.extend(variables.iter().cloned());
+        let node = fold(self, node);
+        self.bound.truncate(depth);
+        node
+    }
+
+    /// Gets the declared type of a term, if known.
+    fn term_type(&self, term: &Term) -> Option<&Type> {
+        match term {
+            Term::Name(name) => self.constants.get(name),
+            Term::Variable(variable) => self
+                .bound
+                .iter()
+                .rev()
+                .find(|bound| bound.value() == variable)
+                .map(|bound| bound.type_()),
+            Term::Function(_) => None,
+        }
+    }
+
+    /// Gets the type guards of the `arguments` that are not known to have the types
+    /// of the `parameters`.
+    fn guards(&self, parameters: &TypedVariables, arguments: &[Term]) -> Vec<GoalDefinition> {
+        parameters
+            .iter()
+            .zip(arguments)
+            .filter(|(parameter, argument)| {
+                *parameter.type_() != Type::OBJECT
+                    && !self.term_type(argument).map_or(false, |r#type| {
+                        self.hierarchy.is_assignable(r#type, parameter.type_())
+                    })
+            })
+            .map(|(parameter, argument)| {
+                let mut variables = Variables::default();
+                variables.visit_term(argument);
+                let variable =
+                    Variable::from(FreshNames::new(variables.0).fresh(parameter.value()));
+                GoalDefinition::new_exists(
+                    TypedVariables::new(vec![Typed::new(
+                        variable.clone(),
+                        parameter.type_().clone(),
+                    )]),
+                    GoalDefinition::new_atomic_formula(AtomicFormula::new_equality(
+                        Term::Variable(variable),
+                        argument.clone(),
+                    )),
+                )
+            })
+            .collect()
+    }
+
+    /// Gets the disjunction of the definitions of the derived predicate instantiated with
+    /// the arguments of the atomic formula, or `None` if the predicate is not inlined.
+    fn expand(&self, af: &PredicateAtomicFormula<Term>) -> Option<GoalDefinition> {
+        let rules = self.rules.get(af.predicate())?;
+        let mut bodies: Vec<_> = rules
+            .iter()
+            .map(|rule| {
+                let parameters = rule.predicate().variables();
+                let mut variables = Variables::default();
+                variables.visit_goal_definition(rule.expression());
+                af.values()
+                    .iter()
+                    .for_each(|term| variables.visit_term(term));
+
+                let mut instantiation = Instantiation {
+                    bindings: parameters
+                        .iter()
+                        .map(|parameter| parameter.value().clone())
+                        .zip(af.values().iter().cloned())
+                        .collect(),
+                    names: FreshNames::new(variables.0),
+                };
+                let body = instantiation.fold_goal_definition(rule.expression().clone());
+                let guards = self.guards(parameters, af.values());
+                if guards.is_empty() {
+                    body
+                } else {
+                    GoalDefinition::new_and(guards.into_iter().chain([body]))
+                }
+            })
+            .collect();
+        Some(match bodies.len() {
+            1 => bodies.remove(0),
+            _ => GoalDefinition::new_or(bodies),
+        })
+    }
+}
+
+impl Fold for Inliner {
+    fn fold_action_definition(&mut self, node: ActionDefinition) -> ActionDefinition {
+        let parameters = node.parameters().clone();
+        self.with_bound(&parameters, node, fold::fold_action_definition)
+    }
+
+    fn fold_durative_action_definition(
+        &mut self,
+        node: DurativeActionDefinition,
+    ) -> DurativeActionDefinition {
+        let parameters = node.parameters().clone();
+        self.with_bound(&parameters, node, fold::fold_durative_action_definition)
+    }
+
+    fn fold_derived_predicate(&mut self, node: DerivedPredicate) -> DerivedPredicate {
+        let parameters = node.predicate().variables().clone();
+        self.with_bound(&parameters, node, fold::fold_derived_predicate)
+    }
+
+    fn fold_goal_definition(&mut self, node: GoalDefinition) -> GoalDefinition {
+        let (af, negated) = match &node {
+            GoalDefinition::Exists(variables, _) | GoalDefinition::ForAll(variables, _) => {
+                let variables = variables.clone();
+                return self.with_bound(&variables, node, fold::fold_goal_definition);
+            }
+            GoalDefinition::AtomicFormula(AtomicFormula::Predicate(af))
+            | GoalDefinition::Literal(Literal::AtomicFormula(AtomicFormula::Predicate(af))) => {
+                (af, false)
+            }
+            GoalDefinition::Literal(Literal::NotAtomicFormula(AtomicFormula::Predicate(af))) => {
+                (af, true)
+            }
+            _ => return fold::fold_goal_definition(self, node),
+        };
+        let Some(gd) = self.expand(af) else {
+            return fold::fold_goal_definition(self, node);
+        };
+
+        // The definitions may use further derived predicates, which are not recursive.
+        let gd = self.fold_goal_definition(gd);
+        if negated {
+            GoalDefinition::new_not(gd)
+        } else {
+            gd
+        }
+    }
+}
+
+/// Collects the names of all variables.
+#[derive(Default)]
+struct Variables<'ast>(Vec<&'ast Name>);
+
+impl<'ast> Visit<'ast> for Variables<'ast> {
+    fn visit_variable(&mut self, node: &'ast Variable) {
+        self.0.push(node);
+    }
+}
+
+/// Substitutes the parameters of a definition by the arguments of its use.
+struct Instantiation {
+    /// The terms bound to each variable, innermost last.
+    bindings: Vec<(Variable, Term)>,
+    names: FreshNames,
+}
+
+impl Instantiation {
+    /// Binds the quantified `variables`, renaming those that would capture a variable of
+    /// the arguments.
+    fn bind(&mut self, variables: TypedVariables) -> TypedVariables {
+        variables
+            .into_iter()
+            .map(|variable| {
+                let captured = self
+                    .bindings
+                    .iter()
+                    .any(|(_, term)| *term == Term::Variable(variable.value().clone()));
+                let renamed = if captured {
+                    Variable::from(self.names.fresh(variable.value()))
+                } else {
+                    variable.value().clone()
+                };
+                self.bindings
+                    .push((variable.value().clone(), Term::Variable(renamed.clone())));
+                Typed::new(renamed, variable.type_().clone())
+            })
+            .collect()
+    }
+}
+
+impl Fold for Instantiation {
+    fn fold_term(&mut self, node: Term) -> Term {
+        match node {
+            Term::Variable(variable) => self
+                .bindings
+                .iter()
+                .rev()
+                .find(|(bound, _)| *bound == variable)
+                .map_or(Term::Variable(variable), |(_, term)| term.clone()),
+            node => fold::fold_term(self, node),
+        }
+    }
+
+    fn fold_goal_definition(&mut self, node: GoalDefinition) -> GoalDefinition {
+        let depth = self.bindings.len();
+        let gd = match node {
+            GoalDefinition::Exists(variables, gd) => {
+                let variables = self.bind(variables);
+                GoalDefinition::new_exists(variables, self.fold_goal_definition(*gd))
+            }
+            GoalDefinition::ForAll(variables, gd) => {
+                let variables = self.bind(variables);
+                GoalDefinition::new_forall(variables, self.fold_goal_definition(*gd))
+            }
+            node => fold::fold_goal_definition(self, node),
+        };
+        self.bindings.truncate(depth);
+        gd
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::types::Requirements;
+    use crate::Parser;
+
+    #[test]
+    fn inlines_nested_and_alternative_definitions() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :derived-predicates :negative-preconditions)
+                (:predicates (p ?x) (q ?x ?y) (a ?x) (b ?x ?y))
+                (:derived (a ?x) (p ?x))
+                (:derived (a ?x) (exists (?y) (q ?x ?y)))
+                (:derived (b ?x ?y) (and (a ?y) (q ?x ?y)))
+                (:action act
+                    :parameters (?y)
+                    :precondition (not (b ?y ?y))
+                    :effect (p ?y)))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (a o)))").unwrap();
+
+        let (domain, problem) = inline_derived_predicates(domain, problem);
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([
+                Requirement::NegativePreconditions,
+                Requirement::DisjunctivePreconditions,
+                Requirement::ExistentialPreconditions,
+            ])
+        );
+        assert_eq!(
+            domain.predicates().to_string(),
+            "(:predicates (p ?x) (q ?x ?y))"
+        );
+        assert_eq!(domain.structure().len(), 1);
+        assert!(domain.structure()[0].to_string().contains(
+            ":precondition (not (and (or (p ?y) (exists (?y-1) (q ?y ?y-1))) (q ?y ?y)))"
+        ));
+        assert_eq!(
+            problem.goal().to_string(),
+            "(:goal (or (p o) (exists (?y) (q o ?y))))"
+        );
+    }
+
+    #[test]
+    fn guards_typed_parameters() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :derived-predicates :typing :negative-preconditions)
+                (:types block table)
+                (:constants t1 - table)
+                (:predicates (on ?x - object ?y - object) (clear ?x - block))
+                (:derived (clear ?x - block) (not (exists (?y) (on ?y ?x))))
+                (:action pick
+                    :parameters (?b - block ?t - table)
+                    :precondition (and (clear ?b) (clear ?t) (clear t1))
+                    :effect (and)))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            "(define (problem p) (:domain d) (:objects b1 - block) (:init) (:goal (clear b1)))",
+        )
+        .unwrap();
+
+        let (domain, problem) = inline_derived_predicates(domain, problem);
+        assert!(domain.structure()[0].to_string().contains(
+            ":precondition (and (not (exists (?y) (on ?y ?b))) \
+             (and (exists (?x - block) (= ?x ?t)) (not (exists (?y) (on ?y ?t)))) \
+             (and (exists (?x - block) (= ?x t1)) (not (exists (?y) (on ?y t1)))))"
+        ));
+        assert_eq!(
+            problem.goal().to_string(),
+            "(:goal (not (exists (?y) (on ?y b1))))"
+        );
+        assert!(domain.requirements().contains(&Requirement::Equality));
+        assert!(domain
+            .requirements()
+            .contains(&Requirement::ExistentialPreconditions));
+    }
+
+    #[test]
+    fn keeps_recursive_definitions() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :derived-predicates :existential-preconditions)
+                (:predicates (edge ?x ?y) (path ?x ?y) (linked ?x))
+                (:derived (path ?x ?y) (or (edge ?x ?y) (exists (?z) (and (edge ?x ?z) (path ?z ?y)))))
+                (:derived (linked ?x) (path ?x ?x)))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (linked o)))")
+                .unwrap();
+
+        let (domain, problem) = inline_derived_predicates(domain, problem);
+        assert!(domain
+            .requirements()
+            .contains(&Requirement::DerivedPredicates));
+        assert_eq!(
+            domain.predicates().to_string(),
+            "(:predicates (edge ?x ?y) (path ?x ?y))"
+        );
+        assert_eq!(domain.structure().len(), 1);
+        assert_eq!(problem.goal().to_string(), "(:goal (path o o))");
+    }
+}
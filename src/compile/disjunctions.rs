@@ -0,0 +1,203 @@
+//! Compiles away disjunctive preconditions of actions.
+
+use crate::compile::{action_names, add_requirement, remove_requirement, FreshNames};
+use crate::types::{ActionDefinition, Domain, Requirement, StructureDef, StructureDefs};
+use crate::validate::infer_requirements;
+
+/// Replaces each action by one action per disjunct of its precondition.
+///
+/// The precondition of every [`ActionDefinition`] is converted into disjunctive normal
+/// form, see [`PreconditionGoalDefinitions::to_dnf`](crate::PreconditionGoalDefinitions::to_dnf).
+/// An action with a single disjunct keeps its name, while an action with several disjuncts
+/// is replaced by copies named `<action>-1`, `<action>-2` and so on, each with the same
+/// parameters and effect and one disjunct as its precondition. Names that are already
+/// taken receive a further numeric suffix. Actions whose precondition
+/// can never hold are removed.
+///
+/// If the result no longer uses disjunctions, [`:disjunctive-preconditions`](Requirement::DisjunctivePreconditions)
+/// is removed from its requirements. Disjunctions may remain inside universally quantified
+/// preconditions, conditions of conditional effects, durative actions, derived predicates
+/// and constraints, in which case the requirement is kept. Since the normal form turns
+/// implications and negated disjunctions into negative literals and may move quantifiers
+/// to the top level, [`:negative-preconditions`](Requirement::NegativePreconditions),
+/// [`:existential-preconditions`](Requirement::ExistentialPreconditions) and
+/// [`:universal-preconditions`](Requirement::UniversalPreconditions) are added if the
+/// result uses them.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, StructureDef};
+/// use pddl::compile::split_disjunctive_preconditions;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :strips :disjunctive-preconditions)
+///     (:predicates (p) (q) (r))
+///     (:action a
+///         :parameters ()
+///         :precondition (or (p) (q))
+///         :effect (r)))"#).unwrap();
+///
+/// let domain = split_disjunctive_preconditions(domain);
+/// assert_eq!(domain.requirements().len(), 1);
+///
+/// let actions: Vec<_> = domain.structure().iter().map(|def| match def {
+///     StructureDef::Action(action) => format!("{} {}", action.symbol(), action.precondition()),
+///     _ => unreachable!(),
+/// }).collect();
+/// assert_eq!(actions, ["a-1 (p)", "a-2 (q)"]);
+/// ```
+pub fn split_disjunctive_preconditions(mut domain: Domain) -> Domain {
+    let mut names = FreshNames::new(action_names(&domain));
+
+    let mut structure = Vec::with_capacity(domain.structure().len());
+    for def in std::mem::take(&mut domain.structure).0 {
+        let StructureDef::Action(action) = def else {
+            structure.push(def);
+            continue;
+        };
+
+        let disjuncts = action.precondition().to_dnf();
+        let split = disjuncts.len() > 1;
+        for (i, precondition) in disjuncts.into_iter().enumerate() {
+            let symbol = if split {
+                names
+                    .fresh(&format!("{}-{}", action.symbol(), i + 1))
+                    .into()
+            } else {
+                action.symbol().clone()
+            };
+            structure.push(StructureDef::Action(ActionDefinition {
+                symbol,
+                parameters: action.parameters().clone(),
+                precondition,
+                effect: action.effect().clone(),
+                span: action.span,
+            }));
+        }
+    }
+    domain.structure = StructureDefs::new(structure);
+
+    let used = infer_requirements(&domain);
+    if !used.contains(&Requirement::DisjunctivePreconditions) {
+        domain.requirements =
+            remove_requirement(domain.requirements(), Requirement::DisjunctivePreconditions);
+    }
+    for requirement in [
+        Requirement::NegativePreconditions,
+        Requirement::ExistentialPreconditions,
+        Requirement::UniversalPreconditions,
+    ] {
+        if used.contains(&requirement) {
+            domain.requirements = add_requirement(domain.requirements(), requirement);
+        }
+    }
+    domain
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn splits_actions_per_disjunct() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :adl)
+                (:predicates (p ?x) (q ?x) (r ?x))
+                (:action a-1 :parameters () :effect (and))
+                (:action a
+                    :parameters (?x)
+                    :precondition (and (p ?x) (imply (q ?x) (r ?x)))
+                    :effect (not (p ?x)))
+                (:action b
+                    :parameters (?x)
+                    :precondition (not (or (p ?x) (q ?x)))
+                    :effect (p ?x))
+                (:action never :parameters () :precondition (or) :effect (and)))"#,
+        )
+        .unwrap();
+
+        let domain = split_disjunctive_preconditions(domain);
+        assert!(!domain
+            .requirements()
+            .contains(&Requirement::DisjunctivePreconditions));
+        assert!(domain
+            .requirements()
+            .contains(&Requirement::NegativePreconditions));
+
+        let actions: Vec<_> = domain
+            .structure()
+            .iter()
+            .map(|def| match def {
+                StructureDef::Action(action) => {
+                    format!("{} {}", action.symbol(), action.precondition())
+                }
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                "a-1 (and)",
+                "a-1-1 (and (p ?x) (not (q ?x)))",
+                "a-2 (and (p ?x) (r ?x))",
+                "b (and (not (p ?x)) (not (q ?x)))",
+            ]
+        );
+
+        // The result is valid PDDL that prints the same after parsing it back.
+        let printed = domain.to_string();
+        assert_eq!(Domain::from_str(&printed).unwrap().to_string(), printed);
+    }
+
+    #[test]
+    fn adds_requirements_for_negated_literals() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :strips :disjunctive-preconditions)
+                (:predicates (p) (q) (r))
+                (:action a
+                    :parameters ()
+                    :precondition (imply (q) (r))
+                    :effect (p)))"#,
+        )
+        .unwrap();
+
+        let domain = split_disjunctive_preconditions(domain);
+        assert_eq!(
+            domain.requirements().to_string(),
+            "(:requirements :strips :negative-preconditions)"
+        );
+
+        let actions: Vec<_> = domain
+            .structure()
+            .iter()
+            .map(|def| match def {
+                StructureDef::Action(action) => {
+                    format!("{} {}", action.symbol(), action.precondition())
+                }
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(actions, ["a-1 (not (q))", "a-2 (r)"]);
+    }
+
+    #[test]
+    fn keeps_requirement_for_remaining_disjunctions() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :disjunctive-preconditions :universal-preconditions)
+                (:predicates (p ?x) (q ?x))
+                (:action a
+                    :parameters ()
+                    :precondition (forall (?x) (or (p ?x) (q ?x)))
+                    :effect (and)))"#,
+        )
+        .unwrap();
+
+        let split = split_disjunctive_preconditions(domain.clone());
+        assert_eq!(split.requirements(), domain.requirements());
+        assert_eq!(split.structure().len(), 1);
+    }
+}
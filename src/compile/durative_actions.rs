@@ -0,0 +1,643 @@
+//! Compiles durative actions into pairs of instantaneous snap actions.
+
+use crate::compile::{action_names, add_requirement, remove_requirement, FreshNames};
+use crate::types::{
+    ActionDefinition, ActionSymbol, AtomicFormula, AtomicFormulaSkeleton, CEffect,
+    ConditionalEffect, Domain, DurationConstraint, DurativeActionDefinition, DurativeActionEffect,
+    DurativeActionGoalDefinition, DurativeActionSymbol, Effects, FAssignDa, FExp, FExpDa,
+    FunctionSymbol, GoalDefinition, Name, PEffect, PreconditionGoalDefinition,
+    PreconditionGoalDefinitions, Predicate, PrefTimedGD, Preference, Problem, Requirement,
+    StructureDef, StructureDefs, Term, TimeSpecifier, TimedEffect, TimedGD, TypedVariables,
+    Variable,
+};
+use crate::validate::{infer_problem_requirements, infer_requirements};
+use crate::visit::{self, Visit};
+use std::collections::HashSet;
+
+/// The name of the `?duration` variable of durative actions.
+const DURATION: &str = "duration";
+
+/// Replaces each durative action of the `domain` by a start and an end action.
+///
+/// This is the "snap action" encoding used by temporal planners such as LPG and POPF.
+/// For a durative action `a`, a new `running-a` predicate over the parameters of `a` is
+/// declared, and two actions with the same parameters are created:
+/// - `a-start` requires the `at start` conditions and that `a` is not running yet, and has
+///   the `at start` effects and makes `a` running,
+/// - `a-end` requires that `a` is running as well as the `at end` and `over all` conditions,
+///   and has the `at end` effects and makes `a` stop running.
+///
+/// Like in POPF, the `over all` conditions must already hold after the start effects. Those
+/// that only use predicates and functions which the `at start` effects do not change are
+/// therefore required by `a-start` as well. Others, such as `(over all (p))` with an
+/// `(at start (p))` effect, cannot be checked by a precondition of `a-start` and are only
+/// required by `a-end`.
+///
+/// The goal of the `problem` is extended so that no action is running at the end of a plan.
+/// The duration constraints cannot be expressed by instantaneous actions and are returned
+/// as [`SnapActions`] along with the names of the new actions, so that a caller can check
+/// them when scheduling a plan. The `over all` conditions are returned as well as
+/// [`SnapActions::invariant`]: other actions may break and restore them between the start
+/// and the end action, so a caller must check them in every state in between.
+///
+/// Durative actions that use `?duration` in their conditions or effects, have continuous
+/// effects or have conditional effects whose condition does not refer to the time of the
+/// effect are kept as-is. The [`:durative-actions`](Requirement::DurativeActions) and
+/// [`:duration-inequalities`](Requirement::DurationInequalities) requirements are removed
+/// if they are no longer used, and the requirements of the new conditions and effects are
+/// added as needed.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::compile::split_durative_actions;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :durative-actions)
+///     (:predicates (at ?x) (road ?x ?y))
+///     (:durative-action drive
+///         :parameters (?x ?y)
+///         :duration (= ?duration 5)
+///         :condition (and (at start (at ?x)) (over all (road ?x ?y)))
+///         :effect (and (at start (not (at ?x))) (at end (at ?y)))))"#).unwrap();
+/// let problem = Problem::from_str(
+///     "(define (problem p) (:domain d) (:init (at a) (road a b)) (:goal (at b)))"
+/// ).unwrap();
+///
+/// let (domain, problem, snaps) = split_durative_actions(domain, problem);
+/// assert_eq!(snaps[0].start().to_string(), "drive-start");
+/// assert_eq!(snaps[0].end().to_string(), "drive-end");
+/// assert_eq!(snaps[0].duration().as_ref().unwrap().to_string(), "(= ?duration 5)");
+/// assert_eq!(domain.structure().len(), 2);
+/// assert!(domain.structure()[1].to_string().contains(
+///     ":precondition (and (running-drive ?x ?y) (road ?x ?y))"
+/// ));
+/// assert_eq!(
+///     problem.goal().to_string(),
+///     "(:goal (and (at b) (forall (?x ?y) (not (running-drive ?x ?y)))))"
+/// );
+/// ```
+pub fn split_durative_actions(
+    mut domain: Domain,
+    mut problem: Problem,
+) -> (Domain, Problem, Vec<SnapActions>) {
+    let mut predicates =
+        FreshNames::new(domain.predicates().iter().map(AtomicFormulaSkeleton::name));
+    let mut actions = FreshNames::new(action_names(&domain));
+
+    let mut snaps = Vec::new();
+    let mut structure = Vec::with_capacity(domain.structure().len());
+    for def in std::mem::take(&mut domain.structure).0 {
+        let action = match def {
+            StructureDef::DurativeAction(action) => action,
+            def => {
+                structure.push(def);
+                continue;
+            }
+        };
+        let Some((conditions, effects)) = split(&action) else {
+            structure.push(StructureDef::DurativeAction(action));
+            continue;
+        };
+
+        let running = Predicate::from(predicates.fresh(&format!("running-{}", action.symbol())));
+        let running_atom = AtomicFormula::new_predicate(
+            running.clone(),
+            action
+                .parameters()
+                .iter()
+                .map(|parameter| Term::Variable(parameter.value().clone())),
+        );
+        domain.predicates.0.push(AtomicFormulaSkeleton::new(
+            running.clone(),
+            action.parameters().clone(),
+        ));
+        let stopped = not_running(action.parameters(), running_atom.clone());
+        problem.goal.0 .0.push(goal(stopped));
+
+        let start = ActionSymbol::from(actions.fresh(&format!("{}-start", action.symbol())));
+        let mut precondition = vec![goal(GoalDefinition::new_not(
+            GoalDefinition::new_atomic_formula(running_atom.clone()),
+        ))];
+        precondition.extend(conditions.start);
+        let mut changed = ChangedSymbols::default();
+        effects
+            .start
+            .iter()
+            .for_each(|effect| changed.visit_c_effect(effect));
+        precondition.extend(
+            conditions
+                .over_all
+                .iter()
+                .filter(|gd| !changed.affects(gd))
+                .cloned(),
+        );
+        let mut effect = effects.start;
+        effect.push(CEffect::Effect(PEffect::new(running_atom.clone())));
+        structure.push(StructureDef::Action(ActionDefinition {
+            symbol: start.clone(),
+            parameters: action.parameters().clone(),
+            precondition: PreconditionGoalDefinitions::new(precondition),
+            effect: Some(Effects::new_and(effect)),
+            span: action.span,
+        }));
+
+        let end = ActionSymbol::from(actions.fresh(&format!("{}-end", action.symbol())));
+        let mut precondition = vec![goal(GoalDefinition::new_atomic_formula(
+            running_atom.clone(),
+        ))];
+        precondition.extend(conditions.end);
+        precondition.extend(conditions.over_all.iter().cloned());
+        let mut effect = effects.end;
+        effect.push(CEffect::Effect(PEffect::new_not(running_atom)));
+        structure.push(StructureDef::Action(ActionDefinition {
+            symbol: end.clone(),
+            parameters: action.parameters().clone(),
+            precondition: PreconditionGoalDefinitions::new(precondition),
+            effect: Some(Effects::new_and(effect)),
+            span: action.span,
+        }));
+
+        snaps.push(SnapActions {
+            action: action.symbol,
+            parameters: action.parameters,
+            start,
+            end,
+            running,
+            duration: action.duration,
+            invariant: PreconditionGoalDefinitions::new(conditions.over_all),
+        });
+    }
+    domain.structure = StructureDefs::new(structure);
+
+    let used = infer_requirements(&domain);
+    for requirement in [
+        Requirement::DurativeActions,
+        Requirement::DurationInequalities,
+    ] {
+        if !used.contains(&requirement) {
+            domain.requirements = remove_requirement(domain.requirements(), requirement);
+        }
+    }
+    let problem_used = infer_problem_requirements(&problem);
+    for requirement in [
+        Requirement::NegativePreconditions,
+        Requirement::UniversalPreconditions,
+        Requirement::ConditionalEffects,
+    ] {
+        if used.contains(&requirement) {
+            domain.requirements = add_requirement(domain.requirements(), requirement);
+        }
+        if problem_used.contains(&requirement) {
+            problem.requires = add_requirement(problem.requirements(), requirement);
+        }
+    }
+    (domain, problem, snaps)
+}
+
+/// A durative action that was replaced by a start and an end action,
+/// see [`split_durative_actions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapActions {
+    action: DurativeActionSymbol,
+    parameters: TypedVariables,
+    start: ActionSymbol,
+    end: ActionSymbol,
+    running: Predicate,
+    duration: Option<DurationConstraint>,
+    invariant: PreconditionGoalDefinitions,
+}
+
+impl SnapActions {
+    /// Gets the name of the replaced durative action.
+    pub const fn action(&self) -> &DurativeActionSymbol {
+        &self.action
+    }
+
+    /// Gets the parameters of the durative action, which the start and end actions share.
+    pub const fn parameters(&self) -> &TypedVariables {
+        &self.parameters
+    }
+
+    /// Gets the name of the action that starts the durative action.
+    pub const fn start(&self) -> &ActionSymbol {
+        &self.start
+    }
+
+    /// Gets the name of the action that ends the durative action.
+    pub const fn end(&self) -> &ActionSymbol {
+        &self.end
+    }
+
+    /// Gets the predicate that holds while the durative action is running.
+    pub const fn running(&self) -> &Predicate {
+        &self.running
+    }
+
+    /// Gets the duration constraint that the time between the start and end action
+    /// must satisfy.
+    pub const fn duration(&self) -> &Option<DurationConstraint> {
+        &self.duration
+    }
+
+    /// Gets the `over all` conditions, which must hold in every state between the start
+    /// and end action.
+    ///
+    /// The end action requires them, and the start action requires those that its effects
+    /// do not change, but neither checks the states in between. Other actions may break
+    /// and restore an invariant while the durative action runs, so a plan is only valid
+    /// if the invariant is checked in every state between the start and the end action.
+    pub const fn invariant(&self) -> &PreconditionGoalDefinitions {
+        &self.invariant
+    }
+}
+
+/// The conditions of a durative action by the time at which they must hold.
+#[derive(Default)]
+struct SnapConditions {
+    start: Vec<PreconditionGoalDefinition>,
+    end: Vec<PreconditionGoalDefinition>,
+    over_all: Vec<PreconditionGoalDefinition>,
+}
+
+/// The effects of a durative action by the time at which they happen.
+#[derive(Default)]
+struct SnapEffects {
+    start: Vec<CEffect>,
+    end: Vec<CEffect>,
+}
+
+/// Splits the conditions and effects of the `action` by their time, or returns `None`
+/// if they cannot be expressed by instantaneous actions.
+fn split(action: &DurativeActionDefinition) -> Option<(SnapConditions, SnapEffects)> {
+    let mut duration = UsesDuration(false);
+    if let Some(condition) = action.condition() {
+        duration.visit_durative_action_goal_definition(condition);
+    }
+    if let Some(effect) = action.effect() {
+        duration.visit_durative_action_effect(effect);
+    }
+    if duration.0 {
+        return None;
+    }
+
+    let mut conditions = SnapConditions::default();
+    if let Some(condition) = action.condition() {
+        split_condition(condition.clone(), &mut conditions);
+    }
+    let mut effects = SnapEffects::default();
+    if let Some(effect) = action.effect() {
+        split_effect(effect.clone(), &mut effects)?;
+    }
+    Some((conditions, effects))
+}
+
+fn split_condition(node: DurativeActionGoalDefinition, conditions: &mut SnapConditions) {
+    let (gd, name) = match node {
+        DurativeActionGoalDefinition::Timed(PrefTimedGD::Required(gd)) => (gd, None),
+        DurativeActionGoalDefinition::Timed(PrefTimedGD::Preference(name, gd)) => (gd, Some(name)),
+        DurativeActionGoalDefinition::And(gds) => {
+            for gd in gds {
+                split_condition(gd, conditions);
+            }
+            return;
+        }
+        DurativeActionGoalDefinition::Forall(variables, gd) => {
+            let mut inner = SnapConditions::default();
+            split_condition(*gd, &mut inner);
+            for (outer, inner) in [
+                (&mut conditions.start, inner.start),
+                (&mut conditions.end, inner.end),
+                (&mut conditions.over_all, inner.over_all),
+            ] {
+                if !inner.is_empty() {
+                    outer.push(PreconditionGoalDefinition::new_forall(
+                        variables.clone(),
+                        PreconditionGoalDefinitions::new(inner),
+                    ));
+                }
+            }
+            return;
+        }
+    };
+
+    let (conditions, gd) = match gd {
+        TimedGD::At(TimeSpecifier::Start, gd) => (&mut conditions.start, gd),
+        TimedGD::At(TimeSpecifier::End, gd) => (&mut conditions.end, gd),
+        TimedGD::Over(_, gd) => (&mut conditions.over_all, gd),
+    };
+    conditions.push(match name {
+        None => goal(gd),
+        Some(name) => PreconditionGoalDefinition::from(Preference::new(name, gd)),
+    });
+}
+
+fn split_effect(node: DurativeActionEffect, effects: &mut SnapEffects) -> Option<()> {
+    match node {
+        DurativeActionEffect::Timed(effect) => {
+            let (time, effect) = snap_effect(effect)?;
+            effects
+                .at(time)
+                .extend(effect.into_iter().map(CEffect::Effect));
+        }
+        DurativeActionEffect::All(nodes) => {
+            for node in nodes {
+                split_effect(node, effects)?;
+            }
+        }
+        DurativeActionEffect::Forall(variables, node) => {
+            let mut inner = SnapEffects::default();
+            split_effect(*node, &mut inner)?;
+            for (outer, inner) in [
+                (&mut effects.start, inner.start),
+                (&mut effects.end, inner.end),
+            ] {
+                if !inner.is_empty() {
+                    outer.push(CEffect::new_forall(
+                        variables.clone(),
+                        Effects::new_and(inner),
+                    ));
+                }
+            }
+        }
+        DurativeActionEffect::When(condition, effect) => {
+            let (time, effect) = snap_effect(effect)?;
+            let condition = timed_condition(condition, time)?;
+            effects.at(time).push(CEffect::new_when(condition, effect));
+        }
+    }
+    Some(())
+}
+
+impl SnapEffects {
+    fn at(&mut self, time: TimeSpecifier) -> &mut Vec<CEffect> {
+        match time {
+            TimeSpecifier::Start => &mut self.start,
+            TimeSpecifier::End => &mut self.end,
+        }
+    }
+}
+
+/// Converts a timed effect into the effect of a snap action, or returns `None` for
+/// continuous effects.
+fn snap_effect(node: TimedEffect) -> Option<(TimeSpecifier, ConditionalEffect)> {
+    match node {
+        TimedEffect::Conditional(time, effect) => Some((time, effect)),
+        TimedEffect::NumericFluent(time, FAssignDa(op, head, exp)) => Some((
+            time,
+            ConditionalEffect::new(PEffect::new_numeric_fluent(op, head, f_exp(exp)?)),
+        )),
+        TimedEffect::ContinuousEffect(..) => None,
+    }
+}
+
+/// Gets the condition of a conditional effect at `time`, or returns `None` if it refers
+/// to another time or contains preferences.
+fn timed_condition(
+    node: DurativeActionGoalDefinition,
+    time: TimeSpecifier,
+) -> Option<GoalDefinition> {
+    match node {
+        DurativeActionGoalDefinition::Timed(PrefTimedGD::Required(TimedGD::At(at, gd)))
+            if at == time =>
+        {
+            Some(gd)
+        }
+        DurativeActionGoalDefinition::And(gds) => Some(GoalDefinition::new_and(
+            gds.into_iter()
+                .map(|gd| timed_condition(gd, time))
+                .collect::<Option<Vec<_>>>()?,
+        )),
+        DurativeActionGoalDefinition::Forall(variables, gd) => Some(GoalDefinition::new_forall(
+            variables,
+            timed_condition(*gd, time)?,
+        )),
+        DurativeActionGoalDefinition::Timed(_) => None,
+    }
+}
+
+/// Converts an expression of a durative action into one of an action, or returns `None`
+/// if it assigns a function.
+fn f_exp(node: FExpDa) -> Option<FExp> {
+    Some(match node {
+        FExpDa::FExp(exp) => exp,
+        FExpDa::BinaryOp(op, lhs, rhs) => FExp::new_binary_op(op, f_exp(*lhs)?, f_exp(*rhs)?),
+        FExpDa::MultiOp(op, lhs, rhs) => FExp::new_multi_op(
+            op,
+            f_exp(*lhs)?,
+            rhs.into_iter().map(f_exp).collect::<Option<Vec<_>>>()?,
+        ),
+        FExpDa::Negative(exp) => FExp::new_negative(f_exp(*exp)?),
+        FExpDa::Assign(..) | FExpDa::Duration => return None,
+    })
+}
+
+/// Gets the goal that no instance of an action with the `parameters` is running.
+fn not_running(parameters: &TypedVariables, running: AtomicFormula<Term>) -> GoalDefinition {
+    let gd = GoalDefinition::new_not(GoalDefinition::new_atomic_formula(running));
+    if parameters.is_empty() {
+        gd
+    } else {
+        GoalDefinition::new_forall(parameters.clone(), gd)
+    }
+}
+
+fn goal(gd: GoalDefinition) -> PreconditionGoalDefinition {
+    PreconditionGoalDefinition::new_preference(gd.into())
+}
+
+/// Collects the predicates and functions changed by effects.
+#[derive(Default)]
+struct ChangedSymbols<'ast>(HashSet<&'ast Name>);
+
+impl ChangedSymbols<'_> {
+    /// Determines whether the `condition` uses a changed predicate or function.
+    fn affects(&self, condition: &PreconditionGoalDefinition) -> bool {
+        let mut used = UsedSymbols::default();
+        used.visit_precondition_goal_definition(condition);
+        used.0.iter().any(|name| self.0.contains(name))
+    }
+}
+
+impl<'ast> Visit<'ast> for ChangedSymbols<'ast> {
+    fn visit_goal_definition(&mut self, _node: &'ast GoalDefinition) {
+        // The conditions of effects do not change anything.
+    }
+
+    fn visit_p_effect(&mut self, node: &'ast PEffect) {
+        match node {
+            PEffect::AtomicFormula(af) | PEffect::NotAtomicFormula(af) => {
+                self.visit_term_atomic_formula(af)
+            }
+            PEffect::AssignNumericFluent(_, head, _) => self.visit_f_head(head),
+            PEffect::AssignObjectFluent(function, _) => self.visit_function_term(function),
+        }
+    }
+
+    fn visit_predicate(&mut self, node: &'ast Predicate) {
+        self.0.insert(node);
+    }
+
+    fn visit_function_symbol(&mut self, node: &'ast FunctionSymbol) {
+        self.0.insert(node);
+    }
+}
+
+/// Collects the predicates and functions used by a condition.
+#[derive(Default)]
+struct UsedSymbols<'ast>(Vec<&'ast Name>);
+
+impl<'ast> Visit<'ast> for UsedSymbols<'ast> {
+    fn visit_predicate(&mut self, node: &'ast Predicate) {
+        self.0.push(node);
+    }
+
+    fn visit_function_symbol(&mut self, node: &'ast FunctionSymbol) {
+        self.0.push(node);
+    }
+}
+
+/// Determines whether `?duration` is used.
+struct UsesDuration(bool);
+
+impl<'ast> Visit<'ast> for UsesDuration {
+    fn visit_variable(&mut self, node: &'ast Variable) {
+        self.0 |= **node == DURATION;
+    }
+
+    fn visit_f_exp_da(&mut self, node: &'ast FExpDa) {
+        self.0 |= matches!(node, FExpDa::Duration);
+        visit::walk_f_exp_da(self, node)
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::types::Requirements;
+    use crate::Parser;
+
+    #[test]
+    fn splits_conditions_and_effects_by_time() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :durative-actions :duration-inequalities :numeric-fluents)
+                (:predicates (at ?x) (road ?x ?y) (free))
+                (:functions (fuel))
+                (:durative-action drive
+                    :parameters (?x ?y)
+                    :duration (and (>= ?duration 1) (<= ?duration 5))
+                    :condition (and (at start (at ?x)) (at end (free)) (over all (road ?x ?y)))
+                    :effect (and (at start (not (at ?x)))
+                                 (at start (decrease (fuel) 1))
+                                 (forall (?z) (at end (not (road ?z ?x))))
+                                 (when (at end (free)) (at end (at ?y))))))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            "(define (problem p) (:domain d) (:init (at a) (road a b)) (:goal (at b)))",
+        )
+        .unwrap();
+
+        let (domain, problem, snaps) = split_durative_actions(domain, problem);
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([
+                Requirement::NumericFluents,
+                Requirement::NegativePreconditions,
+                Requirement::ConditionalEffects,
+            ])
+        );
+        assert_eq!(
+            domain.predicates().to_string(),
+            "(:predicates (at ?x) (road ?x ?y) (free) (running-drive ?x ?y))"
+        );
+        assert_eq!(
+            domain.structure()[0].to_string(),
+            "(:action drive-start\n  \
+               :parameters (?x ?y)\n  \
+               :precondition (and (not (running-drive ?x ?y)) (at ?x) (road ?x ?y))\n  \
+               :effect (and (not (at ?x)) (decrease (fuel) 1) (running-drive ?x ?y)))"
+        );
+        assert_eq!(
+            domain.structure()[1].to_string(),
+            "(:action drive-end\n  \
+               :parameters (?x ?y)\n  \
+               :precondition (and (running-drive ?x ?y) (free) (road ?x ?y))\n  \
+               :effect (and (forall (?z) (not (road ?z ?x))) (when (free) (at ?y)) (not (running-drive ?x ?y))))"
+        );
+        assert_eq!(
+            problem.requirements(),
+            &Requirements::new([
+                Requirement::NegativePreconditions,
+                Requirement::UniversalPreconditions,
+            ])
+        );
+
+        assert_eq!(snaps.len(), 1);
+        assert_eq!(snaps[0].action().to_string(), "drive");
+        assert_eq!(snaps[0].running().to_string(), "running-drive");
+        assert_eq!(
+            snaps[0].duration().as_ref().unwrap().to_string(),
+            "(and (>= ?duration 1) (<= ?duration 5))"
+        );
+        assert_eq!(snaps[0].invariant().to_string(), "(road ?x ?y)");
+    }
+
+    #[test]
+    fn requires_invariants_at_start_unless_start_effects_change_them() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :durative-actions :numeric-fluents)
+                (:predicates (p) (q) (r))
+                (:functions (fuel))
+                (:durative-action act
+                    :parameters ()
+                    :duration (= ?duration 1)
+                    :condition (and (over all (p)) (over all (q)) (over all (> (fuel) 0)))
+                    :effect (and (at start (q)) (at start (decrease (fuel) 1)) (at end (r)))))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (r)))").unwrap();
+
+        let (domain, _, snaps) = split_durative_actions(domain, problem);
+        assert!(domain.structure()[0]
+            .to_string()
+            .contains(":precondition (and (not (running-act)) (p))"));
+        assert!(domain.structure()[1]
+            .to_string()
+            .contains(":precondition (and (running-act) (p) (q) (> (fuel) 0))"));
+        assert_eq!(
+            snaps[0].invariant().to_string(),
+            "(and (p) (q) (> (fuel) 0))"
+        );
+    }
+
+    #[test]
+    fn keeps_actions_that_use_their_duration() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :durative-actions :numeric-fluents)
+                (:functions (fuel))
+                (:durative-action wait
+                    :parameters ()
+                    :duration (= ?duration 2)
+                    :condition (and)
+                    :effect (at end (decrease (fuel) ?duration))))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (and)))").unwrap();
+
+        let (domain, _, snaps) = split_durative_actions(domain, problem);
+        assert!(snaps.is_empty());
+        assert!(domain
+            .requirements()
+            .contains(&Requirement::DurativeActions));
+        assert!(matches!(
+            domain.structure()[0],
+            StructureDef::DurativeAction(_)
+        ));
+    }
+}
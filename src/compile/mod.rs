@@ -0,0 +1,142 @@
+//! Provides compilations that rewrite domains and problems into equivalent ones
+//! that need fewer [`Requirement`]s.
+//!
+//! Many planners only support a subset of PDDL. Each compilation in this module removes
+//! the use of one language feature, e.g. [`split_disjunctive_preconditions`] replaces
+//! actions with disjunctive preconditions by one action per disjunct, so that the
+//! [`:disjunctive-preconditions`](Requirement::DisjunctivePreconditions) requirement is no
+//! longer needed. The results are ordinary [`Domain`] and
+//! [`Problem`](crate::Problem) values that can be printed as PDDL.
+
+mod conditional_effects;
+mod derived_predicates;
+mod disjunctions;
+mod durative_actions;
+mod negative_preconditions;
+mod quantifiers;
+mod timed_initial_literals;
+mod typing;
+
+pub use conditional_effects::{sequence_conditional_effects, split_conditional_effects};
+pub use derived_predicates::inline_derived_predicates;
+pub use disjunctions::split_disjunctive_preconditions;
+pub use durative_actions::{split_durative_actions, SnapActions};
+pub use negative_preconditions::compile_negative_preconditions;
+pub use quantifiers::expand_quantifiers;
+pub use timed_initial_literals::compile_timed_initial_literals;
+pub use typing::compile_typing;
+
+use crate::types::{Domain, Name, Requirement, Requirements, StructureDef};
+use std::collections::HashSet;
+
+/// Generates names that do not clash with the names already in use.
+#[derive(Debug, Default)]
+pub(crate) struct FreshNames {
+    taken: HashSet<Name>,
+}
+
+impl FreshNames {
+    /// Constructs a generator that avoids the given names.
+    pub fn new<'a, I: IntoIterator<Item = &'a Name>>(taken: I) -> Self {
+        Self {
+            taken: taken.into_iter().cloned().collect(),
+        }
+    }
+
+    /// Returns `name` if it is not taken yet, or `name` with the smallest numeric suffix
+    /// that is not taken otherwise, and marks the result as taken.
+    pub fn fresh(&mut self, name: &str) -> Name {
+        let mut candidate = Name::new(name);
+        let mut suffix = 1;
+        while self.taken.contains(&candidate) {
+            candidate = Name::new(format!("{name}-{suffix}"));
+            suffix += 1;
+        }
+        self.taken.insert(candidate.clone());
+        candidate
+    }
+}
+
+/// Gets the names of the actions and durative actions of the `domain`.
+pub(crate) fn action_names(domain: &Domain) -> impl Iterator<Item = &Name> {
+    domain.structure().iter().filter_map(|def| match def {
+        StructureDef::Action(action) => Some(&**action.symbol()),
+        StructureDef::DurativeAction(action) => Some(&**action.symbol()),
+        StructureDef::Derived(_) => None,
+    })
+}
+
+/// Removes `removed` from the `requirements`, replacing shorthands such as
+/// [`:adl`](Requirement::Adl) that imply it by their remaining requirements.
+pub(crate) fn remove_requirement(
+    requirements: &Requirements,
+    removed: Requirement,
+) -> Requirements {
+    let mut result = Vec::with_capacity(requirements.len());
+    for requirement in requirements.iter() {
+        let expanded = requirement.expand();
+        let replacement = if expanded.len() > 1 && expanded.contains(&removed) {
+            expanded
+        } else {
+            vec![*requirement]
+        };
+        for requirement in replacement {
+            if requirement != removed && !result.contains(&requirement) {
+                result.push(requirement);
+            }
+        }
+    }
+    Requirements::new(result)
+}
+
+/// Adds `added` to the `requirements` unless they already imply it.
+pub(crate) fn add_requirement(requirements: &Requirements, added: Requirement) -> Requirements {
+    let mut result = requirements.clone();
+    if !requirements.to_effective().contains(&added) {
+        result.0.push(added);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_names_avoid_taken_names() {
+        let taken = [Name::new("move"), Name::new("move-1")];
+        let mut names = FreshNames::new(&taken);
+        assert_eq!(names.fresh("load"), "load");
+        assert_eq!(names.fresh("move"), "move-2");
+        assert_eq!(names.fresh("load"), "load-1");
+    }
+
+    #[test]
+    fn remove_requirement_expands_shorthands() {
+        let requirements = Requirements::new([Requirement::Typing, Requirement::Adl]);
+        assert_eq!(
+            remove_requirement(&requirements, Requirement::DisjunctivePreconditions),
+            Requirements::new([
+                Requirement::Typing,
+                Requirement::Strips,
+                Requirement::NegativePreconditions,
+                Requirement::Equality,
+                Requirement::ExistentialPreconditions,
+                Requirement::UniversalPreconditions,
+                Requirement::ConditionalEffects,
+            ])
+        );
+        assert_eq!(
+            remove_requirement(&requirements, Requirement::Typing),
+            Requirements::new([
+                Requirement::Strips,
+                Requirement::NegativePreconditions,
+                Requirement::DisjunctivePreconditions,
+                Requirement::Equality,
+                Requirement::ExistentialPreconditions,
+                Requirement::UniversalPreconditions,
+                Requirement::ConditionalEffects,
+            ])
+        );
+    }
+}
@@ -0,0 +1,786 @@
+//! Compiles away negative preconditions by introducing complement predicates.
+
+use crate::compile::{add_requirement, remove_requirement, FreshNames};
+use crate::fold::{self, Fold};
+use crate::index::ProblemIndex;
+use crate::types::{
+    AtomicFormula, AtomicFormulaSkeleton, CEffect, ConditionalEffect, Domain, Effects,
+    ForallCEffect, GoalDefinition, InitElement, Literal, Name, PEffect, Predicate,
+    PredicateAtomicFormula, Problem, Requirement, StructureDef, Term, TypedVariables, Variable,
+    WhenCEffect,
+};
+use crate::validate::{infer_problem_requirements, infer_requirements};
+use crate::visit::Visit;
+use std::collections::{HashMap, HashSet};
+
+/// Replaces negated atomic formulas in the goals of the `domain` and `problem` by
+/// complement predicates, so that the result no longer needs
+/// [`:negative-preconditions`](Requirement::NegativePreconditions).
+///
+/// For every predicate `p` that occurs negated in a precondition, goal, effect condition,
+/// derived predicate or constraint, a complement predicate `not-p` with the same parameters
+/// is declared, or `not-p` with a numeric suffix if that name is already taken. Goals with
+/// negations are converted into their [negation normal form](GoalDefinition::to_nnf), after
+/// which each `(not (p ...))` becomes `(not-p ...)`. Every effect that adds or deletes
+/// `(p ...)` also deletes or adds `(not-p ...)`, so that exactly one of them holds at any time.
+/// Since deletes are applied before adds, an action that both deletes and adds the same
+/// `(p ...)` makes it true, in which case its complement is only deleted. The complement add
+/// of a delete is therefore made conditional on every add of `p` in the action not applying
+/// to the same arguments, such as `(when (not (= ?x ?y)) (not-p ?y))` for the effect
+/// `(and (p ?x) (not (p ?y)))`. This requires [`:equality`](Requirement::Equality) and
+/// [`:conditional-effects`](Requirement::ConditionalEffects), which are added as needed,
+/// and keeps [`:negative-preconditions`](Requirement::NegativePreconditions) for the negated
+/// equalities. The effects of durative actions are matched by their arguments as written,
+/// within each timed effect.
+///
+/// Under the closed-world assumption, the initial state of the problem receives a
+/// `(not-p ...)` fact for each combination of objects for which `(p ...)` is not initially
+/// true, and timed initial literals of `p` are mirrored for `not-p`.
+///
+/// Negated equalities and negated derived predicates cannot be maintained by effects and
+/// are kept as-is, in which case [`:negative-preconditions`](Requirement::NegativePreconditions)
+/// remains a requirement. Since the negation normal form may introduce disjunctions and
+/// quantifiers, the corresponding requirements are added as needed.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::compile::compile_negative_preconditions;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :negative-preconditions)
+///     (:predicates (on ?x) (free))
+///     (:action put
+///         :parameters (?x)
+///         :precondition (and (free) (not (on ?x)))
+///         :effect (and (on ?x) (not (free)))))"#).unwrap();
+///
+/// let problem = Problem::from_str(r#"(define (problem p)
+///     (:domain d)
+///     (:objects a b)
+///     (:init (free) (on a))
+///     (:goal (on b)))"#).unwrap();
+///
+/// let (domain, problem) = compile_negative_preconditions(domain, problem);
+/// assert!(domain.requirements().is_empty());
+/// assert_eq!(
+///     domain.structure()[0].to_string(),
+///     "(:action put\n  :parameters (?x)\n  :precondition (and (free) (not-on ?x))\n  :effect (and (on ?x) (not (not-on ?x)) (not (free))))"
+/// );
+/// assert_eq!(
+///     problem.init().to_string(),
+///     "(:init\n  (free)\n  (on a)\n  (not-on b))"
+/// );
+/// ```
+pub fn compile_negative_preconditions(domain: Domain, problem: Problem) -> (Domain, Problem) {
+    let derived: HashSet<&Name> = domain
+        .structure()
+        .iter()
+        .filter_map(|def| match def {
+            StructureDef::Derived(derived) => Some(derived.predicate().name()),
+            _ => None,
+        })
+        .collect();
+    let mut negated = NegatedPredicates::default();
+    negated.visit_domain(&domain);
+    negated.visit_problem(&problem);
+    // The conditions of complement adds negate the conditions of conditional adds, whose
+    // predicates need complements in turn.
+    loop {
+        let count = negated.0.len();
+        let guards: Vec<_> = domain
+            .structure()
+            .iter()
+            .filter_map(|def| match def {
+                StructureDef::Action(action) => action.effect().as_ref(),
+                _ => None,
+            })
+            .flat_map(|effects| {
+                complement_guards(effects, |predicate| negated.0.contains(predicate))
+            })
+            .flatten()
+            .collect();
+        guards
+            .iter()
+            .for_each(|guard| negated.collect(&guard.to_nnf()));
+        if negated.0.len() == count {
+            break;
+        }
+    }
+
+    let mut names = FreshNames::new(domain.predicates().iter().map(AtomicFormulaSkeleton::name));
+    let mut complements = Complements(HashMap::new());
+    let mut skeletons = Vec::new();
+    for skeleton in domain.predicates().iter() {
+        let predicate = skeleton.predicate();
+        if !negated.0.contains(predicate)
+            || derived.contains(skeleton.name())
+            || complements.0.contains_key(predicate)
+        {
+            continue;
+        }
+        let complement = Predicate::from(names.fresh(&format!("not-{}", skeleton.name())));
+        complements.0.insert(predicate.clone(), complement.clone());
+        skeletons.push(AtomicFormulaSkeleton::new(
+            complement,
+            skeleton.variables().clone(),
+        ));
+    }
+
+    let facts = initial_facts(&domain, &problem, &complements);
+    let mut domain = complements.fold_domain(domain);
+    let mut problem = complements.fold_problem(problem);
+    domain.predicates.0.extend(skeletons);
+    problem.init.0.extend(facts);
+
+    let used = infer_requirements(&domain);
+    if !used.contains(&Requirement::NegativePreconditions) {
+        domain.requirements =
+            remove_requirement(domain.requirements(), Requirement::NegativePreconditions);
+    }
+    let problem_used = infer_problem_requirements(&problem);
+    if !problem_used.contains(&Requirement::NegativePreconditions) {
+        problem.requires =
+            remove_requirement(problem.requirements(), Requirement::NegativePreconditions);
+    }
+    for requirement in [
+        Requirement::Equality,
+        Requirement::ConditionalEffects,
+        Requirement::DisjunctivePreconditions,
+        Requirement::ExistentialPreconditions,
+        Requirement::UniversalPreconditions,
+    ] {
+        if used.contains(&requirement) {
+            domain.requirements = add_requirement(domain.requirements(), requirement);
+        }
+        if problem_used.contains(&requirement) {
+            problem.requires = add_requirement(problem.requirements(), requirement);
+        }
+    }
+    (domain, problem)
+}
+
+/// Collects the predicates that occur negated in the negation normal form of a goal.
+#[derive(Default)]
+struct NegatedPredicates(HashSet<Predicate>);
+
+impl NegatedPredicates {
+    fn collect(&mut self, node: &GoalDefinition) {
+        match node {
+            GoalDefinition::Literal(Literal::NotAtomicFormula(AtomicFormula::Predicate(af))) => {
+                self.0.insert(af.predicate().clone());
+            }
+            GoalDefinition::And(gds) | GoalDefinition::Or(gds) => {
+                gds.iter().for_each(|gd| self.collect(gd))
+            }
+            GoalDefinition::Exists(_, gd) | GoalDefinition::ForAll(_, gd) => self.collect(gd),
+            _ => {}
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for NegatedPredicates {
+    fn visit_goal_definition(&mut self, node: &'ast GoalDefinition) {
+        if has_negation(node) {
+            self.collect(&node.to_nnf());
+        }
+    }
+}
+
+/// Replaces negated predicates by their complements and maintains the complements
+/// in effects.
+struct Complements(HashMap<Predicate, Predicate>);
+
+impl Complements {
+    /// Replaces the negated atomic formulas of a goal in negation normal form.
+    fn replace(&self, node: GoalDefinition) -> GoalDefinition {
+        match node {
+            GoalDefinition::Literal(Literal::NotAtomicFormula(AtomicFormula::Predicate(af)))
+                if self.0.contains_key(af.predicate()) =>
+            {
+                let complement = self.0[af.predicate()].clone();
+                GoalDefinition::new_atomic_formula(AtomicFormula::new_predicate(
+                    complement, af.values,
+                ))
+            }
+            GoalDefinition::And(gds) => {
+                GoalDefinition::And(gds.into_iter().map(|gd| self.replace(gd)).collect())
+            }
+            GoalDefinition::Or(gds) => {
+                GoalDefinition::Or(gds.into_iter().map(|gd| self.replace(gd)).collect())
+            }
+            GoalDefinition::Exists(variables, gd) => {
+                GoalDefinition::new_exists(variables, self.replace(*gd))
+            }
+            GoalDefinition::ForAll(variables, gd) => {
+                GoalDefinition::new_forall(variables, self.replace(*gd))
+            }
+            node => node,
+        }
+    }
+
+    /// Gets the complement of the atomic formula added or deleted by the `effect`.
+    fn complement_atom(&self, effect: &PEffect) -> Option<AtomicFormula<Term>> {
+        let (PEffect::AtomicFormula(AtomicFormula::Predicate(af))
+        | PEffect::NotAtomicFormula(AtomicFormula::Predicate(af))) = effect
+        else {
+            return None;
+        };
+        let complement = self.0.get(af.predicate())?.clone();
+        Some(AtomicFormula::new_predicate(
+            complement,
+            af.values().iter().cloned(),
+        ))
+    }
+
+    /// Gets the effect on the complement of the atomic formula changed by the `effect`
+    /// of an action, along with the condition under which it applies unless it always does.
+    ///
+    /// The conditions of deletes are taken from the `guards` in order, see
+    /// [`complement_guards`].
+    fn complement(
+        &mut self,
+        effect: &PEffect,
+        guards: &mut impl Iterator<Item = Option<GoalDefinition>>,
+    ) -> Option<(PEffect, Option<GoalDefinition>)> {
+        let atom = self.complement_atom(effect)?;
+        if let PEffect::AtomicFormula(_) = effect {
+            return Some((PEffect::new_not(atom), None));
+        }
+        let guard = guards.next().expect("a guard for each deleted atom")?;
+        let guard = (!guard.is_true()).then(|| self.fold_goal_definition(guard));
+        Some((PEffect::new(atom), guard))
+    }
+
+    /// Adds the effects on the complements to the `effects` of an action.
+    fn complement_effects(
+        &mut self,
+        effects: Effects,
+        guards: &mut impl Iterator<Item = Option<GoalDefinition>>,
+    ) -> Effects {
+        let mut result = Vec::with_capacity(effects.len());
+        for effect in effects {
+            match effect {
+                CEffect::Effect(effect) => {
+                    let complement = self.complement(&effect, guards);
+                    result.push(CEffect::Effect(effect));
+                    match complement {
+                        Some((complement, None)) => result.push(CEffect::Effect(complement)),
+                        Some((complement, Some(guard))) => result
+                            .push(CEffect::new_when(guard, ConditionalEffect::new(complement))),
+                        None => {}
+                    }
+                }
+                CEffect::When(WhenCEffect { condition, effect }) => {
+                    let condition = self.fold_goal_definition(condition);
+                    let mut effects = Vec::new();
+                    let mut guarded = Vec::new();
+                    for effect in effect {
+                        let complement = self.complement(&effect, guards);
+                        effects.push(effect);
+                        match complement {
+                            Some((complement, None)) => effects.push(complement),
+                            Some((complement, Some(guard))) => {
+                                let condition =
+                                    GoalDefinition::new_and([condition.clone(), guard]).simplify();
+                                guarded.push(CEffect::new_when(
+                                    condition,
+                                    ConditionalEffect::new(complement),
+                                ));
+                            }
+                            None => {}
+                        }
+                    }
+                    let effect = match effects.len() {
+                        1 => ConditionalEffect::new(effects.remove(0)),
+                        _ => ConditionalEffect::new_and(effects),
+                    };
+                    result.push(CEffect::new_when(condition, effect));
+                    result.extend(guarded);
+                }
+                CEffect::Forall(ForallCEffect { variables, effects }) => {
+                    let variables = self.fold_typed_variables(variables);
+                    let effects = self.complement_effects(effects, guards);
+                    result.push(CEffect::new_forall(variables, effects));
+                }
+            }
+        }
+        Effects::new_and(result)
+    }
+
+    /// Gets the `effects` of a durative action along with the effects on their complements.
+    ///
+    /// A delete of an atom in `effects` that the `effects` also add has no effect on the
+    /// complement, since the add takes precedence.
+    fn with_complements(&self, effects: Vec<PEffect>) -> Vec<PEffect> {
+        let added: Vec<_> = effects
+            .iter()
+            .filter_map(|effect| match effect {
+                PEffect::AtomicFormula(af) => Some(af.clone()),
+                _ => None,
+            })
+            .collect();
+        let mut result = Vec::new();
+        for effect in effects {
+            let complement = self
+                .complement_atom(&effect)
+                .and_then(|atom| match &effect {
+                    PEffect::AtomicFormula(_) => Some(PEffect::new_not(atom)),
+                    PEffect::NotAtomicFormula(deleted) if added.contains(deleted) => None,
+                    _ => Some(PEffect::new(atom)),
+                });
+            result.push(effect);
+            result.extend(complement);
+        }
+        result
+    }
+}
+
+impl Fold for Complements {
+    fn fold_goal_definition(&mut self, node: GoalDefinition) -> GoalDefinition {
+        if has_negation(&node) {
+            self.replace(node.to_nnf())
+        } else {
+            node
+        }
+    }
+
+    fn fold_effects(&mut self, node: Effects) -> Effects {
+        let guards = complement_guards(&node, |predicate| self.0.contains_key(predicate));
+        self.complement_effects(node, &mut guards.into_iter())
+    }
+
+    fn fold_conditional_effect(&mut self, node: ConditionalEffect) -> ConditionalEffect {
+        let node = fold::fold_conditional_effect(self, node);
+        let mut effects = self.with_complements(node.into_iter().collect());
+        match effects.len() {
+            1 => ConditionalEffect::new(effects.remove(0)),
+            _ => ConditionalEffect::new_and(effects),
+        }
+    }
+}
+
+/// Gets the conditions under which the deletes of `complemented` predicates in the
+/// `effects` of an action add their complements, in the order of the deletes.
+///
+/// Since deletes are applied before adds, the complement of a deleted atom must only be
+/// added if no add of the action makes the atom true again. For every add of the same
+/// predicate, the condition therefore requires that the add does not apply or that its
+/// arguments differ from those of the delete. The condition is `None` if some add always
+/// makes the deleted atom true.
+fn complement_guards(
+    effects: &Effects,
+    complemented: impl Fn(&Predicate) -> bool,
+) -> Vec<Option<GoalDefinition>> {
+    let mut changes = Changes::default();
+    changes.collect(effects, &mut Vec::new());
+    let mut variables = VariableNames::default();
+    variables.visit_effects(effects);
+
+    let mut names = FreshNames::new(&variables.0);
+    changes
+        .deletes
+        .iter()
+        .filter(|delete| complemented(delete.atom.predicate()))
+        .map(|delete| delete.guard(&changes.adds, &mut names))
+        .collect()
+}
+
+/// The atomic formulas added and deleted by the effects of an action.
+#[derive(Default)]
+struct Changes<'a> {
+    adds: Vec<Change<'a>>,
+    deletes: Vec<Change<'a>>,
+    lists: usize,
+}
+
+/// An atomic formula added or deleted by an effect, along with the effect list it occurs
+/// in, the condition of that list and the universally quantified effects enclosing it.
+struct Change<'a> {
+    atom: &'a PredicateAtomicFormula<Term>,
+    list: usize,
+    condition: Option<&'a GoalDefinition>,
+    scopes: Vec<(usize, &'a TypedVariables)>,
+}
+
+impl<'a> Changes<'a> {
+    /// Collects the changes of the `effects` in the universally quantified `scopes`.
+    fn collect(&mut self, effects: &'a Effects, scopes: &mut Vec<(usize, &'a TypedVariables)>) {
+        let list = self.next_list();
+        for effect in effects.iter() {
+            match effect {
+                CEffect::Effect(effect) => self.push(effect, list, None, scopes),
+                CEffect::When(when) => {
+                    let list = self.next_list();
+                    let effects = match &when.effect {
+                        ConditionalEffect::Single(effect) => std::slice::from_ref(effect),
+                        ConditionalEffect::All(effects) => effects.as_slice(),
+                    };
+                    for effect in effects {
+                        self.push(effect, list, Some(&when.condition), scopes);
+                    }
+                }
+                CEffect::Forall(forall) => {
+                    let scope = self.next_list();
+                    scopes.push((scope, &forall.variables));
+                    self.collect(&forall.effects, scopes);
+                    scopes.pop();
+                }
+            }
+        }
+    }
+
+    fn push(
+        &mut self,
+        effect: &'a PEffect,
+        list: usize,
+        condition: Option<&'a GoalDefinition>,
+        scopes: &[(usize, &'a TypedVariables)],
+    ) {
+        let (changes, af) = match effect {
+            PEffect::AtomicFormula(AtomicFormula::Predicate(af)) => (&mut self.adds, af),
+            PEffect::NotAtomicFormula(AtomicFormula::Predicate(af)) => (&mut self.deletes, af),
+            _ => return,
+        };
+        changes.push(Change {
+            atom: af,
+            list,
+            condition,
+            scopes: scopes.to_vec(),
+        });
+    }
+
+    fn next_list(&mut self) -> usize {
+        self.lists += 1;
+        self.lists
+    }
+}
+
+impl Change<'_> {
+    /// Gets the condition under which none of the `adds` makes this deleted atom true,
+    /// or `None` if one of them always does.
+    ///
+    /// Variables of universally quantified adds that do not enclose the delete are
+    /// existentially quantified in the condition, and renamed using `names` where they
+    /// clash with variables of the delete.
+    fn guard(&self, adds: &[Change], names: &mut FreshNames) -> Option<GoalDefinition> {
+        let mut guards = Vec::new();
+        for add in adds {
+            if add.atom.predicate() != self.atom.predicate() {
+                continue;
+            }
+            let shared = add
+                .scopes
+                .iter()
+                .zip(&self.scopes)
+                .take_while(|(add, delete)| add.0 == delete.0)
+                .count();
+            let bound = self.scopes[shared..]
+                .iter()
+                .flat_map(|(_, variables)| variables.iter());
+            let mut renaming = Renaming(Vec::new());
+            for variable in add.scopes[shared..]
+                .iter()
+                .flat_map(|(_, variables)| variables.iter())
+            {
+                let variable = variable.value();
+                if bound.clone().any(|bound| bound.value() == variable) {
+                    let renamed = Variable::new(names.fresh(variable));
+                    renaming.0.push((variable.clone(), renamed));
+                }
+            }
+
+            let values: Vec<_> = add
+                .atom
+                .values()
+                .iter()
+                .map(|value| renaming.fold_term(value.clone()))
+                .collect();
+            let Some(equalities) = unify(&values, self.atom.values()) else {
+                continue;
+            };
+            let variables: TypedVariables = add.scopes[shared..]
+                .iter()
+                .flat_map(|(_, variables)| variables.iter().cloned())
+                .map(|variable| renaming.fold_typed_variable(variable))
+                .collect();
+            // The condition of an add in the same effect list holds whenever the delete applies.
+            let condition = add
+                .condition
+                .filter(|_| add.list != self.list)
+                .map(|condition| renaming.fold_goal_definition(condition.clone()));
+            if variables.is_empty() && equalities.is_empty() && condition.is_none() {
+                return None;
+            }
+
+            let mut applies: Vec<_> = condition.into_iter().chain(equalities).collect();
+            let mut applies = match applies.len() {
+                1 => applies.remove(0),
+                _ => GoalDefinition::new_and(applies),
+            };
+            if !variables.is_empty() {
+                applies = GoalDefinition::new_exists(variables, applies);
+            }
+            guards.push(GoalDefinition::new_not(applies));
+        }
+        Some(match guards.len() {
+            1 => guards.remove(0),
+            _ => GoalDefinition::new_and(guards),
+        })
+    }
+}
+
+/// Gets the equalities under which the `added` arguments equal the `deleted` ones, or
+/// `None` if they differ for every grounding.
+fn unify(added: &[Term], deleted: &[Term]) -> Option<Vec<GoalDefinition>> {
+    let mut equalities = Vec::new();
+    for (added, deleted) in added.iter().zip(deleted) {
+        match (added, deleted) {
+            _ if added == deleted => {}
+            (Term::Name(_), Term::Name(_)) => return None,
+            _ => equalities.push(GoalDefinition::new_atomic_formula(
+                AtomicFormula::new_equality(added.clone(), deleted.clone()),
+            )),
+        }
+    }
+    Some(equalities)
+}
+
+/// Renames variables.
+struct Renaming(Vec<(Variable, Variable)>);
+
+impl Fold for Renaming {
+    fn fold_variable(&mut self, node: Variable) -> Variable {
+        match self.0.iter().find(|(from, _)| *from == node) {
+            Some((_, to)) => to.clone(),
+            None => node,
+        }
+    }
+}
+
+/// Collects the names of the variables that occur in a node.
+#[derive(Default)]
+struct VariableNames(Vec<Name>);
+
+impl<'ast> Visit<'ast> for VariableNames {
+    fn visit_variable(&mut self, node: &'ast Variable) {
+        self.0.push((**node).clone());
+    }
+}
+
+/// Determines whether the goal contains a negation or an implication.
+fn has_negation(node: &GoalDefinition) -> bool {
+    match node {
+        GoalDefinition::Not(_)
+        | GoalDefinition::Imply(_, _)
+        | GoalDefinition::Literal(Literal::NotAtomicFormula(_)) => true,
+        GoalDefinition::And(gds) | GoalDefinition::Or(gds) => gds.iter().any(has_negation),
+        GoalDefinition::Exists(_, gd) | GoalDefinition::ForAll(_, gd) => has_negation(gd),
+        GoalDefinition::AtomicFormula(_)
+        | GoalDefinition::Literal(Literal::AtomicFormula(_))
+        | GoalDefinition::FComp(_) => false,
+    }
+}
+
+/// Gets the initial complement facts of the objects for which the original predicate
+/// is not initially true, along with the timed initial literals of the complements.
+fn initial_facts(
+    domain: &Domain,
+    problem: &Problem,
+    complements: &Complements,
+) -> Vec<InitElement> {
+    let mut initial = HashSet::new();
+    let mut facts = Vec::new();
+    for element in problem.init().iter() {
+        match element {
+            InitElement::Literal(Literal::AtomicFormula(AtomicFormula::Predicate(af))) => {
+                initial.insert((af.predicate(), af.values()));
+            }
+            InitElement::At(time, literal) => {
+                let (Literal::AtomicFormula(AtomicFormula::Predicate(af))
+                | Literal::NotAtomicFormula(AtomicFormula::Predicate(af))) = literal
+                else {
+                    continue;
+                };
+                let Some(complement) = complements.0.get(af.predicate()) else {
+                    continue;
+                };
+                let atom = AtomicFormula::new_predicate(complement.clone(), af.values().to_vec());
+                let literal = match literal {
+                    Literal::AtomicFormula(_) => Literal::new_not(atom),
+                    Literal::NotAtomicFormula(_) => Literal::new(atom),
+                };
+                facts.push(InitElement::At(*time, literal));
+            }
+            _ => {}
+        }
+    }
+
+    let index = ProblemIndex::new(domain, problem);
+    let mut initial_facts = Vec::new();
+    for skeleton in domain.predicates().iter() {
+        let Some(complement) = complements.0.get(skeleton.predicate()) else {
+            continue;
+        };
+        let mut tuples = vec![Vec::new()];
+        for variable in skeleton.variables().iter() {
+            let objects = index.objects_of_type(variable.type_());
+            tuples = tuples
+                .iter()
+                .flat_map(|tuple: &Vec<Name>| {
+                    objects.iter().map(|&object| {
+                        let mut tuple = tuple.clone();
+                        tuple.push(index.objects()[object].value().clone());
+                        tuple
+                    })
+                })
+                .collect();
+        }
+        for tuple in tuples {
+            if !initial.contains(&(skeleton.predicate(), tuple.as_slice())) {
+                let atom = AtomicFormula::new_predicate(complement.clone(), tuple);
+                initial_facts.push(InitElement::Literal(Literal::new(atom)));
+            }
+        }
+    }
+    initial_facts.extend(facts);
+    initial_facts
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::types::Requirements;
+    use crate::Parser;
+
+    #[test]
+    fn replaces_negations_and_maintains_complements() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :typing :adl :timed-initial-literals)
+                (:types a b)
+                (:predicates (p ?x - a) (q ?x - a ?y - b) (not-p))
+                (:action act
+                    :parameters (?x - a)
+                    :precondition (not (or (p ?x) (forall (?y - b) (q ?x ?y))))
+                    :effect (and (p ?x) (when (not (q ?x ?x)) (not (p ?x))))))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem p)
+                (:domain d)
+                (:objects a1 a2 - a b1 - b)
+                (:init (p a1) (q a2 b1) (at 10 (not (p a1))))
+                (:goal (not (p a2))))"#,
+        )
+        .unwrap();
+
+        let (domain, problem) = compile_negative_preconditions(domain, problem);
+        assert!(!domain
+            .requirements()
+            .to_effective()
+            .contains(&Requirement::NegativePreconditions));
+        assert_eq!(
+            domain.predicates().to_string(),
+            "(:predicates (p ?x - a) (q ?x - a ?y - b) (not-p) (not-p-1 ?x - a) (not-q ?x - a ?y - b))"
+        );
+
+        let action = domain.structure()[0].to_string();
+        assert!(action.contains(":precondition (and (not-p-1 ?x) (exists (?y - b) (not-q ?x ?y)))"));
+        // The unconditional add takes precedence over the conditional delete.
+        assert!(action
+            .contains(":effect (and (p ?x) (not (not-p-1 ?x)) (when (not-q ?x ?x) (not (p ?x))))"));
+
+        assert_eq!(
+            problem.init().to_string(),
+            "(:init\n  (p a1)\n  (q a2 b1)\n  (at 10 (not (p a1)))\n  (not-p-1 a2)\n  (not-q a1 b1)\n  (at 10 (not-p-1 a1)))"
+        );
+        assert_eq!(problem.goal().to_string(), "(:goal (not-p-1 a2))");
+
+        // The result is valid PDDL that prints the same after parsing it back.
+        let printed = domain.to_string();
+        assert_eq!(Domain::from_str(&printed).unwrap().to_string(), printed);
+    }
+
+    #[test]
+    fn prefers_adds_over_deletes_of_the_same_atom() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :negative-preconditions :conditional-effects)
+                (:predicates (p ?x) (q))
+                (:action act
+                    :parameters (?x ?y)
+                    :precondition (and (not (p ?x)) (not (q)))
+                    :effect (and (p ?x) (not (p ?x)) (not (p ?y))
+                                 (when (q) (and (not (q)) (q) (p ?y))))))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (and)))").unwrap();
+
+        let (domain, _) = compile_negative_preconditions(domain, problem);
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([
+                Requirement::NegativePreconditions,
+                Requirement::ConditionalEffects,
+                Requirement::Equality,
+            ])
+        );
+
+        // `(p ?y)` remains true if `?x` and `?y` are the same object or `(q)` holds.
+        let action = domain.structure()[0].to_string();
+        assert!(action.contains(
+            ":effect (and (p ?x) (not (not-p ?x)) (not (p ?x)) (not (p ?y)) \
+             (when (and (not (= ?x ?y)) (not-q)) (not-p ?y)) \
+             (when (q) (and (not (q)) (q) (not (not-q)) (p ?y) (not (not-p ?y))))))"
+        ));
+    }
+
+    #[test]
+    fn guards_complements_of_quantified_deletes() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :adl)
+                (:predicates (p ?x) (r ?x))
+                (:action act
+                    :parameters (?x)
+                    :precondition (not (p ?x))
+                    :effect (and (forall (?z) (not (p ?z)))
+                                 (forall (?z) (when (r ?z) (p ?z))))))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (and)))").unwrap();
+
+        let (domain, _) = compile_negative_preconditions(domain, problem);
+        let action = domain.structure()[0].to_string();
+        assert!(action.contains(
+            ":effect (and (forall (?z) (and (not (p ?z)) \
+             (when (forall (?z-1) (or (not-r ?z-1) (not (= ?z-1 ?z)))) (not-p ?z)))) \
+             (forall (?z) (when (r ?z) (and (p ?z) (not (not-p ?z)))))))"
+        ));
+    }
+
+    #[test]
+    fn keeps_negated_equalities_and_derived_predicates() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :negative-preconditions :equality :derived-predicates)
+                (:predicates (p ?x) (d ?x))
+                (:derived (d ?x) (p ?x))
+                (:action act
+                    :parameters (?x ?y)
+                    :precondition (and (not (= ?x ?y)) (not (d ?x)))
+                    :effect (p ?x)))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (and)))").unwrap();
+
+        let (compiled, problem) = compile_negative_preconditions(domain.clone(), problem);
+        assert_eq!(compiled.requirements(), domain.requirements());
+        assert_eq!(compiled.predicates(), domain.predicates());
+        assert_eq!(problem.init().len(), 0);
+    }
+}
@@ -0,0 +1,325 @@
+//! Compiles away quantifiers by expanding them over the objects of a problem.
+
+use crate::compile::remove_requirement;
+use crate::fold::{self, Fold};
+use crate::index::ProblemIndex;
+use crate::types::{
+    CEffect, ConGD, Domain, DurativeActionEffect, DurativeActionGoalDefinition, Effects,
+    ForallCEffect, GoalDefinition, Name, PreconditionGoalDefinition, PreconditionGoalDefinitions,
+    PrefConGD, PrefConGDs, Problem, Requirement, Term, TypedVariables, Variable,
+};
+use crate::validate::{infer_problem_requirements, infer_requirements};
+
+/// Replaces all quantifiers of the `domain` and `problem` by the conjunction or disjunction
+/// of their instances over the constants of the domain and the objects of the problem.
+///
+/// Universally quantified goals, preconditions, effects and constraints become conjunctions
+/// and existentially quantified goals become disjunctions, with one instance per combination
+/// of constants and objects whose types match the types of the quantified variables.
+/// Quantifiers over a type without objects thus become empty conjunctions or disjunctions.
+/// Since the expanded domain refers to the objects of the problem, it is specific to it.
+///
+/// [`:existential-preconditions`](Requirement::ExistentialPreconditions) and
+/// [`:universal-preconditions`](Requirement::UniversalPreconditions) are removed from
+/// the requirements of both the domain and the problem.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::compile::expand_quantifiers;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :typing :quantified-preconditions)
+///     (:types ball room)
+///     (:constants home - room)
+///     (:predicates (in ?b - ball ?r - room))
+///     (:action check
+///         :parameters (?b - ball)
+///         :precondition (exists (?r - room) (in ?b ?r))
+///         :effect (and)))"#).unwrap();
+///
+/// let problem = Problem::from_str(r#"(define (problem p)
+///     (:domain d)
+///     (:objects b1 b2 - ball kitchen - room)
+///     (:init (in b1 home))
+///     (:goal (forall (?b - ball) (in ?b kitchen))))"#).unwrap();
+///
+/// let (domain, problem) = expand_quantifiers(domain, problem);
+/// assert_eq!(domain.requirements().len(), 1);
+/// assert_eq!(
+///     domain.structure()[0].to_string(),
+///     "(:action check\n  :parameters (?b - ball)\n  :precondition (or (in ?b home) (in ?b kitchen))\n  :effect (and))"
+/// );
+/// assert_eq!(problem.goal().to_string(), "(:goal (and (in b1 kitchen) (in b2 kitchen)))");
+/// ```
+pub fn expand_quantifiers(domain: Domain, problem: Problem) -> (Domain, Problem) {
+    let mut expansion = QuantifierExpansion {
+        index: ProblemIndex::new(&domain, &problem),
+    };
+    let mut domain = expansion.fold_domain(domain);
+    let mut problem = expansion.fold_problem(problem);
+
+    let domain_requirements = infer_requirements(&domain);
+    let problem_requirements = infer_problem_requirements(&problem);
+    for requirement in [
+        Requirement::ExistentialPreconditions,
+        Requirement::UniversalPreconditions,
+    ] {
+        if !domain_requirements.contains(&requirement) {
+            domain.requirements = remove_requirement(domain.requirements(), requirement);
+        }
+        if !problem_requirements.contains(&requirement) {
+            problem.requires = remove_requirement(problem.requirements(), requirement);
+        }
+    }
+    (domain, problem)
+}
+
+/// Expands quantifiers bottom-up, so that the body of a quantifier is free of quantifiers
+/// by the time its variables are substituted.
+struct QuantifierExpansion {
+    index: ProblemIndex,
+}
+
+impl QuantifierExpansion {
+    /// Instantiates the `body` for each combination of objects matching the `variables`.
+    fn instantiate<T: Clone>(
+        &self,
+        variables: &TypedVariables,
+        body: T,
+        mut substitute: impl FnMut(&mut Substitution, T) -> T,
+    ) -> Vec<T> {
+        let mut bindings = vec![Vec::new()];
+        for variable in variables.iter() {
+            let objects = self.index.objects_of_type(variable.type_());
+            bindings = bindings
+                .iter()
+                .flat_map(|binding: &Vec<(Variable, Name)>| {
+                    objects.iter().map(move |&object| {
+                        let mut binding = binding.clone();
+                        let object = self.index.objects()[object].value().clone();
+                        binding.push((variable.value().clone(), object));
+                        binding
+                    })
+                })
+                .collect();
+        }
+
+        bindings
+            .into_iter()
+            .map(|bindings| substitute(&mut Substitution { bindings }, body.clone()))
+            .collect()
+    }
+}
+
+impl Fold for QuantifierExpansion {
+    fn fold_precondition_goal_definitions(
+        &mut self,
+        node: PreconditionGoalDefinitions,
+    ) -> PreconditionGoalDefinitions {
+        let mut values = Vec::with_capacity(node.len());
+        for value in node {
+            match value {
+                PreconditionGoalDefinition::Forall(variables, gds) => {
+                    let gds = self.fold_precondition_goal_definitions(gds);
+                    let instances = self.instantiate(
+                        &variables,
+                        gds,
+                        Substitution::fold_precondition_goal_definitions,
+                    );
+                    values.extend(instances.into_iter().flatten());
+                }
+                value => values.push(self.fold_precondition_goal_definition(value)),
+            }
+        }
+        PreconditionGoalDefinitions::new(values)
+    }
+
+    fn fold_goal_definition(&mut self, node: GoalDefinition) -> GoalDefinition {
+        match node {
+            GoalDefinition::Exists(variables, gd) => {
+                let gd = self.fold_goal_definition(*gd);
+                GoalDefinition::new_or(self.instantiate(
+                    &variables,
+                    gd,
+                    Substitution::fold_goal_definition,
+                ))
+            }
+            GoalDefinition::ForAll(variables, gd) => {
+                let gd = self.fold_goal_definition(*gd);
+                GoalDefinition::new_and(self.instantiate(
+                    &variables,
+                    gd,
+                    Substitution::fold_goal_definition,
+                ))
+            }
+            node => fold::fold_goal_definition(self, node),
+        }
+    }
+
+    fn fold_effects(&mut self, node: Effects) -> Effects {
+        let mut effects = Vec::with_capacity(node.len());
+        for effect in node {
+            match effect {
+                CEffect::Forall(ForallCEffect {
+                    variables,
+                    effects: body,
+                }) => {
+                    let body = self.fold_effects(body);
+                    let instances = self.instantiate(&variables, body, Substitution::fold_effects);
+                    effects.extend(instances.into_iter().flatten());
+                }
+                effect => effects.push(self.fold_c_effect(effect)),
+            }
+        }
+        Effects::new_and(effects)
+    }
+
+    fn fold_durative_action_goal_definition(
+        &mut self,
+        node: DurativeActionGoalDefinition,
+    ) -> DurativeActionGoalDefinition {
+        match node {
+            DurativeActionGoalDefinition::Forall(variables, gd) => {
+                let gd = self.fold_durative_action_goal_definition(*gd);
+                DurativeActionGoalDefinition::new_and(self.instantiate(
+                    &variables,
+                    gd,
+                    Substitution::fold_durative_action_goal_definition,
+                ))
+            }
+            node => fold::fold_durative_action_goal_definition(self, node),
+        }
+    }
+
+    fn fold_durative_action_effect(&mut self, node: DurativeActionEffect) -> DurativeActionEffect {
+        match node {
+            DurativeActionEffect::Forall(variables, effect) => {
+                let effect = self.fold_durative_action_effect(*effect);
+                DurativeActionEffect::new_and(self.instantiate(
+                    &variables,
+                    effect,
+                    Substitution::fold_durative_action_effect,
+                ))
+            }
+            node => fold::fold_durative_action_effect(self, node),
+        }
+    }
+
+    fn fold_con_gd(&mut self, node: ConGD) -> ConGD {
+        match node {
+            ConGD::Forall(variables, gd) => {
+                let gd = self.fold_con_gd(*gd);
+                ConGD::new_and(self.instantiate(&variables, gd, Substitution::fold_con_gd))
+            }
+            node => fold::fold_con_gd(self, node),
+        }
+    }
+
+    fn fold_pref_con_gds(&mut self, node: PrefConGDs) -> PrefConGDs {
+        let mut values = Vec::with_capacity(node.len());
+        for value in node {
+            match value {
+                PrefConGD::Forall(variables, gds) => {
+                    let gds = self.fold_pref_con_gds(gds);
+                    let instances =
+                        self.instantiate(&variables, gds, Substitution::fold_pref_con_gds);
+                    values.extend(instances.into_iter().flatten());
+                }
+                value => values.push(self.fold_pref_con_gd(value)),
+            }
+        }
+        PrefConGDs::new(values)
+    }
+}
+
+/// Replaces variables by the objects they are bound to.
+struct Substitution {
+    bindings: Vec<(Variable, Name)>,
+}
+
+impl Fold for Substitution {
+    fn fold_term(&mut self, node: Term) -> Term {
+        match node {
+            Term::Variable(variable) => {
+                match self.bindings.iter().find(|(bound, _)| *bound == variable) {
+                    Some((_, object)) => Term::Name(object.clone()),
+                    None => Term::Variable(variable),
+                }
+            }
+            node => fold::fold_term(self, node),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn expands_nested_quantifiers_and_effects() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :adl :constraints :preferences)
+                (:types a b - object c - a)
+                (:predicates (p ?x) (q ?x ?y))
+                (:action act
+                    :parameters (?z)
+                    :precondition (and (p ?z) (forall (?x - b) (exists (?y - a) (q ?x ?y))))
+                    :effect (forall (?x - a) (forall (?y - b) (when (p ?x) (q ?x ?y))))))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem p)
+                (:domain d)
+                (:objects a1 - a c1 - c b1 b2 - b)
+                (:init)
+                (:goal (and))
+                (:constraints (forall (?x - b) (preference q (always (p ?x))))))"#,
+        )
+        .unwrap();
+
+        let (domain, problem) = expand_quantifiers(domain, problem);
+        assert!(!domain
+            .requirements()
+            .iter()
+            .any(|requirement| requirement.expand().len() > 1
+                || *requirement == Requirement::ExistentialPreconditions
+                || *requirement == Requirement::UniversalPreconditions));
+
+        let action = domain.structure()[0].to_string();
+        assert!(action.contains(
+            ":precondition (and (p ?z) (or (q b1 a1) (q b1 c1)) (or (q b2 a1) (q b2 c1)))"
+        ));
+        assert!(action.contains(
+            ":effect (and (when (p a1) (q a1 b1)) (when (p a1) (q a1 b2)) (when (p c1) (q c1 b1)) (when (p c1) (q c1 b2)))"
+        ));
+        assert_eq!(
+            problem.constraints().to_string(),
+            "(and (preference q (always (p b1))) (preference q (always (p b2))))"
+        );
+    }
+
+    #[test]
+    fn expands_over_empty_types() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :typing :quantified-preconditions)
+                (:types t)
+                (:predicates (p ?x))
+                (:action act
+                    :parameters ()
+                    :precondition (or (exists (?x - t) (p ?x)) (forall (?x - t) (p ?x)))
+                    :effect (and)))"#,
+        )
+        .unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init) (:goal (and)))").unwrap();
+
+        let (domain, _) = expand_quantifiers(domain, problem);
+        assert!(domain.structure()[0]
+            .to_string()
+            .contains(":precondition (or (or) (and))"));
+    }
+}
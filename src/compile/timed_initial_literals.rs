@@ -0,0 +1,464 @@
+//! Compiles timed initial literals into a chain of durative clock actions.
+
+use crate::compile::{action_names, add_requirement, remove_requirement, FreshNames};
+use crate::types::{
+    AtomicFormula, AtomicFormulaSkeleton, ConditionalEffect, DOp, Domain, DurationConstraint,
+    DurationValue, DurativeActionDefinition, DurativeActionEffect, DurativeActionGoalDefinition,
+    GoalDefinition, InitElement, Interval, Literal, Name, NameLiteral, Number, PEffect,
+    PreconditionGoalDefinition, Predicate, PrefTimedGD, Problem, Requirement,
+    SimpleDurationConstraint, StructureDef, StructureDefs, Term, TimeSpecifier, TimedEffect,
+    TimedGD, Timeline, TypedVariables,
+};
+use crate::validate::infer_problem_requirements;
+
+/// Replaces the timed initial literals of the `problem` by durative clock actions in
+/// the `domain`.
+///
+/// This follows the encoding of Coles et al. The literals are grouped into a [`Timeline`].
+/// A durative `clock` envelope action lasts until the last time point. It can only start
+/// once, while the initial `clock-ready` fact holds, and at its start adds `clock-started`
+/// and `clock-0`, along with `clock-running`, which it deletes again at its end. For the
+/// `i`-th time point, a durative `tick-i` action lasts for the time since the previous
+/// one and at its end makes the literals of the time point take effect, replacing
+/// `clock-<i-1>` by `clock-i`. Every tick requires `clock-running` and `clock-<i-1>`
+/// over all, so that it runs inside the envelope and after the previous tick; since
+/// `over all` conditions need not hold at the start of an action, it may start at the
+/// very time the previous tick ends. As the durations of the ticks add up to that of the
+/// envelope, the first tick starts along with the envelope and each further one as soon
+/// as the previous one ends.
+///
+/// Every other action requires `clock-started` at its start, so a plan of the compiled
+/// problem is a plan of the original problem once its times are shifted by the start of
+/// the envelope. The goal is extended by the last `clock` fact so that every literal takes
+/// effect.
+///
+/// Literals at time `0` are added to or removed from the initial state instead. The
+/// [`:timed-initial-literals`](Requirement::TimedInitialLiterals) requirement is removed,
+/// and [`:durative-actions`](Requirement::DurativeActions) is added to the domain if any
+/// ticks are needed. Names receive a numeric suffix if they are already taken.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::compile::compile_timed_initial_literals;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :durative-actions :timed-initial-literals)
+///     (:predicates (open)))"#).unwrap();
+/// let problem = Problem::from_str(r#"(define (problem p) (:domain d)
+///     (:init (at 3 (open)) (at 8 (not (open))))
+///     (:goal (open)))"#).unwrap();
+///
+/// let (domain, problem) = compile_timed_initial_literals(domain, problem);
+/// assert_eq!(
+///     domain.predicates().to_string(),
+///     "(:predicates (open) (clock-ready) (clock-started) (clock-running) (clock-0) (clock-1) (clock-2))"
+/// );
+/// assert_eq!(domain.structure().len(), 3);
+/// assert!(domain.structure()[0].to_string().contains(":duration (= ?duration 8)"));
+/// assert!(domain.structure()[2].to_string().contains(":duration (= ?duration 5)"));
+/// assert_eq!(problem.init().to_string(), "(:init\n  (clock-ready))");
+/// assert_eq!(problem.goal().to_string(), "(:goal (and (open) (clock-2)))");
+/// ```
+pub fn compile_timed_initial_literals(
+    mut domain: Domain,
+    mut problem: Problem,
+) -> (Domain, Problem) {
+    let timeline = Timeline::new(problem.init());
+    if timeline.is_empty() {
+        return (domain, problem);
+    }
+
+    let mut init: Vec<_> = std::mem::take(&mut problem.init.0)
+        .into_iter()
+        .filter(|element| !matches!(element, InitElement::At(..)))
+        .collect();
+    let mut events = timeline.iter().peekable();
+    while let Some((_, literals)) = events.next_if(|(time, _)| **time <= 0.0) {
+        for literal in literals {
+            let fact =
+                InitElement::Literal(Literal::AtomicFormula(atomic_formula(literal).clone()));
+            init.retain(|element| *element != fact);
+            if let Literal::AtomicFormula(_) = literal {
+                init.push(fact);
+            }
+        }
+    }
+
+    let events: Vec<_> = events.collect();
+    if !events.is_empty() {
+        let last = add_clock_actions(&mut domain, &mut init, &events);
+        let done = PreconditionGoalDefinition::new_preference(
+            GoalDefinition::new_atomic_formula(atom(&last)).into(),
+        );
+        problem.goal.0 .0.push(done);
+        domain.requirements = add_requirement(domain.requirements(), Requirement::DurativeActions);
+    }
+    problem.init.0 = init;
+
+    domain.requirements =
+        remove_requirement(domain.requirements(), Requirement::TimedInitialLiterals);
+    if !infer_problem_requirements(&problem).contains(&Requirement::TimedInitialLiterals) {
+        problem.requires =
+            remove_requirement(problem.requirements(), Requirement::TimedInitialLiterals);
+    }
+    (domain, problem)
+}
+
+/// Adds the `clock` envelope action and a `tick` action for each of the `events`, which
+/// follow time `0`, to the `domain`, gates the other actions on the start of the envelope
+/// and adds the initial `clock-ready` fact to the `init` elements. Returns the last
+/// `clock` fact.
+fn add_clock_actions(
+    domain: &mut Domain,
+    init: &mut Vec<InitElement>,
+    events: &[(Number, &[NameLiteral])],
+) -> Predicate {
+    let mut predicates =
+        FreshNames::new(domain.predicates().iter().map(AtomicFormulaSkeleton::name));
+    let mut actions = FreshNames::new(action_names(domain));
+    let ready = declare(domain, &mut predicates, "clock-ready");
+    let started = declare(domain, &mut predicates, "clock-started");
+    let running = declare(domain, &mut predicates, "clock-running");
+    let mut stage = declare(domain, &mut predicates, "clock-0");
+    init.push(InitElement::Literal(Literal::AtomicFormula(
+        AtomicFormula::new_predicate(ready.clone(), []),
+    )));
+
+    let mut structure = Vec::with_capacity(domain.structure().len() + events.len() + 1);
+    for def in std::mem::take(&mut domain.structure).0 {
+        structure.push(match def {
+            StructureDef::Action(mut action) => {
+                action
+                    .precondition
+                    .0
+                    .push(PreconditionGoalDefinition::new_preference(
+                        GoalDefinition::new_atomic_formula(atom(&started)).into(),
+                    ));
+                StructureDef::Action(action)
+            }
+            StructureDef::DurativeAction(mut action) => {
+                let mut condition = vec![at(TimeSpecifier::Start, &started)];
+                match action.condition.take() {
+                    Some(DurativeActionGoalDefinition::And(gds)) => condition.extend(gds),
+                    Some(gd) => condition.push(gd),
+                    None => {}
+                }
+                action.condition = Some(DurativeActionGoalDefinition::new_and(condition));
+                StructureDef::DurativeAction(action)
+            }
+            def => def,
+        });
+    }
+
+    let (last, _) = events[events.len() - 1];
+    structure.push(StructureDef::DurativeAction(DurativeActionDefinition::new(
+        actions.fresh("clock").into(),
+        TypedVariables::default(),
+        Some(duration(*last)),
+        Some(at(TimeSpecifier::Start, &ready)),
+        Some(DurativeActionEffect::new_and([
+            timed_effect(
+                TimeSpecifier::Start,
+                vec![
+                    PEffect::new_not(atom(&ready)),
+                    PEffect::new(atom(&started)),
+                    PEffect::new(atom(&running)),
+                    PEffect::new(atom(&stage)),
+                ],
+            ),
+            timed_effect(TimeSpecifier::End, vec![PEffect::new_not(atom(&running))]),
+        ])),
+    )));
+
+    let mut previous = Number::default();
+    for (index, &(time, literals)) in events.iter().enumerate() {
+        let next = declare(domain, &mut predicates, &format!("clock-{}", index + 1));
+        let mut effects = vec![PEffect::new_not(atom(&stage)), PEffect::new(atom(&next))];
+        effects.extend(literals.iter().map(effect));
+
+        structure.push(StructureDef::DurativeAction(DurativeActionDefinition::new(
+            actions.fresh(&format!("tick-{}", index + 1)).into(),
+            TypedVariables::default(),
+            Some(duration(*time - *previous)),
+            Some(DurativeActionGoalDefinition::new_and([
+                over_all(&running),
+                over_all(&stage),
+            ])),
+            Some(timed_effect(TimeSpecifier::End, effects)),
+        )));
+        previous = time;
+        stage = next;
+    }
+    domain.structure = StructureDefs::new(structure);
+    stage
+}
+
+/// Declares a new fact named `name`, or `name` with a numeric suffix if it is taken.
+fn declare(domain: &mut Domain, names: &mut FreshNames, name: &str) -> Predicate {
+    let predicate = Predicate::from(names.fresh(name));
+    domain.predicates.0.push(AtomicFormulaSkeleton::new(
+        predicate.clone(),
+        TypedVariables::default(),
+    ));
+    predicate
+}
+
+fn duration(value: f32) -> DurationConstraint {
+    DurationConstraint::new(SimpleDurationConstraint::new_op(
+        DOp::Equal,
+        DurationValue::new_number(value),
+    ))
+}
+
+fn at(time: TimeSpecifier, fact: &Predicate) -> DurativeActionGoalDefinition {
+    DurativeActionGoalDefinition::new_timed(PrefTimedGD::new_required(TimedGD::new_at(
+        time,
+        GoalDefinition::new_atomic_formula(atom(fact)),
+    )))
+}
+
+fn over_all(fact: &Predicate) -> DurativeActionGoalDefinition {
+    DurativeActionGoalDefinition::new_timed(PrefTimedGD::new_required(TimedGD::new_over(
+        Interval::All,
+        GoalDefinition::new_atomic_formula(atom(fact)),
+    )))
+}
+
+fn timed_effect(time: TimeSpecifier, mut effects: Vec<PEffect>) -> DurativeActionEffect {
+    let effect = match effects.len() {
+        1 => ConditionalEffect::new(effects.remove(0)),
+        _ => ConditionalEffect::new_and(effects),
+    };
+    DurativeActionEffect::new_timed(TimedEffect::new_conditional(time, effect))
+}
+
+fn atom(predicate: &Predicate) -> AtomicFormula<Term> {
+    AtomicFormula::new_predicate(predicate.clone(), [])
+}
+
+fn atomic_formula(literal: &NameLiteral) -> &AtomicFormula<Name> {
+    match literal {
+        Literal::AtomicFormula(af) | Literal::NotAtomicFormula(af) => af,
+    }
+}
+
+/// Converts a timed initial literal into the effect that applies it.
+fn effect(literal: &NameLiteral) -> PEffect {
+    let af = match atomic_formula(literal) {
+        AtomicFormula::Equality(eq) => AtomicFormula::new_equality(
+            Term::Name(eq.first().clone()),
+            Term::Name(eq.second().clone()),
+        ),
+        AtomicFormula::Predicate(af) => AtomicFormula::new_predicate(
+            af.predicate().clone(),
+            af.values().iter().cloned().map(Term::Name),
+        ),
+    };
+    match literal {
+        Literal::AtomicFormula(_) => PEffect::new(af),
+        Literal::NotAtomicFormula(_) => PEffect::new_not(af),
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::types::Requirements;
+    use crate::Parser;
+
+    #[test]
+    fn compiles_timeline_into_clock_chain() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :typing :timed-initial-literals)
+                (:types truck)
+                (:predicates (open ?t - truck) (clock-1))
+                (:action tick-1 :parameters () :precondition (and) :effect (and)))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem p) (:domain d)
+                (:requirements :timed-initial-literals)
+                (:objects t1 - truck)
+                (:init (at 0 (open t1)) (at 10 (not (open t1))) (at 4 (open t1)))
+                (:goal (open t1)))"#,
+        )
+        .unwrap();
+
+        let (domain, problem) = compile_timed_initial_literals(domain, problem);
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([Requirement::Typing, Requirement::DurativeActions])
+        );
+        assert!(problem.requirements().is_empty());
+        assert_eq!(
+            domain.predicates().to_string(),
+            "(:predicates (open ?t - truck) (clock-1) (clock-ready) (clock-started) \
+             (clock-running) (clock-0) (clock-1-1) (clock-2))"
+        );
+        assert_eq!(
+            domain.structure()[0].to_string(),
+            "(:action tick-1\n  :parameters ()\n  :precondition (clock-started)\n  :effect (and))"
+        );
+        assert_eq!(
+            domain.structure()[1].to_string(),
+            "(:durative-action clock\n  \
+               :parameters ()\n  \
+               :duration (= ?duration 10)\n  \
+               :condition (at start (clock-ready))\n  \
+               :effect (and (at start (and (not (clock-ready)) (clock-started) (clock-running) (clock-0))) \
+             (at end (not (clock-running)))))"
+        );
+        assert_eq!(
+            domain.structure()[2].to_string(),
+            "(:durative-action tick-1-1\n  \
+               :parameters ()\n  \
+               :duration (= ?duration 4)\n  \
+               :condition (and (over all (clock-running)) (over all (clock-0)))\n  \
+               :effect (at end (and (not (clock-0)) (clock-1-1) (open t1))))"
+        );
+        assert_eq!(
+            domain.structure()[3].to_string(),
+            "(:durative-action tick-2\n  \
+               :parameters ()\n  \
+               :duration (= ?duration 6)\n  \
+               :condition (and (over all (clock-running)) (over all (clock-1-1)))\n  \
+               :effect (at end (and (not (clock-1-1)) (clock-2) (not (open t1)))))"
+        );
+        assert_eq!(
+            problem.init().to_string(),
+            "(:init\n  (open t1)\n  (clock-ready))"
+        );
+        assert_eq!(
+            problem.goal().to_string(),
+            "(:goal (and (open t1) (clock-2)))"
+        );
+    }
+
+    #[test]
+    fn rejects_late_ticks() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :durative-actions :timed-initial-literals)
+                (:predicates (open) (delivered))
+                (:durative-action deliver :parameters () :duration (= ?duration 1)
+                    :condition (over all (open)) :effect (at end (delivered))))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem p) (:domain d)
+                (:init (at 2 (open)) (at 10 (not (open))))
+                (:goal (delivered)))"#,
+        )
+        .unwrap();
+        let (domain, _) = compile_timed_initial_literals(domain, problem);
+        assert!(domain.structure()[0]
+            .to_string()
+            .contains(":condition (and (at start (clock-started)) (over all (open)))"));
+
+        // Starting the second tick late would keep the shop open until 11 and let `deliver`
+        // run from 10 to 11, but the tick would then end after the envelope.
+        assert!(!over_all_conditions_hold(
+            &domain,
+            &[("clock", 0.0), ("tick-1", 0.0), ("tick-2", 3.0)]
+        ));
+        // Nor can the whole chain start after the envelope.
+        assert!(!over_all_conditions_hold(
+            &domain,
+            &[("clock", 0.0), ("tick-1", 1.0), ("tick-2", 3.0)]
+        ));
+        // The timeline only moves along with the envelope, before which no action can start.
+        assert!(over_all_conditions_hold(
+            &domain,
+            &[("clock", 5.0), ("tick-1", 5.0), ("tick-2", 7.0)]
+        ));
+    }
+
+    /// Determines whether the `over all` conditions of the clock actions of the `domain`
+    /// hold when the actions start at the times of the `schedule`.
+    fn over_all_conditions_hold(domain: &Domain, schedule: &[(&str, f32)]) -> bool {
+        let action = |name: &str| {
+            domain
+                .structure()
+                .iter()
+                .find_map(|def| match def {
+                    StructureDef::DurativeAction(action) if **action.symbol() == name => {
+                        Some(action)
+                    }
+                    _ => None,
+                })
+                .unwrap()
+        };
+        let end = |name: &str, start: f32| match action(name).duration() {
+            Some(DurationConstraint::Single(SimpleDurationConstraint::Op(
+                _,
+                DurationValue::Number(duration),
+            ))) => start + **duration,
+            _ => unreachable!(),
+        };
+
+        let mut changes = Vec::new();
+        for &(name, start) in schedule {
+            let effects = match action(name).effect() {
+                Some(DurativeActionEffect::All(effects)) => effects.clone(),
+                Some(effect) => vec![effect.clone()],
+                None => Vec::new(),
+            };
+            for effect in effects {
+                let DurativeActionEffect::Timed(TimedEffect::Conditional(time, effect)) = effect
+                else {
+                    unreachable!()
+                };
+                let time = match time {
+                    TimeSpecifier::Start => start,
+                    TimeSpecifier::End => end(name, start),
+                };
+                for effect in effect {
+                    match effect {
+                        PEffect::AtomicFormula(af) => changes.push((time, af, true)),
+                        PEffect::NotAtomicFormula(af) => changes.push((time, af, false)),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        }
+
+        schedule.iter().all(|&(name, start)| {
+            let end = end(name, start);
+            let Some(DurativeActionGoalDefinition::And(conditions)) = action(name).condition()
+            else {
+                return true;
+            };
+            conditions.iter().all(|condition| {
+                let DurativeActionGoalDefinition::Timed(PrefTimedGD::Required(TimedGD::Over(
+                    _,
+                    GoalDefinition::AtomicFormula(fact),
+                ))) = condition
+                else {
+                    return true;
+                };
+                // The fact must be added by the start and not deleted before the end.
+                let added = changes
+                    .iter()
+                    .any(|(time, af, add)| af == fact && *add && *time <= start);
+                let deleted = changes
+                    .iter()
+                    .any(|(time, af, add)| af == fact && !*add && start < *time && *time < end);
+                added && !deleted
+            })
+        })
+    }
+
+    #[test]
+    fn keeps_problems_without_timed_literals() {
+        let domain = Domain::from_str("(define (domain d) (:predicates (p)))").unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain d) (:init (p)) (:goal (p)))").unwrap();
+
+        let (compiled, compiled_problem) =
+            compile_timed_initial_literals(domain.clone(), problem.clone());
+        assert_eq!(compiled, domain);
+        assert_eq!(compiled_problem, problem);
+    }
+}
@@ -0,0 +1,658 @@
+//! Compiles away types by replacing them with unary predicates.
+
+use crate::compile::{add_requirement, remove_requirement, FreshNames};
+use crate::fold::{self, Fold};
+use crate::types::{
+    ActionDefinition, AtomicFormula, AtomicFormulaSkeleton, CEffect, Con2GD, ConGD,
+    ConditionalEffect, DerivedPredicate, Domain, DurativeActionDefinition, DurativeActionEffect,
+    DurativeActionGoalDefinition, Effects, ForallCEffect, GoalDefinition, InitElement, Literal,
+    Name, PreconditionGoalDefinition, PreconditionGoalDefinitions, Predicate, PrefConGD,
+    PrefConGDs, PrefTimedGD, Preference, PreferenceGD, Problem, Requirement, Term, TimeSpecifier,
+    TimedGD, TypeHierarchy, Typed, TypedVariables, Types, Variable, WhenCEffect, TYPE_OBJECT,
+};
+use crate::validate::{infer_problem_requirements, infer_requirements};
+use std::collections::HashMap;
+
+/// Replaces the types of the `domain` and `problem` by unary predicates, so that the
+/// result no longer needs [`:typing`](Requirement::Typing).
+///
+/// Each type of the domain, except for `object`, becomes a unary predicate of the same name,
+/// or with a numeric suffix if a predicate of that name already exists. Variables lose their
+/// types and are constrained by these predicates instead:
+///
+/// - typed parameters of actions, durative actions and derived predicates become conjuncts
+///   of their precondition, condition or definition,
+/// - universally quantified goals `(forall (?x - t) φ)` become `(forall (?x) (imply (t ?x) φ))`,
+/// - existentially quantified goals `(exists (?x - t) φ)` become `(exists (?x) (and (t ?x) φ))`,
+/// - universally quantified effects only apply to members of the type through conditional
+///   effects, and quantified constraints are guarded in the same way.
+///
+/// A variable of type `(either a b)` is constrained by `(or (a ?x) (b ?x))`. Constants and
+/// objects lose their types as well, and the initial state of the problem receives a fact
+/// for each of their types and its supertypes.
+///
+/// [`:typing`](Requirement::Typing) is removed from the requirements of the domain and the
+/// problem unless object fluents still refer to types. Since the guards may require
+/// [`:disjunctive-preconditions`](Requirement::DisjunctivePreconditions) or
+/// [`:conditional-effects`](Requirement::ConditionalEffects), these are added as needed.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::compile::compile_typing;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :typing)
+///     (:types truck - vehicle location)
+///     (:predicates (at ?v - vehicle ?l - location))
+///     (:action drive
+///         :parameters (?t - truck ?from ?to - location)
+///         :precondition (at ?t ?from)
+///         :effect (and (not (at ?t ?from)) (at ?t ?to))))"#).unwrap();
+///
+/// let problem = Problem::from_str(r#"(define (problem p)
+///     (:domain d)
+///     (:objects t1 - truck home - location)
+///     (:init (at t1 home))
+///     (:goal (at t1 home)))"#).unwrap();
+///
+/// let (domain, problem) = compile_typing(domain, problem);
+/// assert!(domain.requirements().is_empty());
+/// assert_eq!(
+///     domain.predicates().to_string(),
+///     "(:predicates (at ?v ?l) (truck ?x) (location ?x) (vehicle ?x))"
+/// );
+/// assert_eq!(
+///     domain.structure()[0].to_string(),
+///     "(:action drive\n  :parameters (?t ?from ?to)\n  :precondition (and (truck ?t) (location ?from) (location ?to) (at ?t ?from))\n  :effect (and (not (at ?t ?from)) (at ?t ?to)))"
+/// );
+/// assert_eq!(
+///     problem.init().to_string(),
+///     "(:init\n  (at t1 home)\n  (truck t1)\n  (vehicle t1)\n  (location home))"
+/// );
+/// ```
+pub fn compile_typing(domain: Domain, problem: Problem) -> (Domain, Problem) {
+    let mut compilation = TypeCompilation::new(&domain);
+    let facts: Vec<_> = domain
+        .constants()
+        .iter()
+        .chain(problem.objects().iter())
+        .flat_map(|object| compilation.facts(object))
+        .collect();
+
+    let mut domain = compilation.fold_domain(domain);
+    let mut problem = compilation.fold_problem(problem);
+    domain
+        .predicates
+        .0
+        .extend(compilation.predicates.into_iter().map(|predicate| {
+            let variable = Typed::new_object(Variable::from_static("x"));
+            AtomicFormulaSkeleton::new(predicate, TypedVariables::new(vec![variable]))
+        }));
+    problem.init.0.extend(facts);
+
+    let used = infer_requirements(&domain);
+    if !used.contains(&Requirement::Typing) {
+        domain.requirements = remove_requirement(domain.requirements(), Requirement::Typing);
+    }
+    for requirement in [
+        Requirement::DisjunctivePreconditions,
+        Requirement::ConditionalEffects,
+    ] {
+        if used.contains(&requirement) {
+            domain.requirements = add_requirement(domain.requirements(), requirement);
+        }
+    }
+
+    let used = infer_problem_requirements(&problem);
+    if !used.contains(&Requirement::Typing) {
+        problem.requires = remove_requirement(problem.requirements(), Requirement::Typing);
+    }
+    if used.contains(&Requirement::DisjunctivePreconditions) {
+        problem.requires = add_requirement(
+            problem.requirements(),
+            Requirement::DisjunctivePreconditions,
+        );
+    }
+    (domain, problem)
+}
+
+/// Strips types and guards the typed variables by their type predicates.
+struct TypeCompilation {
+    hierarchy: TypeHierarchy,
+    names: FreshNames,
+    /// The predicate of each type.
+    types: HashMap<Name, Predicate>,
+    /// The type predicates in order of their creation.
+    predicates: Vec<Predicate>,
+}
+
+impl TypeCompilation {
+    /// Creates the predicates of the types declared by the `domain`.
+    fn new(domain: &Domain) -> Self {
+        let mut compilation = Self {
+            hierarchy: TypeHierarchy::new(domain.types()),
+            names: FreshNames::new(domain.predicates().iter().map(AtomicFormulaSkeleton::name)),
+            types: HashMap::new(),
+            predicates: Vec::new(),
+        };
+        let types: Vec<_> = compilation.hierarchy.types().cloned().collect();
+        for r#type in &types {
+            if r#type != TYPE_OBJECT.name() {
+                compilation.predicate(r#type);
+            }
+        }
+        compilation
+    }
+
+    /// Gets the predicate of the type, creating it if the type is not known yet.
+    fn predicate(&mut self, r#type: &Name) -> Predicate {
+        if let Some(predicate) = self.types.get(r#type) {
+            return predicate.clone();
+        }
+        let predicate = Predicate::from(self.names.fresh(r#type));
+        self.types.insert(r#type.clone(), predicate.clone());
+        self.predicates.push(predicate.clone());
+        predicate
+    }
+
+    /// Gets the initial facts stating the types of a constant or object.
+    fn facts(&mut self, object: &Typed<Name>) -> Vec<InitElement> {
+        let mut types: Vec<Name> = Vec::new();
+        for r#type in object.type_().primitives() {
+            let ancestors = self.hierarchy.ancestors(r#type.name());
+            for r#type in std::iter::once(r#type.name()).chain(ancestors) {
+                if r#type != TYPE_OBJECT.name() && !types.contains(r#type) {
+                    types.push(r#type.clone());
+                }
+            }
+        }
+
+        types
+            .iter()
+            .map(|r#type| {
+                let fact =
+                    AtomicFormula::new_predicate(self.predicate(r#type), [object.value().clone()]);
+                InitElement::Literal(Literal::new(fact))
+            })
+            .collect()
+    }
+
+    /// Gets the goals that hold if the `variables` are members of their types.
+    fn guards(&mut self, variables: &TypedVariables) -> Vec<GoalDefinition> {
+        let mut guards = Vec::new();
+        for variable in variables.iter() {
+            let primitives = variable.type_().primitives();
+            if primitives.iter().any(|r#type| r#type == &TYPE_OBJECT) {
+                continue;
+            }
+            let mut members: Vec<_> = primitives
+                .iter()
+                .map(|r#type| {
+                    let term = Term::Variable(variable.value().clone());
+                    let atom = AtomicFormula::new_predicate(self.predicate(r#type.name()), [term]);
+                    GoalDefinition::new_atomic_formula(atom)
+                })
+                .collect();
+            guards.push(match members.len() {
+                1 => members.remove(0),
+                _ => GoalDefinition::new_or(members),
+            });
+        }
+        guards
+    }
+
+    /// Gets the conjunction of the [`guards`](Self::guards) of the `variables`, or `None`
+    /// if none of them are typed.
+    fn guard(&mut self, variables: &TypedVariables) -> Option<GoalDefinition> {
+        let mut guards = self.guards(variables);
+        match guards.len() {
+            0 => None,
+            1 => guards.pop(),
+            _ => Some(GoalDefinition::new_and(guards)),
+        }
+    }
+}
+
+impl Fold for TypeCompilation {
+    fn fold_types(&mut self, _node: Types) -> Types {
+        Types::default()
+    }
+
+    fn fold_typed_name(&mut self, node: Typed<Name>) -> Typed<Name> {
+        Typed::new_object(self.fold_name(node.0))
+    }
+
+    fn fold_typed_variable(&mut self, node: Typed<Variable>) -> Typed<Variable> {
+        Typed::new_object(self.fold_variable(node.0))
+    }
+
+    fn fold_action_definition(&mut self, node: ActionDefinition) -> ActionDefinition {
+        let guards = self.guards(node.parameters());
+        let mut node = fold::fold_action_definition(self, node);
+        let precondition = std::mem::take(&mut node.precondition);
+        node.precondition = PreconditionGoalDefinitions::new(
+            guards
+                .into_iter()
+                .map(|gd| PreconditionGoalDefinition::new_preference(gd.into()))
+                .chain(precondition)
+                .collect(),
+        );
+        node
+    }
+
+    fn fold_durative_action_definition(
+        &mut self,
+        node: DurativeActionDefinition,
+    ) -> DurativeActionDefinition {
+        let guards = self.guards(node.parameters());
+        let mut node = fold::fold_durative_action_definition(self, node);
+        if guards.is_empty() {
+            return node;
+        }
+
+        let mut condition: Vec<_> = guards.into_iter().map(at_start).collect();
+        match node.condition.take() {
+            Some(DurativeActionGoalDefinition::And(gds)) => condition.extend(gds),
+            Some(gd) => condition.push(gd),
+            None => {}
+        }
+        node.condition = Some(DurativeActionGoalDefinition::new_and(condition));
+        node
+    }
+
+    fn fold_derived_predicate(&mut self, node: DerivedPredicate) -> DerivedPredicate {
+        let guards = self.guards(node.0.variables());
+        let mut node = fold::fold_derived_predicate(self, node);
+        if !guards.is_empty() {
+            node.1 = GoalDefinition::new_and(guards.into_iter().chain([node.1]));
+        }
+        node
+    }
+
+    fn fold_goal_definition(&mut self, node: GoalDefinition) -> GoalDefinition {
+        let guard = match &node {
+            GoalDefinition::Exists(variables, _) | GoalDefinition::ForAll(variables, _) => {
+                self.guard(variables)
+            }
+            _ => None,
+        };
+        match (fold::fold_goal_definition(self, node), guard) {
+            (GoalDefinition::Exists(variables, gd), Some(guard)) => {
+                GoalDefinition::new_exists(variables, GoalDefinition::new_and([guard, *gd]))
+            }
+            (GoalDefinition::ForAll(variables, gd), Some(guard)) => {
+                GoalDefinition::new_forall(variables, GoalDefinition::new_imply(guard, *gd))
+            }
+            (node, _) => node,
+        }
+    }
+
+    fn fold_precondition_goal_definition(
+        &mut self,
+        node: PreconditionGoalDefinition,
+    ) -> PreconditionGoalDefinition {
+        let guard = match &node {
+            PreconditionGoalDefinition::Forall(variables, _) => self.guard(variables),
+            PreconditionGoalDefinition::Preference(_) => None,
+        };
+        match fold::fold_precondition_goal_definition(self, node) {
+            PreconditionGoalDefinition::Forall(variables, gds) => match guard {
+                Some(guard) => PreconditionGoalDefinition::Forall(
+                    variables,
+                    gds.into_iter()
+                        .map(|gd| guard_precondition(&guard, gd))
+                        .collect(),
+                ),
+                None => PreconditionGoalDefinition::Forall(variables, gds),
+            },
+            node => node,
+        }
+    }
+
+    fn fold_c_effect(&mut self, node: CEffect) -> CEffect {
+        let guard = match &node {
+            CEffect::Forall(forall) => self.guard(&forall.variables),
+            _ => None,
+        };
+        match (fold::fold_c_effect(self, node), guard) {
+            (CEffect::Forall(forall), Some(guard)) => guard_effect(&guard, CEffect::Forall(forall)),
+            (node, _) => node,
+        }
+    }
+
+    fn fold_durative_action_goal_definition(
+        &mut self,
+        node: DurativeActionGoalDefinition,
+    ) -> DurativeActionGoalDefinition {
+        let guard = match &node {
+            DurativeActionGoalDefinition::Forall(variables, _) => self.guard(variables),
+            _ => None,
+        };
+        match (
+            fold::fold_durative_action_goal_definition(self, node),
+            guard,
+        ) {
+            (DurativeActionGoalDefinition::Forall(variables, gd), Some(guard)) => {
+                DurativeActionGoalDefinition::new_forall(variables, guard_da_gd(&guard, *gd))
+            }
+            (node, _) => node,
+        }
+    }
+
+    fn fold_durative_action_effect(&mut self, node: DurativeActionEffect) -> DurativeActionEffect {
+        let guard = match &node {
+            DurativeActionEffect::Forall(variables, _) => self.guard(variables),
+            _ => None,
+        };
+        match (fold::fold_durative_action_effect(self, node), guard) {
+            (DurativeActionEffect::Forall(variables, effect), Some(guard)) => {
+                DurativeActionEffect::new_forall(variables, guard_da_effect(&guard, *effect))
+            }
+            (node, _) => node,
+        }
+    }
+
+    fn fold_con_gd(&mut self, node: ConGD) -> ConGD {
+        let guard = match &node {
+            ConGD::Forall(variables, _) => self.guard(variables),
+            _ => None,
+        };
+        match (fold::fold_con_gd(self, node), guard) {
+            (ConGD::Forall(variables, gd), Some(guard)) => {
+                ConGD::new_forall(variables, guard_con_gd(&guard, *gd))
+            }
+            (node, _) => node,
+        }
+    }
+
+    fn fold_pref_con_gd(&mut self, node: PrefConGD) -> PrefConGD {
+        let guard = match &node {
+            PrefConGD::Forall(variables, _) => self.guard(variables),
+            _ => None,
+        };
+        match (fold::fold_pref_con_gd(self, node), guard) {
+            (PrefConGD::Forall(variables, gds), Some(guard)) => PrefConGD::Forall(
+                variables,
+                PrefConGDs::new(
+                    gds.into_iter()
+                        .map(|gd| guard_pref_con_gd(&guard, gd))
+                        .collect(),
+                ),
+            ),
+            (node, _) => node,
+        }
+    }
+}
+
+/// Makes the goals and preferences of a universally quantified precondition hold
+/// trivially unless the `guard` holds.
+fn guard_precondition(
+    guard: &GoalDefinition,
+    node: PreconditionGoalDefinition,
+) -> PreconditionGoalDefinition {
+    match node {
+        PreconditionGoalDefinition::Preference(PreferenceGD::Goal(gd)) => {
+            PreconditionGoalDefinition::new_preference(PreferenceGD::Goal(imply(guard, gd)))
+        }
+        PreconditionGoalDefinition::Preference(PreferenceGD::Preference(Preference(name, gd))) => {
+            PreconditionGoalDefinition::new_preference(PreferenceGD::Preference(Preference::new(
+                name,
+                imply(guard, gd),
+            )))
+        }
+        PreconditionGoalDefinition::Forall(variables, gds) => PreconditionGoalDefinition::Forall(
+            variables,
+            gds.into_iter()
+                .map(|gd| guard_precondition(guard, gd))
+                .collect(),
+        ),
+    }
+}
+
+/// Makes the effect conditional on the `guard`.
+fn guard_effect(guard: &GoalDefinition, node: CEffect) -> CEffect {
+    match node {
+        CEffect::Effect(effect) => CEffect::new_when(guard.clone(), ConditionalEffect::new(effect)),
+        CEffect::When(WhenCEffect { condition, effect }) => {
+            CEffect::new_when(GoalDefinition::new_and([guard.clone(), condition]), effect)
+        }
+        CEffect::Forall(ForallCEffect { variables, effects }) => CEffect::new_forall(
+            variables,
+            Effects::new_and(
+                effects
+                    .into_iter()
+                    .map(|effect| guard_effect(guard, effect))
+                    .collect(),
+            ),
+        ),
+    }
+}
+
+/// Makes the timed goals hold trivially unless the `guard` holds.
+fn guard_da_gd(
+    guard: &GoalDefinition,
+    node: DurativeActionGoalDefinition,
+) -> DurativeActionGoalDefinition {
+    let guard_timed = |gd: TimedGD| match gd {
+        TimedGD::At(time, gd) => TimedGD::new_at(time, imply(guard, gd)),
+        TimedGD::Over(interval, gd) => TimedGD::new_over(interval, imply(guard, gd)),
+    };
+    match node {
+        DurativeActionGoalDefinition::Timed(PrefTimedGD::Required(gd)) => {
+            DurativeActionGoalDefinition::new_timed(PrefTimedGD::new_required(guard_timed(gd)))
+        }
+        DurativeActionGoalDefinition::Timed(PrefTimedGD::Preference(name, gd)) => {
+            DurativeActionGoalDefinition::new_timed(PrefTimedGD::new_preference(
+                name,
+                guard_timed(gd),
+            ))
+        }
+        DurativeActionGoalDefinition::And(gds) => {
+            DurativeActionGoalDefinition::new_and(gds.into_iter().map(|gd| guard_da_gd(guard, gd)))
+        }
+        DurativeActionGoalDefinition::Forall(variables, gd) => {
+            DurativeActionGoalDefinition::new_forall(variables, guard_da_gd(guard, *gd))
+        }
+    }
+}
+
+/// Makes the timed effects conditional on the `guard` holding at the start of the action.
+fn guard_da_effect(guard: &GoalDefinition, node: DurativeActionEffect) -> DurativeActionEffect {
+    match node {
+        DurativeActionEffect::Timed(effect) => {
+            DurativeActionEffect::new_when(at_start(guard.clone()), effect)
+        }
+        DurativeActionEffect::When(condition, effect) => DurativeActionEffect::new_when(
+            DurativeActionGoalDefinition::new_and([at_start(guard.clone()), condition]),
+            effect,
+        ),
+        DurativeActionEffect::All(effects) => DurativeActionEffect::new_and(
+            effects
+                .into_iter()
+                .map(|effect| guard_da_effect(guard, effect)),
+        ),
+        DurativeActionEffect::Forall(variables, effect) => {
+            DurativeActionEffect::new_forall(variables, guard_da_effect(guard, *effect))
+        }
+    }
+}
+
+/// Makes the constraint hold trivially unless the `guard` holds.
+///
+/// Since the guard is static, goals whose truth is required are replaced by implications
+/// and goals whose truth triggers a requirement by conjunctions.
+fn guard_con_gd(guard: &GoalDefinition, node: ConGD) -> ConGD {
+    let required = |gd: Con2GD| guard_con2_gd(guard, gd, false);
+    let trigger = |gd: Con2GD| guard_con2_gd(guard, gd, true);
+    match node {
+        ConGD::And(gds) => ConGD::new_and(gds.into_iter().map(|gd| guard_con_gd(guard, gd))),
+        ConGD::Forall(variables, gd) => ConGD::new_forall(variables, guard_con_gd(guard, *gd)),
+        ConGD::AtEnd(gd) => ConGD::new_at_end(imply(guard, gd)),
+        ConGD::Always(gd) => ConGD::new_always(required(gd)),
+        ConGD::Sometime(gd) => ConGD::new_sometime(required(gd)),
+        ConGD::Within(number, gd) => ConGD::new_within(number, required(gd)),
+        ConGD::AtMostOnce(gd) => ConGD::new_at_most_once(trigger(gd)),
+        ConGD::SometimeAfter(first, then) => {
+            ConGD::new_sometime_after(trigger(first), required(then))
+        }
+        ConGD::SometimeBefore(later, earlier) => {
+            ConGD::new_sometime_before(trigger(later), required(earlier))
+        }
+        ConGD::AlwaysWithin(number, first, second) => {
+            ConGD::new_always_within(number, trigger(first), required(second))
+        }
+        ConGD::HoldDuring(begin, end, gd) => ConGD::new_hold_during(begin, end, required(gd)),
+        ConGD::HoldAfter(number, gd) => ConGD::new_hold_after(number, required(gd)),
+    }
+}
+
+fn guard_con2_gd(guard: &GoalDefinition, node: Con2GD, trigger: bool) -> Con2GD {
+    match node {
+        Con2GD::Goal(gd) if trigger => {
+            Con2GD::new_goal(GoalDefinition::new_and([guard.clone(), gd]))
+        }
+        Con2GD::Goal(gd) => Con2GD::new_goal(imply(guard, gd)),
+        Con2GD::Nested(gd) => Con2GD::new_nested(guard_con_gd(guard, *gd)),
+    }
+}
+
+/// Makes the constraint or preference hold trivially unless the `guard` holds.
+fn guard_pref_con_gd(guard: &GoalDefinition, node: PrefConGD) -> PrefConGD {
+    match node {
+        PrefConGD::Goal(gd) => PrefConGD::new_goal(guard_con_gd(guard, gd)),
+        PrefConGD::Preference(name, gd) => PrefConGD::new_preference(name, guard_con_gd(guard, gd)),
+        PrefConGD::Forall(variables, gds) => PrefConGD::Forall(
+            variables,
+            PrefConGDs::new(
+                gds.into_iter()
+                    .map(|gd| guard_pref_con_gd(guard, gd))
+                    .collect(),
+            ),
+        ),
+    }
+}
+
+fn imply(guard: &GoalDefinition, gd: GoalDefinition) -> GoalDefinition {
+    GoalDefinition::new_imply(guard.clone(), gd)
+}
+
+fn at_start(gd: GoalDefinition) -> DurativeActionGoalDefinition {
+    DurativeActionGoalDefinition::new_timed(PrefTimedGD::new_required(TimedGD::new_at(
+        TimeSpecifier::Start,
+        gd,
+    )))
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::types::Requirements;
+    use crate::Parser;
+
+    #[test]
+    fn guards_parameters_and_quantifiers() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :typing :universal-preconditions :existential-preconditions)
+                (:types a b - object c - a p)
+                (:constants k - c)
+                (:predicates (p ?x - a) (q ?x ?y))
+                (:action act
+                    :parameters (?x - (either b c) ?y)
+                    :precondition (and (forall (?z - a) (q ?z ?x)) (exists (?z - p) (q ?y ?z)))
+                    :effect (forall (?z - b) (q ?x ?z))))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem p)
+                (:domain d)
+                (:requirements :typing)
+                (:objects o - (either b c) u)
+                (:init)
+                (:goal (forall (?x - b) (p ?x))))"#,
+        )
+        .unwrap();
+
+        let (domain, problem) = compile_typing(domain, problem);
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([
+                Requirement::UniversalPreconditions,
+                Requirement::ExistentialPreconditions,
+                Requirement::DisjunctivePreconditions,
+                Requirement::ConditionalEffects,
+            ])
+        );
+        assert_eq!(domain.types().len(), 0);
+        assert_eq!(domain.constants().to_string(), "(:constants k)");
+        assert_eq!(
+            domain.predicates().to_string(),
+            "(:predicates (p ?x) (q ?x ?y) (a ?x) (b ?x) (c ?x) (p-1 ?x))"
+        );
+
+        let action = domain.structure()[0].to_string();
+        assert!(action.contains(":parameters (?x ?y)"));
+        assert!(action.contains(
+            ":precondition (and (or (b ?x) (c ?x)) (forall (?z) (imply (a ?z) (q ?z ?x))) (exists (?z) (and (p-1 ?z) (q ?y ?z))))"
+        ));
+        assert!(action.contains(":effect (forall (?z) (when (b ?z) (q ?x ?z)))"));
+
+        assert_eq!(
+            problem.requirements(),
+            &Requirements::new([Requirement::DisjunctivePreconditions])
+        );
+        assert_eq!(problem.objects().to_string(), "(:objects o u)");
+        assert_eq!(
+            problem.init().to_string(),
+            "(:init\n  (c k)\n  (a k)\n  (b o)\n  (c o)\n  (a o))"
+        );
+        assert_eq!(
+            problem.goal().to_string(),
+            "(:goal (forall (?x) (imply (b ?x) (p ?x))))"
+        );
+
+        // The result is valid PDDL that prints the same after parsing it back.
+        let printed = domain.to_string();
+        assert_eq!(Domain::from_str(&printed).unwrap().to_string(), printed);
+    }
+
+    #[test]
+    fn guards_durative_actions_derived_predicates_and_constraints() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :typing :durative-actions :derived-predicates :constraints :adl)
+                (:types t)
+                (:predicates (p ?x - t) (q ?x))
+                (:derived (q ?x - t) (p ?x))
+                (:durative-action act
+                    :parameters (?x - t)
+                    :duration (= ?duration 1)
+                    :condition (over all (p ?x))
+                    :effect (forall (?y - t) (at end (p ?y)))))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem p)
+                (:domain d)
+                (:objects o - t)
+                (:init)
+                (:goal (and))
+                (:constraints (forall (?x - t) (and (always (p ?x)) (sometime-before (q ?x) (p ?x))))))"#,
+        )
+        .unwrap();
+
+        let (domain, problem) = compile_typing(domain, problem);
+        assert!(!infer_requirements(&domain).contains(&Requirement::Typing));
+        assert!(domain.structure()[0]
+            .to_string()
+            .contains("(q ?x) (and (t ?x) (p ?x))"));
+        let action = domain.structure()[1].to_string();
+        assert!(action.contains(":condition (and (at start (t ?x)) (over all (p ?x)))"));
+        assert!(action.contains(":effect (forall (?y) (when (at start (t ?y)) (at end (p ?y))))"));
+        assert_eq!(
+            problem.constraints().to_string(),
+            "(forall (?x) (and (always (imply (t ?x) (p ?x))) (sometime-before (and (t ?x) (q ?x)) (imply (t ?x) (p ?x)))))"
+        );
+    }
+}
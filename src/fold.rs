@@ -0,0 +1,1547 @@
+//! Provides the [`Fold`] trait to rewrite domains and problems.
+//!
+//! Every node type of the syntax tree has a `fold_*` method on [`Fold`] that takes the node
+//! by value and returns its replacement. By default, these methods call the `fold_*`
+//! function of the same name in this module, which folds the children of the node and
+//! rebuilds it. A folder that overrides no methods thus returns its input unchanged,
+//! including the source locations recorded by the `spans` feature.
+//!
+//! Transformation passes override the methods of the nodes they rewrite, and call the
+//! corresponding `fold_*` function to keep descending into the children.
+//!
+//! ## Example
+//! ```
+//! # use pddl::{Domain, Parser, Predicate};
+//! use pddl::fold::{self, Fold};
+//!
+//! /// Renames the `at` predicate to `located-at`.
+//! struct Rename;
+//!
+//! impl Fold for Rename {
+//!     fn fold_predicate(&mut self, node: Predicate) -> Predicate {
+//!         if node == Predicate::from("at") {
+//!             Predicate::from("located-at")
+//!         } else {
+//!             fold::fold_predicate(self, node)
+//!         }
+//!     }
+//! }
+//!
+//! let domain = Domain::from_str(r#"(define (domain briefcase-world)
+//!     (:predicates (at ?x ?y))
+//!     (:action move
+//!         :parameters (?x ?from ?to)
+//!         :precondition (at ?x ?from)
+//!         :effect (and (not (at ?x ?from)) (at ?x ?to))))"#).unwrap();
+//!
+//! let renamed = Rename.fold_domain(domain);
+//! assert_eq!(renamed.predicates()[0].name(), "located-at");
+//! assert!(renamed.to_string().contains("(not (located-at ?x ?from))"));
+//! ```
+
+use crate::types::{
+    ActionDefinition, ActionSymbol, AssignOp, AssignOpT, AtomicFormula, AtomicFormulaSkeleton,
+    AtomicFunctionSkeleton, BasicFunctionTerm, BinaryComp, BinaryOp, CEffect, Con2GD, ConGD,
+    ConditionalEffect, Constants, DOp, DerivedPredicate, Domain, DomainConstraintsDef,
+    DurationConstraint, DurationValue, DurativeActionDefinition, DurativeActionEffect,
+    DurativeActionGoalDefinition, DurativeActionSymbol, Effects, EqualityAtomicFormula, FAssignDa,
+    FComp, FExp, FExpDa, FExpT, FHead, ForallCEffect, FunctionSymbol, FunctionTerm, FunctionType,
+    FunctionTyped, FunctionTypedList, Functions, GoalDef, GoalDefinition, InitElement,
+    InitElements, Interval, LengthSpec, Literal, MetricFExp, MetricSpec, MultiOp, Name,
+    NameLiteral, Number, Objects, Optimization, PEffect, PreconditionGoalDefinition,
+    PreconditionGoalDefinitions, Predicate, PredicateAtomicFormula, PredicateDefinitions,
+    PrefConGD, PrefConGDs, PrefTimedGD, Preference, PreferenceGD, PreferenceName, PrimitiveType,
+    Problem, ProblemConstraintsDef, Requirement, Requirements, SimpleDurationConstraint,
+    StructureDef, StructureDefs, Term, TermLiteral, TimeSpecifier, TimedEffect, TimedGD, Timeless,
+    Type, Typed, TypedList, TypedNames, TypedVariables, Types, Variable, WhenCEffect,
+};
+
+/// Rewrites the syntax tree of a [`Domain`] or [`Problem`] by value.
+///
+/// See the [module documentation](self) for an example.
+pub trait Fold {
+    // Domains and problems.
+
+    /// Folds a [`Domain`].
+    fn fold_domain(&mut self, node: Domain) -> Domain {
+        fold_domain(self, node)
+    }
+
+    /// Folds a [`Problem`].
+    fn fold_problem(&mut self, node: Problem) -> Problem {
+        fold_problem(self, node)
+    }
+
+    // Domain sections.
+
+    /// Folds a list of [`Requirements`].
+    fn fold_requirements(&mut self, node: Requirements) -> Requirements {
+        fold_requirements(self, node)
+    }
+
+    /// Folds a [`Requirement`].
+    fn fold_requirement(&mut self, node: Requirement) -> Requirement {
+        node
+    }
+
+    /// Folds the [`Types`] declaration of a domain.
+    fn fold_types(&mut self, node: Types) -> Types {
+        fold_types(self, node)
+    }
+
+    /// Folds the [`Constants`] declaration of a domain.
+    fn fold_constants(&mut self, node: Constants) -> Constants {
+        fold_constants(self, node)
+    }
+
+    /// Folds the [`PredicateDefinitions`] of a domain.
+    fn fold_predicate_definitions(&mut self, node: PredicateDefinitions) -> PredicateDefinitions {
+        fold_predicate_definitions(self, node)
+    }
+
+    /// Folds an [`AtomicFormulaSkeleton`], i.e. a predicate definition.
+    fn fold_atomic_formula_skeleton(
+        &mut self,
+        node: AtomicFormulaSkeleton,
+    ) -> AtomicFormulaSkeleton {
+        fold_atomic_formula_skeleton(self, node)
+    }
+
+    /// Folds the [`Functions`] declaration of a domain.
+    fn fold_functions(&mut self, node: Functions) -> Functions {
+        fold_functions(self, node)
+    }
+
+    /// Folds an [`AtomicFunctionSkeleton`], i.e. a function definition.
+    fn fold_atomic_function_skeleton(
+        &mut self,
+        node: AtomicFunctionSkeleton,
+    ) -> AtomicFunctionSkeleton {
+        fold_atomic_function_skeleton(self, node)
+    }
+
+    /// Folds a [`FunctionType`].
+    fn fold_function_type(&mut self, node: FunctionType) -> FunctionType {
+        fold_function_type(self, node)
+    }
+
+    /// Folds the [`Timeless`] predicates of a domain.
+    fn fold_timeless(&mut self, node: Timeless) -> Timeless {
+        fold_timeless(self, node)
+    }
+
+    /// Folds the [`ConGD`] constraints of a domain.
+    fn fold_domain_constraints_def(&mut self, node: DomainConstraintsDef) -> DomainConstraintsDef {
+        fold_domain_constraints_def(self, node)
+    }
+
+    /// Folds the [`StructureDefs`] of a domain.
+    fn fold_structure_defs(&mut self, node: StructureDefs) -> StructureDefs {
+        fold_structure_defs(self, node)
+    }
+
+    /// Folds a [`StructureDef`].
+    fn fold_structure_def(&mut self, node: StructureDef) -> StructureDef {
+        fold_structure_def(self, node)
+    }
+
+    /// Folds an [`ActionDefinition`].
+    fn fold_action_definition(&mut self, node: ActionDefinition) -> ActionDefinition {
+        fold_action_definition(self, node)
+    }
+
+    /// Folds a [`DurativeActionDefinition`].
+    fn fold_durative_action_definition(
+        &mut self,
+        node: DurativeActionDefinition,
+    ) -> DurativeActionDefinition {
+        fold_durative_action_definition(self, node)
+    }
+
+    /// Folds a [`DerivedPredicate`].
+    fn fold_derived_predicate(&mut self, node: DerivedPredicate) -> DerivedPredicate {
+        fold_derived_predicate(self, node)
+    }
+
+    // Problem sections.
+
+    /// Folds the [`Objects`] declaration of a problem.
+    fn fold_objects(&mut self, node: Objects) -> Objects {
+        fold_objects(self, node)
+    }
+
+    /// Folds the [`InitElements`] of a problem.
+    fn fold_init_elements(&mut self, node: InitElements) -> InitElements {
+        fold_init_elements(self, node)
+    }
+
+    /// Folds an [`InitElement`].
+    fn fold_init_element(&mut self, node: InitElement) -> InitElement {
+        fold_init_element(self, node)
+    }
+
+    /// Folds a [`BasicFunctionTerm`].
+    fn fold_basic_function_term(&mut self, node: BasicFunctionTerm) -> BasicFunctionTerm {
+        fold_basic_function_term(self, node)
+    }
+
+    /// Folds the [`GoalDef`] of a problem.
+    fn fold_goal_def(&mut self, node: GoalDef) -> GoalDef {
+        fold_goal_def(self, node)
+    }
+
+    /// Folds the [`PrefConGDs`] constraints of a problem.
+    fn fold_problem_constraints_def(
+        &mut self,
+        node: ProblemConstraintsDef,
+    ) -> ProblemConstraintsDef {
+        fold_problem_constraints_def(self, node)
+    }
+
+    /// Folds a [`MetricSpec`].
+    fn fold_metric_spec(&mut self, node: MetricSpec) -> MetricSpec {
+        fold_metric_spec(self, node)
+    }
+
+    /// Folds an [`Optimization`].
+    fn fold_optimization(&mut self, node: Optimization) -> Optimization {
+        node
+    }
+
+    /// Folds a [`MetricFExp`].
+    fn fold_metric_f_exp(&mut self, node: MetricFExp) -> MetricFExp {
+        fold_metric_f_exp(self, node)
+    }
+
+    /// Folds a [`LengthSpec`].
+    fn fold_length_spec(&mut self, node: LengthSpec) -> LengthSpec {
+        node
+    }
+
+    // Names and types.
+
+    /// Folds a [`Name`].
+    fn fold_name(&mut self, node: Name) -> Name {
+        node
+    }
+
+    /// Folds a [`Variable`].
+    fn fold_variable(&mut self, node: Variable) -> Variable {
+        fold_variable(self, node)
+    }
+
+    /// Folds a [`Predicate`].
+    fn fold_predicate(&mut self, node: Predicate) -> Predicate {
+        fold_predicate(self, node)
+    }
+
+    /// Folds a [`FunctionSymbol`].
+    fn fold_function_symbol(&mut self, node: FunctionSymbol) -> FunctionSymbol {
+        fold_function_symbol(self, node)
+    }
+
+    /// Folds an [`ActionSymbol`].
+    fn fold_action_symbol(&mut self, node: ActionSymbol) -> ActionSymbol {
+        fold_action_symbol(self, node)
+    }
+
+    /// Folds a [`DurativeActionSymbol`].
+    fn fold_durative_action_symbol(&mut self, node: DurativeActionSymbol) -> DurativeActionSymbol {
+        fold_durative_action_symbol(self, node)
+    }
+
+    /// Folds a [`PreferenceName`].
+    fn fold_preference_name(&mut self, node: PreferenceName) -> PreferenceName {
+        fold_preference_name(self, node)
+    }
+
+    /// Folds a list of [`TypedNames`], e.g. object declarations.
+    fn fold_typed_names(&mut self, node: TypedNames) -> TypedNames {
+        fold_typed_names(self, node)
+    }
+
+    /// Folds a [`Typed`] [`Name`].
+    fn fold_typed_name(&mut self, node: Typed<Name>) -> Typed<Name> {
+        fold_typed_name(self, node)
+    }
+
+    /// Folds a list of [`TypedVariables`], e.g. action parameters.
+    fn fold_typed_variables(&mut self, node: TypedVariables) -> TypedVariables {
+        fold_typed_variables(self, node)
+    }
+
+    /// Folds a [`Typed`] [`Variable`].
+    fn fold_typed_variable(&mut self, node: Typed<Variable>) -> Typed<Variable> {
+        fold_typed_variable(self, node)
+    }
+
+    /// Folds a [`Type`].
+    fn fold_type(&mut self, node: Type) -> Type {
+        fold_type(self, node)
+    }
+
+    /// Folds a [`PrimitiveType`].
+    fn fold_primitive_type(&mut self, node: PrimitiveType) -> PrimitiveType {
+        fold_primitive_type(self, node)
+    }
+
+    /// Folds a [`Number`].
+    fn fold_number(&mut self, node: Number) -> Number {
+        node
+    }
+
+    // Goals.
+
+    /// Folds a list of [`PreconditionGoalDefinitions`].
+    fn fold_precondition_goal_definitions(
+        &mut self,
+        node: PreconditionGoalDefinitions,
+    ) -> PreconditionGoalDefinitions {
+        fold_precondition_goal_definitions(self, node)
+    }
+
+    /// Folds a [`PreconditionGoalDefinition`].
+    fn fold_precondition_goal_definition(
+        &mut self,
+        node: PreconditionGoalDefinition,
+    ) -> PreconditionGoalDefinition {
+        fold_precondition_goal_definition(self, node)
+    }
+
+    /// Folds a [`PreferenceGD`].
+    fn fold_preference_gd(&mut self, node: PreferenceGD) -> PreferenceGD {
+        fold_preference_gd(self, node)
+    }
+
+    /// Folds a [`Preference`].
+    fn fold_preference(&mut self, node: Preference) -> Preference {
+        fold_preference(self, node)
+    }
+
+    /// Folds a [`GoalDefinition`].
+    fn fold_goal_definition(&mut self, node: GoalDefinition) -> GoalDefinition {
+        fold_goal_definition(self, node)
+    }
+
+    /// Folds an [`AtomicFormula`] over [`Term`]s, as used in goals and effects.
+    fn fold_term_atomic_formula(&mut self, node: AtomicFormula<Term>) -> AtomicFormula<Term> {
+        fold_term_atomic_formula(self, node)
+    }
+
+    /// Folds an [`AtomicFormula`] over [`Name`]s, as used in initial states.
+    fn fold_name_atomic_formula(&mut self, node: AtomicFormula<Name>) -> AtomicFormula<Name> {
+        fold_name_atomic_formula(self, node)
+    }
+
+    /// Folds a [`TermLiteral`].
+    fn fold_term_literal(&mut self, node: TermLiteral) -> TermLiteral {
+        fold_term_literal(self, node)
+    }
+
+    /// Folds a [`NameLiteral`].
+    fn fold_name_literal(&mut self, node: NameLiteral) -> NameLiteral {
+        fold_name_literal(self, node)
+    }
+
+    /// Folds a [`Term`].
+    fn fold_term(&mut self, node: Term) -> Term {
+        fold_term(self, node)
+    }
+
+    /// Folds a [`FunctionTerm`].
+    fn fold_function_term(&mut self, node: FunctionTerm) -> FunctionTerm {
+        fold_function_term(self, node)
+    }
+
+    /// Folds an [`FComp`].
+    fn fold_f_comp(&mut self, node: FComp) -> FComp {
+        fold_f_comp(self, node)
+    }
+
+    /// Folds a [`BinaryComp`].
+    fn fold_binary_comp(&mut self, node: BinaryComp) -> BinaryComp {
+        node
+    }
+
+    /// Folds an [`FExp`].
+    fn fold_f_exp(&mut self, node: FExp) -> FExp {
+        fold_f_exp(self, node)
+    }
+
+    /// Folds an [`FHead`].
+    fn fold_f_head(&mut self, node: FHead) -> FHead {
+        fold_f_head(self, node)
+    }
+
+    /// Folds a [`BinaryOp`].
+    fn fold_binary_op(&mut self, node: BinaryOp) -> BinaryOp {
+        node
+    }
+
+    /// Folds a [`MultiOp`].
+    fn fold_multi_op(&mut self, node: MultiOp) -> MultiOp {
+        node
+    }
+
+    // Effects.
+
+    /// Folds [`Effects`].
+    fn fold_effects(&mut self, node: Effects) -> Effects {
+        fold_effects(self, node)
+    }
+
+    /// Folds a [`CEffect`].
+    fn fold_c_effect(&mut self, node: CEffect) -> CEffect {
+        fold_c_effect(self, node)
+    }
+
+    /// Folds a [`ForallCEffect`].
+    fn fold_forall_c_effect(&mut self, node: ForallCEffect) -> ForallCEffect {
+        fold_forall_c_effect(self, node)
+    }
+
+    /// Folds a [`WhenCEffect`].
+    fn fold_when_c_effect(&mut self, node: WhenCEffect) -> WhenCEffect {
+        fold_when_c_effect(self, node)
+    }
+
+    /// Folds a [`ConditionalEffect`].
+    fn fold_conditional_effect(&mut self, node: ConditionalEffect) -> ConditionalEffect {
+        fold_conditional_effect(self, node)
+    }
+
+    /// Folds a [`PEffect`].
+    fn fold_p_effect(&mut self, node: PEffect) -> PEffect {
+        fold_p_effect(self, node)
+    }
+
+    /// Folds an [`AssignOp`].
+    fn fold_assign_op(&mut self, node: AssignOp) -> AssignOp {
+        node
+    }
+
+    // Durative actions.
+
+    /// Folds a [`DurationConstraint`].
+    fn fold_duration_constraint(&mut self, node: DurationConstraint) -> DurationConstraint {
+        fold_duration_constraint(self, node)
+    }
+
+    /// Folds a [`SimpleDurationConstraint`].
+    fn fold_simple_duration_constraint(
+        &mut self,
+        node: SimpleDurationConstraint,
+    ) -> SimpleDurationConstraint {
+        fold_simple_duration_constraint(self, node)
+    }
+
+    /// Folds a [`DOp`].
+    fn fold_d_op(&mut self, node: DOp) -> DOp {
+        node
+    }
+
+    /// Folds a [`DurationValue`].
+    fn fold_duration_value(&mut self, node: DurationValue) -> DurationValue {
+        fold_duration_value(self, node)
+    }
+
+    /// Folds a [`DurativeActionGoalDefinition`].
+    fn fold_durative_action_goal_definition(
+        &mut self,
+        node: DurativeActionGoalDefinition,
+    ) -> DurativeActionGoalDefinition {
+        fold_durative_action_goal_definition(self, node)
+    }
+
+    /// Folds a [`PrefTimedGD`].
+    fn fold_pref_timed_gd(&mut self, node: PrefTimedGD) -> PrefTimedGD {
+        fold_pref_timed_gd(self, node)
+    }
+
+    /// Folds a [`TimedGD`].
+    fn fold_timed_gd(&mut self, node: TimedGD) -> TimedGD {
+        fold_timed_gd(self, node)
+    }
+
+    /// Folds a [`TimeSpecifier`].
+    fn fold_time_specifier(&mut self, node: TimeSpecifier) -> TimeSpecifier {
+        node
+    }
+
+    /// Folds an [`Interval`].
+    fn fold_interval(&mut self, node: Interval) -> Interval {
+        node
+    }
+
+    /// Folds a [`DurativeActionEffect`].
+    fn fold_durative_action_effect(&mut self, node: DurativeActionEffect) -> DurativeActionEffect {
+        fold_durative_action_effect(self, node)
+    }
+
+    /// Folds a [`TimedEffect`].
+    fn fold_timed_effect(&mut self, node: TimedEffect) -> TimedEffect {
+        fold_timed_effect(self, node)
+    }
+
+    /// Folds an [`FAssignDa`].
+    fn fold_f_assign_da(&mut self, node: FAssignDa) -> FAssignDa {
+        fold_f_assign_da(self, node)
+    }
+
+    /// Folds an [`FExpDa`].
+    fn fold_f_exp_da(&mut self, node: FExpDa) -> FExpDa {
+        fold_f_exp_da(self, node)
+    }
+
+    /// Folds an [`AssignOpT`].
+    fn fold_assign_op_t(&mut self, node: AssignOpT) -> AssignOpT {
+        node
+    }
+
+    /// Folds an [`FExpT`].
+    fn fold_f_exp_t(&mut self, node: FExpT) -> FExpT {
+        fold_f_exp_t(self, node)
+    }
+
+    // Constraints.
+
+    /// Folds a [`ConGD`].
+    fn fold_con_gd(&mut self, node: ConGD) -> ConGD {
+        fold_con_gd(self, node)
+    }
+
+    /// Folds a [`Con2GD`].
+    fn fold_con2_gd(&mut self, node: Con2GD) -> Con2GD {
+        fold_con2_gd(self, node)
+    }
+
+    /// Folds a list of [`PrefConGDs`].
+    fn fold_pref_con_gds(&mut self, node: PrefConGDs) -> PrefConGDs {
+        fold_pref_con_gds(self, node)
+    }
+
+    /// Folds a [`PrefConGD`].
+    fn fold_pref_con_gd(&mut self, node: PrefConGD) -> PrefConGD {
+        fold_pref_con_gd(self, node)
+    }
+}
+
+/// Folds the name, sections and structure definitions of a [`Domain`].
+pub fn fold_domain<F: Fold + ?Sized>(folder: &mut F, node: Domain) -> Domain {
+    Domain {
+        name: folder.fold_name(node.name),
+        extends: node
+            .extends
+            .into_iter()
+            .map(|name| folder.fold_name(name))
+            .collect(),
+        requirements: folder.fold_requirements(node.requirements),
+        types: folder.fold_types(node.types),
+        constants: folder.fold_constants(node.constants),
+        predicates: folder.fold_predicate_definitions(node.predicates),
+        functions: folder.fold_functions(node.functions),
+        constraints: folder.fold_domain_constraints_def(node.constraints),
+        timeless: folder.fold_timeless(node.timeless),
+        structure: folder.fold_structure_defs(node.structure),
+        span: node.span,
+    }
+}
+
+/// Folds the names and sections of a [`Problem`].
+pub fn fold_problem<F: Fold + ?Sized>(folder: &mut F, node: Problem) -> Problem {
+    Problem {
+        name: folder.fold_name(node.name),
+        domain: folder.fold_name(node.domain),
+        requires: folder.fold_requirements(node.requires),
+        objects: folder.fold_objects(node.objects),
+        init: folder.fold_init_elements(node.init),
+        goal: folder.fold_goal_def(node.goal),
+        constraints: folder.fold_problem_constraints_def(node.constraints),
+        metric_spec: node
+            .metric_spec
+            .map(|metric| folder.fold_metric_spec(metric)),
+        length_spec: node
+            .length_spec
+            .map(|length| folder.fold_length_spec(length)),
+        span: node.span,
+    }
+}
+
+/// Folds each [`Requirement`].
+pub fn fold_requirements<F: Fold + ?Sized>(folder: &mut F, node: Requirements) -> Requirements {
+    Requirements(
+        node.0
+            .into_iter()
+            .map(|requirement| folder.fold_requirement(requirement))
+            .collect(),
+    )
+}
+
+/// Folds the declared types.
+pub fn fold_types<F: Fold + ?Sized>(folder: &mut F, node: Types) -> Types {
+    Types(folder.fold_typed_names(node.0))
+}
+
+/// Folds the declared constants.
+pub fn fold_constants<F: Fold + ?Sized>(folder: &mut F, node: Constants) -> Constants {
+    Constants(folder.fold_typed_names(node.0))
+}
+
+/// Folds each predicate definition.
+pub fn fold_predicate_definitions<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PredicateDefinitions,
+) -> PredicateDefinitions {
+    PredicateDefinitions(
+        node.0
+            .into_iter()
+            .map(|predicate| folder.fold_atomic_formula_skeleton(predicate))
+            .collect(),
+    )
+}
+
+/// Folds the predicate and parameters of an [`AtomicFormulaSkeleton`].
+pub fn fold_atomic_formula_skeleton<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AtomicFormulaSkeleton,
+) -> AtomicFormulaSkeleton {
+    AtomicFormulaSkeleton {
+        predicate: folder.fold_predicate(node.predicate),
+        variables: folder.fold_typed_variables(node.variables),
+        span: node.span,
+    }
+}
+
+/// Folds each function definition along with its type.
+pub fn fold_functions<F: Fold + ?Sized>(folder: &mut F, node: Functions) -> Functions {
+    Functions(FunctionTypedList(
+        node.0
+             .0
+            .into_iter()
+            .map(|function| {
+                FunctionTyped(
+                    folder.fold_atomic_function_skeleton(function.0),
+                    folder.fold_function_type(function.1),
+                )
+            })
+            .collect(),
+    ))
+}
+
+/// Folds the symbol and parameters of an [`AtomicFunctionSkeleton`].
+pub fn fold_atomic_function_skeleton<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AtomicFunctionSkeleton,
+) -> AtomicFunctionSkeleton {
+    AtomicFunctionSkeleton {
+        symbol: folder.fold_function_symbol(node.symbol),
+        variables: folder.fold_typed_variables(node.variables),
+    }
+}
+
+/// Folds the [`Type`] of a [`FunctionType`].
+pub fn fold_function_type<F: Fold + ?Sized>(folder: &mut F, node: FunctionType) -> FunctionType {
+    FunctionType(folder.fold_type(node.0))
+}
+
+/// Folds each timeless literal.
+pub fn fold_timeless<F: Fold + ?Sized>(folder: &mut F, node: Timeless) -> Timeless {
+    Timeless(
+        node.0
+            .into_iter()
+            .map(|literal| folder.fold_name_literal(literal))
+            .collect(),
+    )
+}
+
+/// Folds the constraints of a [`DomainConstraintsDef`].
+pub fn fold_domain_constraints_def<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DomainConstraintsDef,
+) -> DomainConstraintsDef {
+    DomainConstraintsDef(folder.fold_con_gd(node.0))
+}
+
+/// Folds each [`StructureDef`].
+pub fn fold_structure_defs<F: Fold + ?Sized>(folder: &mut F, node: StructureDefs) -> StructureDefs {
+    StructureDefs(
+        node.0
+            .into_iter()
+            .map(|def| folder.fold_structure_def(def))
+            .collect(),
+    )
+}
+
+/// Folds the action, durative action or derived predicate.
+pub fn fold_structure_def<F: Fold + ?Sized>(folder: &mut F, node: StructureDef) -> StructureDef {
+    match node {
+        StructureDef::Action(action) => StructureDef::Action(folder.fold_action_definition(action)),
+        StructureDef::DurativeAction(action) => {
+            StructureDef::DurativeAction(folder.fold_durative_action_definition(action))
+        }
+        StructureDef::Derived(derived) => {
+            StructureDef::Derived(folder.fold_derived_predicate(derived))
+        }
+    }
+}
+
+/// Folds the symbol, parameters, precondition and effect of an [`ActionDefinition`].
+pub fn fold_action_definition<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ActionDefinition,
+) -> ActionDefinition {
+    ActionDefinition {
+        symbol: folder.fold_action_symbol(node.symbol),
+        parameters: folder.fold_typed_variables(node.parameters),
+        precondition: folder.fold_precondition_goal_definitions(node.precondition),
+        effect: node.effect.map(|effect| folder.fold_effects(effect)),
+        span: node.span,
+    }
+}
+
+/// Folds the symbol, parameters, duration, condition and effect of a
+/// [`DurativeActionDefinition`].
+pub fn fold_durative_action_definition<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DurativeActionDefinition,
+) -> DurativeActionDefinition {
+    DurativeActionDefinition {
+        symbol: folder.fold_durative_action_symbol(node.symbol),
+        parameters: folder.fold_typed_variables(node.parameters),
+        duration: node
+            .duration
+            .map(|duration| folder.fold_duration_constraint(duration)),
+        condition: node
+            .condition
+            .map(|condition| folder.fold_durative_action_goal_definition(condition)),
+        effect: node
+            .effect
+            .map(|effect| folder.fold_durative_action_effect(effect)),
+        span: node.span,
+    }
+}
+
+/// Folds the predicate and the defining expression of a [`DerivedPredicate`].
+pub fn fold_derived_predicate<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DerivedPredicate,
+) -> DerivedPredicate {
+    DerivedPredicate(
+        folder.fold_atomic_formula_skeleton(node.0),
+        folder.fold_goal_definition(node.1),
+        node.2,
+    )
+}
+
+/// Folds the declared objects.
+pub fn fold_objects<F: Fold + ?Sized>(folder: &mut F, node: Objects) -> Objects {
+    Objects(folder.fold_typed_names(node.0))
+}
+
+/// Folds each [`InitElement`].
+pub fn fold_init_elements<F: Fold + ?Sized>(folder: &mut F, node: InitElements) -> InitElements {
+    InitElements(
+        node.0
+            .into_iter()
+            .map(|element| folder.fold_init_element(element))
+            .collect(),
+        node.1,
+    )
+}
+
+/// Folds the literal or function assignment of an [`InitElement`].
+pub fn fold_init_element<F: Fold + ?Sized>(folder: &mut F, node: InitElement) -> InitElement {
+    match node {
+        InitElement::Literal(literal) => InitElement::Literal(folder.fold_name_literal(literal)),
+        InitElement::At(time, literal) => {
+            InitElement::At(folder.fold_number(time), folder.fold_name_literal(literal))
+        }
+        InitElement::IsValue(term, value) => InitElement::IsValue(
+            folder.fold_basic_function_term(term),
+            folder.fold_number(value),
+        ),
+        InitElement::IsObject(term, value) => InitElement::IsObject(
+            folder.fold_basic_function_term(term),
+            folder.fold_name(value),
+        ),
+    }
+}
+
+/// Folds the symbol and names of a [`BasicFunctionTerm`].
+pub fn fold_basic_function_term<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: BasicFunctionTerm,
+) -> BasicFunctionTerm {
+    BasicFunctionTerm(
+        folder.fold_function_symbol(node.0),
+        node.1
+            .into_iter()
+            .map(|name| folder.fold_name(name))
+            .collect(),
+    )
+}
+
+/// Folds the goals of a [`GoalDef`].
+pub fn fold_goal_def<F: Fold + ?Sized>(folder: &mut F, node: GoalDef) -> GoalDef {
+    GoalDef(folder.fold_precondition_goal_definitions(node.0), node.1)
+}
+
+/// Folds the constraints of a [`ProblemConstraintsDef`].
+pub fn fold_problem_constraints_def<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ProblemConstraintsDef,
+) -> ProblemConstraintsDef {
+    ProblemConstraintsDef(folder.fold_pref_con_gds(node.0))
+}
+
+/// Folds the optimization and expression of a [`MetricSpec`].
+pub fn fold_metric_spec<F: Fold + ?Sized>(folder: &mut F, node: MetricSpec) -> MetricSpec {
+    MetricSpec {
+        optimization: folder.fold_optimization(node.optimization),
+        exp: folder.fold_metric_f_exp(node.exp),
+    }
+}
+
+/// Folds the operands of a [`MetricFExp`].
+pub fn fold_metric_f_exp<F: Fold + ?Sized>(folder: &mut F, node: MetricFExp) -> MetricFExp {
+    match node {
+        MetricFExp::BinaryOp(op, lhs, rhs) => MetricFExp::BinaryOp(
+            folder.fold_binary_op(op),
+            Box::new(folder.fold_metric_f_exp(*lhs)),
+            Box::new(folder.fold_metric_f_exp(*rhs)),
+        ),
+        MetricFExp::MultiOp(op, lhs, rhs) => MetricFExp::MultiOp(
+            folder.fold_multi_op(op),
+            Box::new(folder.fold_metric_f_exp(*lhs)),
+            rhs.into_iter()
+                .map(|exp| folder.fold_metric_f_exp(exp))
+                .collect(),
+        ),
+        MetricFExp::Negative(exp) => MetricFExp::Negative(Box::new(folder.fold_metric_f_exp(*exp))),
+        MetricFExp::Number(number) => MetricFExp::Number(folder.fold_number(number)),
+        MetricFExp::Function(symbol, names) => MetricFExp::Function(
+            folder.fold_function_symbol(symbol),
+            names
+                .into_iter()
+                .map(|name| folder.fold_name(name))
+                .collect(),
+        ),
+        MetricFExp::TotalTime => MetricFExp::TotalTime,
+        MetricFExp::IsViolated(name) => MetricFExp::IsViolated(folder.fold_preference_name(name)),
+    }
+}
+
+/// Folds the [`Name`] of a [`Variable`].
+pub fn fold_variable<F: Fold + ?Sized>(folder: &mut F, node: Variable) -> Variable {
+    Variable(folder.fold_name(node.0))
+}
+
+/// Folds the [`Name`] of a [`Predicate`].
+pub fn fold_predicate<F: Fold + ?Sized>(folder: &mut F, node: Predicate) -> Predicate {
+    Predicate(folder.fold_name(node.0))
+}
+
+/// Folds the [`Name`] of a [`FunctionSymbol`].
+pub fn fold_function_symbol<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: FunctionSymbol,
+) -> FunctionSymbol {
+    FunctionSymbol(folder.fold_name(node.0))
+}
+
+/// Folds the [`Name`] of an [`ActionSymbol`].
+pub fn fold_action_symbol<F: Fold + ?Sized>(folder: &mut F, node: ActionSymbol) -> ActionSymbol {
+    ActionSymbol(folder.fold_name(node.0))
+}
+
+/// Folds the [`Name`] of a [`DurativeActionSymbol`].
+pub fn fold_durative_action_symbol<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DurativeActionSymbol,
+) -> DurativeActionSymbol {
+    DurativeActionSymbol(folder.fold_name(node.0))
+}
+
+/// Folds the [`Name`] of a [`PreferenceName`].
+pub fn fold_preference_name<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PreferenceName,
+) -> PreferenceName {
+    PreferenceName(folder.fold_name(node.0))
+}
+
+/// Folds each typed name.
+pub fn fold_typed_names<F: Fold + ?Sized>(folder: &mut F, node: TypedNames) -> TypedNames {
+    TypedList(
+        node.0
+            .into_iter()
+            .map(|name| folder.fold_typed_name(name))
+            .collect(),
+    )
+}
+
+/// Folds the name and type of a typed name.
+pub fn fold_typed_name<F: Fold + ?Sized>(folder: &mut F, node: Typed<Name>) -> Typed<Name> {
+    Typed(folder.fold_name(node.0), folder.fold_type(node.1))
+}
+
+/// Folds each typed variable.
+pub fn fold_typed_variables<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: TypedVariables,
+) -> TypedVariables {
+    TypedList(
+        node.0
+            .into_iter()
+            .map(|variable| folder.fold_typed_variable(variable))
+            .collect(),
+    )
+}
+
+/// Folds the variable and type of a typed variable.
+pub fn fold_typed_variable<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: Typed<Variable>,
+) -> Typed<Variable> {
+    Typed(folder.fold_variable(node.0), folder.fold_type(node.1))
+}
+
+/// Folds each [`PrimitiveType`] of a [`Type`].
+pub fn fold_type<F: Fold + ?Sized>(folder: &mut F, node: Type) -> Type {
+    match node {
+        Type::Exactly(r#type) => Type::Exactly(folder.fold_primitive_type(r#type)),
+        Type::EitherOf(types) => Type::EitherOf(
+            types
+                .into_iter()
+                .map(|r#type| folder.fold_primitive_type(r#type))
+                .collect(),
+        ),
+    }
+}
+
+/// Folds the [`Name`] of a [`PrimitiveType`].
+pub fn fold_primitive_type<F: Fold + ?Sized>(folder: &mut F, node: PrimitiveType) -> PrimitiveType {
+    PrimitiveType(folder.fold_name(node.0))
+}
+
+/// Folds each [`PreconditionGoalDefinition`].
+pub fn fold_precondition_goal_definitions<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PreconditionGoalDefinitions,
+) -> PreconditionGoalDefinitions {
+    PreconditionGoalDefinitions(
+        node.0
+            .into_iter()
+            .map(|gd| folder.fold_precondition_goal_definition(gd))
+            .collect(),
+    )
+}
+
+/// Folds the preference or the quantified goals of a [`PreconditionGoalDefinition`].
+pub fn fold_precondition_goal_definition<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: PreconditionGoalDefinition,
+) -> PreconditionGoalDefinition {
+    match node {
+        PreconditionGoalDefinition::Preference(pref) => {
+            PreconditionGoalDefinition::Preference(folder.fold_preference_gd(pref))
+        }
+        PreconditionGoalDefinition::Forall(variables, gds) => PreconditionGoalDefinition::Forall(
+            folder.fold_typed_variables(variables),
+            folder.fold_precondition_goal_definitions(gds),
+        ),
+    }
+}
+
+/// Folds the goal or the preference of a [`PreferenceGD`].
+pub fn fold_preference_gd<F: Fold + ?Sized>(folder: &mut F, node: PreferenceGD) -> PreferenceGD {
+    match node {
+        PreferenceGD::Goal(gd) => PreferenceGD::Goal(folder.fold_goal_definition(gd)),
+        PreferenceGD::Preference(pref) => PreferenceGD::Preference(folder.fold_preference(pref)),
+    }
+}
+
+/// Folds the optional name and the goal of a [`Preference`].
+pub fn fold_preference<F: Fold + ?Sized>(folder: &mut F, node: Preference) -> Preference {
+    Preference(
+        node.0.map(|name| folder.fold_preference_name(name)),
+        folder.fold_goal_definition(node.1),
+    )
+}
+
+/// Folds the formulas, subgoals and quantified variables of a [`GoalDefinition`].
+pub fn fold_goal_definition<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: GoalDefinition,
+) -> GoalDefinition {
+    match node {
+        GoalDefinition::AtomicFormula(formula) => {
+            GoalDefinition::AtomicFormula(folder.fold_term_atomic_formula(formula))
+        }
+        GoalDefinition::Literal(literal) => {
+            GoalDefinition::Literal(folder.fold_term_literal(literal))
+        }
+        GoalDefinition::And(gds) => GoalDefinition::And(
+            gds.into_iter()
+                .map(|gd| folder.fold_goal_definition(gd))
+                .collect(),
+        ),
+        GoalDefinition::Or(gds) => GoalDefinition::Or(
+            gds.into_iter()
+                .map(|gd| folder.fold_goal_definition(gd))
+                .collect(),
+        ),
+        GoalDefinition::Not(gd) => GoalDefinition::Not(Box::new(folder.fold_goal_definition(*gd))),
+        GoalDefinition::Imply(antecedent, consequent) => GoalDefinition::Imply(
+            Box::new(folder.fold_goal_definition(*antecedent)),
+            Box::new(folder.fold_goal_definition(*consequent)),
+        ),
+        GoalDefinition::Exists(variables, gd) => GoalDefinition::Exists(
+            folder.fold_typed_variables(variables),
+            Box::new(folder.fold_goal_definition(*gd)),
+        ),
+        GoalDefinition::ForAll(variables, gd) => GoalDefinition::ForAll(
+            folder.fold_typed_variables(variables),
+            Box::new(folder.fold_goal_definition(*gd)),
+        ),
+        GoalDefinition::FComp(comp) => GoalDefinition::FComp(folder.fold_f_comp(comp)),
+    }
+}
+
+/// Folds the predicate and terms of an [`AtomicFormula`] over [`Term`]s.
+pub fn fold_term_atomic_formula<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AtomicFormula<Term>,
+) -> AtomicFormula<Term> {
+    match node {
+        AtomicFormula::Equality(formula) => AtomicFormula::Equality(EqualityAtomicFormula {
+            first: folder.fold_term(formula.first),
+            second: folder.fold_term(formula.second),
+        }),
+        AtomicFormula::Predicate(formula) => AtomicFormula::Predicate(PredicateAtomicFormula {
+            predicate: folder.fold_predicate(formula.predicate),
+            values: formula
+                .values
+                .into_iter()
+                .map(|term| folder.fold_term(term))
+                .collect(),
+        }),
+    }
+}
+
+/// Folds the predicate and names of an [`AtomicFormula`] over [`Name`]s.
+pub fn fold_name_atomic_formula<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: AtomicFormula<Name>,
+) -> AtomicFormula<Name> {
+    match node {
+        AtomicFormula::Equality(formula) => AtomicFormula::Equality(EqualityAtomicFormula {
+            first: folder.fold_name(formula.first),
+            second: folder.fold_name(formula.second),
+        }),
+        AtomicFormula::Predicate(formula) => AtomicFormula::Predicate(PredicateAtomicFormula {
+            predicate: folder.fold_predicate(formula.predicate),
+            values: formula
+                .values
+                .into_iter()
+                .map(|name| folder.fold_name(name))
+                .collect(),
+        }),
+    }
+}
+
+/// Folds the atomic formula of a [`TermLiteral`].
+pub fn fold_term_literal<F: Fold + ?Sized>(folder: &mut F, node: TermLiteral) -> TermLiteral {
+    match node {
+        Literal::AtomicFormula(formula) => {
+            Literal::AtomicFormula(folder.fold_term_atomic_formula(formula))
+        }
+        Literal::NotAtomicFormula(formula) => {
+            Literal::NotAtomicFormula(folder.fold_term_atomic_formula(formula))
+        }
+    }
+}
+
+/// Folds the atomic formula of a [`NameLiteral`].
+pub fn fold_name_literal<F: Fold + ?Sized>(folder: &mut F, node: NameLiteral) -> NameLiteral {
+    match node {
+        Literal::AtomicFormula(formula) => {
+            Literal::AtomicFormula(folder.fold_name_atomic_formula(formula))
+        }
+        Literal::NotAtomicFormula(formula) => {
+            Literal::NotAtomicFormula(folder.fold_name_atomic_formula(formula))
+        }
+    }
+}
+
+/// Folds the name, variable or function term of a [`Term`].
+pub fn fold_term<F: Fold + ?Sized>(folder: &mut F, node: Term) -> Term {
+    match node {
+        Term::Name(name) => Term::Name(folder.fold_name(name)),
+        Term::Variable(variable) => Term::Variable(folder.fold_variable(variable)),
+        Term::Function(function) => Term::Function(folder.fold_function_term(function)),
+    }
+}
+
+/// Folds the symbol and terms of a [`FunctionTerm`].
+pub fn fold_function_term<F: Fold + ?Sized>(folder: &mut F, node: FunctionTerm) -> FunctionTerm {
+    FunctionTerm(
+        folder.fold_function_symbol(node.0),
+        node.1
+            .into_iter()
+            .map(|term| folder.fold_term(term))
+            .collect(),
+    )
+}
+
+/// Folds the comparison and operands of an [`FComp`].
+pub fn fold_f_comp<F: Fold + ?Sized>(folder: &mut F, node: FComp) -> FComp {
+    FComp(
+        folder.fold_binary_comp(node.0),
+        folder.fold_f_exp(node.1),
+        folder.fold_f_exp(node.2),
+    )
+}
+
+/// Folds the operands of an [`FExp`].
+pub fn fold_f_exp<F: Fold + ?Sized>(folder: &mut F, node: FExp) -> FExp {
+    match node {
+        FExp::Number(number) => FExp::Number(folder.fold_number(number)),
+        FExp::Function(head) => FExp::Function(folder.fold_f_head(head)),
+        FExp::Negative(exp) => FExp::Negative(Box::new(folder.fold_f_exp(*exp))),
+        FExp::BinaryOp(op, lhs, rhs) => FExp::BinaryOp(
+            folder.fold_binary_op(op),
+            Box::new(folder.fold_f_exp(*lhs)),
+            Box::new(folder.fold_f_exp(*rhs)),
+        ),
+        FExp::MultiOp(op, lhs, rhs) => FExp::MultiOp(
+            folder.fold_multi_op(op),
+            Box::new(folder.fold_f_exp(*lhs)),
+            rhs.into_iter().map(|exp| folder.fold_f_exp(exp)).collect(),
+        ),
+    }
+}
+
+/// Folds the symbol and terms of an [`FHead`].
+pub fn fold_f_head<F: Fold + ?Sized>(folder: &mut F, node: FHead) -> FHead {
+    match node {
+        FHead::Simple(symbol) => FHead::Simple(folder.fold_function_symbol(symbol)),
+        FHead::WithTerms(symbol, terms) => FHead::WithTerms(
+            folder.fold_function_symbol(symbol),
+            terms
+                .into_iter()
+                .map(|term| folder.fold_term(term))
+                .collect(),
+        ),
+    }
+}
+
+/// Folds each [`CEffect`].
+pub fn fold_effects<F: Fold + ?Sized>(folder: &mut F, node: Effects) -> Effects {
+    Effects(
+        node.0
+            .into_iter()
+            .map(|effect| folder.fold_c_effect(effect))
+            .collect(),
+    )
+}
+
+/// Folds the primitive, universal or conditional effect of a [`CEffect`].
+pub fn fold_c_effect<F: Fold + ?Sized>(folder: &mut F, node: CEffect) -> CEffect {
+    match node {
+        CEffect::Effect(effect) => CEffect::Effect(folder.fold_p_effect(effect)),
+        CEffect::Forall(effect) => CEffect::Forall(folder.fold_forall_c_effect(effect)),
+        CEffect::When(effect) => CEffect::When(folder.fold_when_c_effect(effect)),
+    }
+}
+
+/// Folds the variables and effects of a [`ForallCEffect`].
+pub fn fold_forall_c_effect<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ForallCEffect,
+) -> ForallCEffect {
+    ForallCEffect {
+        variables: folder.fold_typed_variables(node.variables),
+        effects: folder.fold_effects(node.effects),
+    }
+}
+
+/// Folds the condition and effect of a [`WhenCEffect`].
+pub fn fold_when_c_effect<F: Fold + ?Sized>(folder: &mut F, node: WhenCEffect) -> WhenCEffect {
+    WhenCEffect {
+        condition: folder.fold_goal_definition(node.condition),
+        effect: folder.fold_conditional_effect(node.effect),
+    }
+}
+
+/// Folds each [`PEffect`] of a [`ConditionalEffect`].
+pub fn fold_conditional_effect<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: ConditionalEffect,
+) -> ConditionalEffect {
+    match node {
+        ConditionalEffect::Single(effect) => {
+            ConditionalEffect::Single(folder.fold_p_effect(effect))
+        }
+        ConditionalEffect::All(effects) => ConditionalEffect::All(
+            effects
+                .into_iter()
+                .map(|effect| folder.fold_p_effect(effect))
+                .collect(),
+        ),
+    }
+}
+
+/// Folds the formula or the assignment of a [`PEffect`].
+pub fn fold_p_effect<F: Fold + ?Sized>(folder: &mut F, node: PEffect) -> PEffect {
+    match node {
+        PEffect::AtomicFormula(formula) => {
+            PEffect::AtomicFormula(folder.fold_term_atomic_formula(formula))
+        }
+        PEffect::NotAtomicFormula(formula) => {
+            PEffect::NotAtomicFormula(folder.fold_term_atomic_formula(formula))
+        }
+        PEffect::AssignNumericFluent(op, head, exp) => PEffect::AssignNumericFluent(
+            folder.fold_assign_op(op),
+            folder.fold_f_head(head),
+            folder.fold_f_exp(exp),
+        ),
+        PEffect::AssignObjectFluent(function, term) => PEffect::AssignObjectFluent(
+            folder.fold_function_term(function),
+            term.map(|term| folder.fold_term(term)),
+        ),
+    }
+}
+
+/// Folds each [`SimpleDurationConstraint`] of a [`DurationConstraint`].
+pub fn fold_duration_constraint<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DurationConstraint,
+) -> DurationConstraint {
+    match node {
+        DurationConstraint::Single(constraint) => {
+            DurationConstraint::Single(folder.fold_simple_duration_constraint(constraint))
+        }
+        DurationConstraint::All(constraints) => DurationConstraint::All(
+            constraints
+                .into_iter()
+                .map(|constraint| folder.fold_simple_duration_constraint(constraint))
+                .collect(),
+        ),
+    }
+}
+
+/// Folds the operation and value, or the time specifier and nested constraint,
+/// of a [`SimpleDurationConstraint`].
+pub fn fold_simple_duration_constraint<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: SimpleDurationConstraint,
+) -> SimpleDurationConstraint {
+    match node {
+        SimpleDurationConstraint::Op(op, value) => {
+            SimpleDurationConstraint::Op(folder.fold_d_op(op), folder.fold_duration_value(value))
+        }
+        SimpleDurationConstraint::At(time, constraint) => SimpleDurationConstraint::At(
+            folder.fold_time_specifier(time),
+            Box::new(folder.fold_simple_duration_constraint(*constraint)),
+        ),
+    }
+}
+
+/// Folds the number or expression of a [`DurationValue`].
+pub fn fold_duration_value<F: Fold + ?Sized>(folder: &mut F, node: DurationValue) -> DurationValue {
+    match node {
+        DurationValue::Number(number) => DurationValue::Number(folder.fold_number(number)),
+        DurationValue::FExp(exp) => DurationValue::FExp(folder.fold_f_exp(exp)),
+    }
+}
+
+/// Folds the timed goals, subgoals and quantified variables of a
+/// [`DurativeActionGoalDefinition`].
+pub fn fold_durative_action_goal_definition<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DurativeActionGoalDefinition,
+) -> DurativeActionGoalDefinition {
+    match node {
+        DurativeActionGoalDefinition::Timed(gd) => {
+            DurativeActionGoalDefinition::Timed(folder.fold_pref_timed_gd(gd))
+        }
+        DurativeActionGoalDefinition::And(gds) => DurativeActionGoalDefinition::And(
+            gds.into_iter()
+                .map(|gd| folder.fold_durative_action_goal_definition(gd))
+                .collect(),
+        ),
+        DurativeActionGoalDefinition::Forall(variables, gd) => {
+            DurativeActionGoalDefinition::Forall(
+                folder.fold_typed_variables(variables),
+                Box::new(folder.fold_durative_action_goal_definition(*gd)),
+            )
+        }
+    }
+}
+
+/// Folds the optional preference name and the timed goal of a [`PrefTimedGD`].
+pub fn fold_pref_timed_gd<F: Fold + ?Sized>(folder: &mut F, node: PrefTimedGD) -> PrefTimedGD {
+    match node {
+        PrefTimedGD::Required(gd) => PrefTimedGD::Required(folder.fold_timed_gd(gd)),
+        PrefTimedGD::Preference(name, gd) => PrefTimedGD::Preference(
+            name.map(|name| folder.fold_preference_name(name)),
+            folder.fold_timed_gd(gd),
+        ),
+    }
+}
+
+/// Folds the time specifier or interval and the goal of a [`TimedGD`].
+pub fn fold_timed_gd<F: Fold + ?Sized>(folder: &mut F, node: TimedGD) -> TimedGD {
+    match node {
+        TimedGD::At(time, gd) => TimedGD::At(
+            folder.fold_time_specifier(time),
+            folder.fold_goal_definition(gd),
+        ),
+        TimedGD::Over(interval, gd) => TimedGD::Over(
+            folder.fold_interval(interval),
+            folder.fold_goal_definition(gd),
+        ),
+    }
+}
+
+/// Folds the timed effects, conditions and quantified variables of a
+/// [`DurativeActionEffect`].
+pub fn fold_durative_action_effect<F: Fold + ?Sized>(
+    folder: &mut F,
+    node: DurativeActionEffect,
+) -> DurativeActionEffect {
+    match node {
+        DurativeActionEffect::Timed(effect) => {
+            DurativeActionEffect::Timed(folder.fold_timed_effect(effect))
+        }
+        DurativeActionEffect::All(effects) => DurativeActionEffect::All(
+            effects
+                .into_iter()
+                .map(|effect| folder.fold_durative_action_effect(effect))
+                .collect(),
+        ),
+        DurativeActionEffect::Forall(variables, effect) => DurativeActionEffect::Forall(
+            folder.fold_typed_variables(variables),
+            Box::new(folder.fold_durative_action_effect(*effect)),
+        ),
+        DurativeActionEffect::When(condition, effect) => DurativeActionEffect::When(
+            folder.fold_durative_action_goal_definition(condition),
+            folder.fold_timed_effect(effect),
+        ),
+    }
+}
+
+/// Folds the time specifier and effect, or the continuous effect, of a [`TimedEffect`].
+pub fn fold_timed_effect<F: Fold + ?Sized>(folder: &mut F, node: TimedEffect) -> TimedEffect {
+    match node {
+        TimedEffect::Conditional(time, effect) => TimedEffect::Conditional(
+            folder.fold_time_specifier(time),
+            folder.fold_conditional_effect(effect),
+        ),
+        TimedEffect::NumericFluent(time, assignment) => TimedEffect::NumericFluent(
+            folder.fold_time_specifier(time),
+            folder.fold_f_assign_da(assignment),
+        ),
+        TimedEffect::ContinuousEffect(op, head, exp) => TimedEffect::ContinuousEffect(
+            folder.fold_assign_op_t(op),
+            folder.fold_f_head(head),
+            folder.fold_f_exp_t(exp),
+        ),
+    }
+}
+
+/// Folds the operation, function and expression of an [`FAssignDa`].
+pub fn fold_f_assign_da<F: Fold + ?Sized>(folder: &mut F, node: FAssignDa) -> FAssignDa {
+    FAssignDa(
+        folder.fold_assign_op(node.0),
+        folder.fold_f_head(node.1),
+        folder.fold_f_exp_da(node.2),
+    )
+}
+
+/// Folds the operands of an [`FExpDa`].
+pub fn fold_f_exp_da<F: Fold + ?Sized>(folder: &mut F, node: FExpDa) -> FExpDa {
+    match node {
+        FExpDa::Assign(op, head, exp) => FExpDa::Assign(
+            folder.fold_assign_op(op),
+            folder.fold_f_head(head),
+            Box::new(folder.fold_f_exp_da(*exp)),
+        ),
+        FExpDa::BinaryOp(op, lhs, rhs) => FExpDa::BinaryOp(
+            folder.fold_binary_op(op),
+            Box::new(folder.fold_f_exp_da(*lhs)),
+            Box::new(folder.fold_f_exp_da(*rhs)),
+        ),
+        FExpDa::MultiOp(op, lhs, rhs) => FExpDa::MultiOp(
+            folder.fold_multi_op(op),
+            Box::new(folder.fold_f_exp_da(*lhs)),
+            rhs.into_iter()
+                .map(|exp| folder.fold_f_exp_da(exp))
+                .collect(),
+        ),
+        FExpDa::Negative(exp) => FExpDa::Negative(Box::new(folder.fold_f_exp_da(*exp))),
+        FExpDa::Duration => FExpDa::Duration,
+        FExpDa::FExp(exp) => FExpDa::FExp(folder.fold_f_exp(exp)),
+    }
+}
+
+/// Folds the scaled expression of an [`FExpT`].
+pub fn fold_f_exp_t<F: Fold + ?Sized>(folder: &mut F, node: FExpT) -> FExpT {
+    match node {
+        FExpT::Now => FExpT::Now,
+        FExpT::Scaled(exp) => FExpT::Scaled(folder.fold_f_exp(exp)),
+    }
+}
+
+/// Folds the goals, nested constraints, numbers and quantified variables of a [`ConGD`].
+pub fn fold_con_gd<F: Fold + ?Sized>(folder: &mut F, node: ConGD) -> ConGD {
+    match node {
+        ConGD::And(gds) => ConGD::And(gds.into_iter().map(|gd| folder.fold_con_gd(gd)).collect()),
+        ConGD::Forall(variables, gd) => ConGD::Forall(
+            folder.fold_typed_variables(variables),
+            Box::new(folder.fold_con_gd(*gd)),
+        ),
+        ConGD::AtEnd(gd) => ConGD::AtEnd(folder.fold_goal_definition(gd)),
+        ConGD::Always(gd) => ConGD::Always(folder.fold_con2_gd(gd)),
+        ConGD::Sometime(gd) => ConGD::Sometime(folder.fold_con2_gd(gd)),
+        ConGD::Within(number, gd) => {
+            ConGD::Within(folder.fold_number(number), folder.fold_con2_gd(gd))
+        }
+        ConGD::AtMostOnce(gd) => ConGD::AtMostOnce(folder.fold_con2_gd(gd)),
+        ConGD::SometimeAfter(first, second) => {
+            ConGD::SometimeAfter(folder.fold_con2_gd(first), folder.fold_con2_gd(second))
+        }
+        ConGD::SometimeBefore(first, second) => {
+            ConGD::SometimeBefore(folder.fold_con2_gd(first), folder.fold_con2_gd(second))
+        }
+        ConGD::AlwaysWithin(number, first, second) => ConGD::AlwaysWithin(
+            folder.fold_number(number),
+            folder.fold_con2_gd(first),
+            folder.fold_con2_gd(second),
+        ),
+        ConGD::HoldDuring(start, end, gd) => ConGD::HoldDuring(
+            folder.fold_number(start),
+            folder.fold_number(end),
+            folder.fold_con2_gd(gd),
+        ),
+        ConGD::HoldAfter(number, gd) => {
+            ConGD::HoldAfter(folder.fold_number(number), folder.fold_con2_gd(gd))
+        }
+    }
+}
+
+/// Folds the goal or nested constraint of a [`Con2GD`].
+pub fn fold_con2_gd<F: Fold + ?Sized>(folder: &mut F, node: Con2GD) -> Con2GD {
+    match node {
+        Con2GD::Goal(gd) => Con2GD::Goal(folder.fold_goal_definition(gd)),
+        Con2GD::Nested(gd) => Con2GD::Nested(Box::new(folder.fold_con_gd(*gd))),
+    }
+}
+
+/// Folds each [`PrefConGD`].
+pub fn fold_pref_con_gds<F: Fold + ?Sized>(folder: &mut F, node: PrefConGDs) -> PrefConGDs {
+    PrefConGDs(
+        node.0
+            .into_iter()
+            .map(|gd| folder.fold_pref_con_gd(gd))
+            .collect(),
+    )
+}
+
+/// Folds the constraint, preference name and quantified variables of a [`PrefConGD`].
+pub fn fold_pref_con_gd<F: Fold + ?Sized>(folder: &mut F, node: PrefConGD) -> PrefConGD {
+    match node {
+        PrefConGD::Goal(gd) => PrefConGD::Goal(folder.fold_con_gd(gd)),
+        PrefConGD::Forall(variables, gds) => PrefConGD::Forall(
+            folder.fold_typed_variables(variables),
+            folder.fold_pref_con_gds(gds),
+        ),
+        PrefConGD::Preference(name, gd) => PrefConGD::Preference(
+            name.map(|name| folder.fold_preference_name(name)),
+            folder.fold_con_gd(gd),
+        ),
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    const DOMAIN: &str = r#"(define (domain briefcase-world)
+        (:requirements :strips :typing :conditional-effects :durative-actions :constraints)
+        (:types location physob)
+        (:constants B - physob)
+        (:predicates (at ?x - physob ?y - location) (in ?x - physob))
+        (:functions (distance ?from ?to - location))
+        (:constraints (always (at B home)))
+        (:action mov-B
+            :parameters (?m ?l - location)
+            :precondition (and (at B ?m) (not (= ?m ?l)))
+            :effect (and (at B ?l) (not (at B ?m))
+                         (forall (?z)
+                             (when (and (in ?z) (not (= ?z B)))
+                                   (and (at ?z ?l) (not (at ?z ?m)))))))
+        (:durative-action drive
+            :parameters (?from ?to - location)
+            :duration (= ?duration (distance ?from ?to))
+            :condition (at start (at B ?from))
+            :effect (and (at start (not (at B ?from))) (at end (at B ?to)))))"#;
+
+    const PROBLEM: &str = r#"(define (problem get-paid)
+        (:domain briefcase-world)
+        (:objects home office - location P - physob)
+        (:init (at B home) (at P home) (in P) (= (distance home office) 10))
+        (:goal (and (at B office) (at P home)))
+        (:metric minimize (total-time)))"#;
+
+    /// Rebuilds every node unchanged.
+    struct Identity;
+
+    impl Fold for Identity {}
+
+    /// Prefixes every variable name with `v-`.
+    struct RenameVariables;
+
+    impl Fold for RenameVariables {
+        fn fold_variable(&mut self, node: Variable) -> Variable {
+            Variable::from(format!("v-{}", &*node))
+        }
+    }
+
+    #[test]
+    fn identity_rebuilds_unchanged() {
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        assert_eq!(Identity.fold_domain(domain.clone()), domain);
+
+        let problem = Problem::from_str(PROBLEM).unwrap();
+        assert_eq!(Identity.fold_problem(problem.clone()), problem);
+    }
+
+    #[test]
+    fn rewrites_nested_nodes() {
+        let domain = RenameVariables.fold_domain(Domain::from_str(DOMAIN).unwrap());
+        let printed = domain.to_string();
+
+        assert!(!printed.contains("?m"));
+        assert!(printed.contains("(at ?v-z ?v-l)"));
+        assert!(printed.contains("(distance ?v-from ?v-to)"));
+        assert_eq!(Domain::from_str(&printed).unwrap(), domain);
+    }
+
+    #[cfg(feature = "spans")]
+    #[test]
+    fn preserves_spans() {
+        use crate::Spanned;
+
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        let folded = RenameVariables.fold_domain(domain.clone());
+        assert_eq!(folded.span(), domain.span());
+        assert_eq!(folded.structure()[1].span(), domain.structure()[1].span());
+
+        let problem = Problem::from_str(PROBLEM).unwrap();
+        let folded = Identity.fold_problem(problem.clone());
+        assert_eq!(folded.init().span_of(3), problem.init().span_of(3));
+    }
+}
@@ -0,0 +1,158 @@
+//! A canonical formatter for PDDL files.
+//!
+//! Unlike the [`Display`](std::fmt::Display) implementations of the domain and problem types,
+//! the formatter operates on the textual representation of the input and keeps `;` line
+//! comments in place.
+//!
+//! ## Example
+//!
+//! ```
+//! use pddl::format::{format, FormatOptions};
+//!
+//! let input = r#"(define (problem get-paid) (:domain briefcase-world) ; the domain
+//!     (:init (at B home) (at P home) (in P))
+//!     (:goal (and (at B office) (at P home))))"#;
+//!
+//! let formatted = format(input, &FormatOptions::default()).unwrap();
+//! assert_eq!(formatted, r#"(define (problem get-paid)
+//!   (:domain briefcase-world) ; the domain
+//!   (:init (at B home) (at P home) (in P))
+//!   (:goal (and (at B office) (at P home)))
+//! )
+//! "#);
+//! ```
+
+mod options;
+mod reader;
+mod writer;
+
+pub use options::{FormatOptions, SectionOrder};
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Formats PDDL source text according to the specified options.
+///
+/// The input is not required to be a valid domain or problem definition; any
+/// sequence of balanced parenthesized expressions and comments is accepted.
+pub fn format(input: &str, options: &FormatOptions) -> Result<String, FormatError> {
+    let items = reader::read(input)?;
+    Ok(writer::Writer::new(options).write(&items))
+}
+
+/// An error returned by [`format`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FormatError {
+    /// A parenthesis was opened at the specified position but never closed.
+    UnclosedParenthesis { line: usize, column: usize },
+    /// A parenthesis was closed at the specified position without being opened.
+    UnexpectedClosingParenthesis { line: usize, column: usize },
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatError::UnclosedParenthesis { line, column } => {
+                write!(f, "Unclosed parenthesis at line {line}, column {column}")
+            }
+            FormatError::UnexpectedClosingParenthesis { line, column } => {
+                write!(
+                    f,
+                    "Unexpected closing parenthesis at line {line}, column {column}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOMAIN: &str = r#"
+; A toy domain.
+(define (domain briefcase-world)
+      (:predicates (at ?x - physob ?y - location)
+                   (in ?x ?y - physob))
+      (:requirements :strips :equality :typing :conditional-effects)
+      (:types location physob) ; type definitions could also be represented as predicates
+      (:constants
+            B ; the briefcase
+            P ; the paycheck
+            D
+            - physob)
+
+
+      ; Move briefcase from one location to another.
+      (:action mov-B
+           :parameters (?m ?l - location)
+           :precondition (and (at B ?m) (not (= ?m ?l)))
+           :effect (and (at B ?l) (not (at B ?m))
+                        (forall (?z)
+                            (when (and (in ?z) (not (= ?z B)))
+                                  (and (at ?z ?l) (not (at ?z ?m)))))) )
+)"#;
+
+    #[test]
+    fn format_keeps_comments() {
+        let formatted = format(DOMAIN, &FormatOptions::default()).unwrap();
+        assert_eq!(
+            formatted,
+            r#"; A toy domain.
+(define (domain briefcase-world)
+  (:predicates (at ?x - physob ?y - location) (in ?x ?y - physob))
+  (:requirements :strips :equality :typing :conditional-effects)
+  (:types location physob) ; type definitions could also be represented as predicates
+  (:constants B ; the briefcase
+    P ; the paycheck
+    D - physob)
+
+  ; Move briefcase from one location to another.
+  (:action mov-B
+    :parameters (?m ?l - location)
+    :precondition (and (at B ?m) (not (= ?m ?l)))
+    :effect (and
+      (at B ?l)
+      (not (at B ?m))
+      (forall (?z)
+        (when (and (in ?z) (not (= ?z B))) (and (at ?z ?l) (not (at ?z ?m)))))))
+)
+"#
+        );
+    }
+
+    #[test]
+    fn format_sorts_sections() {
+        let options = FormatOptions::default().with_section_order(SectionOrder::Sorted);
+        let formatted = format(DOMAIN, &options).unwrap();
+        let requirements = formatted.find("(:requirements").unwrap();
+        let types = formatted.find("(:types").unwrap();
+        let predicates = formatted.find("(:predicates").unwrap();
+        let action = formatted.find("(:action").unwrap();
+        assert!(requirements < types && types < predicates && predicates < action);
+
+        // Comments stay attached to their sections.
+        assert!(formatted.contains("(:types location physob) ; type definitions"));
+        assert!(formatted.contains("; Move briefcase from one location to another.\n  (:action"));
+    }
+
+    #[test]
+    fn format_wraps_long_lines() {
+        let input = "(define (problem p) (:domain d) (:init (a) (b) (c) (d) (e) (f) (g)))";
+        let options = FormatOptions::default().with_line_width(20).with_indent(4);
+        let formatted = format(input, &options).unwrap();
+        assert_eq!(
+            formatted,
+            "(define (problem p)\n    (:domain d)\n    (:init\n        (a)\n        (b)\n        (c)\n        (d)\n        (e)\n        (f)\n        (g))\n)\n"
+        );
+    }
+
+    #[test]
+    fn format_is_idempotent() {
+        let options = FormatOptions::default();
+        let formatted = format(DOMAIN, &options).unwrap();
+        assert_eq!(format(&formatted, &options).unwrap(), formatted);
+    }
+}
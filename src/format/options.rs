@@ -0,0 +1,58 @@
+//! Contains the formatter configuration via the [`FormatOptions`] type.
+
+/// Options controlling the output of [`format`](crate::format::format).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FormatOptions {
+    /// The number of spaces used for each level of indentation.
+    pub indent: usize,
+    /// The maximum line width. Expressions that do not fit within this width,
+    /// such as long `:init` or `:goal` blocks, are broken up over multiple lines.
+    pub line_width: usize,
+    /// The order in which the sections of a domain or problem are emitted.
+    pub section_order: SectionOrder,
+}
+
+/// The order in which the sections of a domain or problem definition are emitted.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum SectionOrder {
+    /// Keep the sections in the order they appear in the input.
+    #[default]
+    Original,
+    /// Sort the sections into the order given by the PDDL BNF, e.g. `:requirements` before
+    /// `:types` before `:predicates` in domains, and `:objects` before `:init` before
+    /// `:goal` in problems. Structure definitions such as actions keep their relative order.
+    Sorted,
+}
+
+impl FormatOptions {
+    pub const DEFAULT_INDENT: usize = 2;
+    pub const DEFAULT_LINE_WIDTH: usize = 80;
+
+    /// Sets the number of spaces used for each level of indentation.
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the maximum line width.
+    pub fn with_line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    /// Sets the order in which sections are emitted.
+    pub fn with_section_order(mut self, section_order: SectionOrder) -> Self {
+        self.section_order = section_order;
+        self
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent: Self::DEFAULT_INDENT,
+            line_width: Self::DEFAULT_LINE_WIDTH,
+            section_order: SectionOrder::default(),
+        }
+    }
+}
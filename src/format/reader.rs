@@ -0,0 +1,197 @@
+//! Reads PDDL into a lightweight syntax tree that retains comments.
+
+use crate::format::FormatError;
+
+/// An element of the syntax tree.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    /// A name, variable, keyword or number, e.g. `?x` or `:requirements`.
+    Atom(String),
+    /// A parenthesized list of elements.
+    List(Vec<Item>),
+    /// A line comment, including the leading `;`.
+    Comment(String),
+}
+
+/// A [`Node`] along with the layout information of the input that is kept when formatting.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Item {
+    pub node: Node,
+    /// Whether the input had at least one empty line before this element.
+    pub blank_line_before: bool,
+    /// Whether this element started on the same line as the preceding element.
+    /// This is only relevant for comments.
+    pub same_line: bool,
+}
+
+impl Node {
+    /// Returns the atom's text, if this node is an atom.
+    pub fn as_atom(&self) -> Option<&str> {
+        match self {
+            Node::Atom(atom) => Some(atom),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of the leading atom, if this node is a list starting with an atom.
+    pub fn head(&self) -> Option<&str> {
+        match self {
+            Node::List(items) => items.first().and_then(|item| item.node.as_atom()),
+            _ => None,
+        }
+    }
+
+    pub fn is_comment(&self) -> bool {
+        matches!(self, Node::Comment(_))
+    }
+}
+
+/// Reads all top-level elements of the input.
+pub(crate) fn read(input: &str) -> Result<Vec<Item>, FormatError> {
+    let mut reader = Reader {
+        input,
+        position: 0,
+        line: 1,
+        column: 1,
+    };
+    reader.read_items(None)
+}
+
+struct Reader<'a> {
+    input: &'a str,
+    position: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Skips whitespace and returns the number of line breaks encountered.
+    fn skip_whitespace(&mut self) -> usize {
+        let mut newlines = 0;
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            if c == '\n' {
+                newlines += 1;
+            }
+            self.bump();
+        }
+        newlines
+    }
+
+    /// Consumes characters while the predicate holds and returns them.
+    fn take_while<P: Fn(char) -> bool>(&mut self, predicate: P) -> &'a str {
+        let start = self.position;
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            self.bump();
+        }
+        &self.input[start..self.position]
+    }
+
+    /// Reads elements up to the closing parenthesis of the list opened at `open`,
+    /// or up to the end of the input if `open` is `None`.
+    fn read_items(&mut self, open: Option<(usize, usize)>) -> Result<Vec<Item>, FormatError> {
+        let mut items = Vec::new();
+        // The opening parenthesis counts as a preceding element for comments.
+        let mut at_line_start = open.is_none();
+
+        loop {
+            let newlines = self.skip_whitespace();
+            let same_line = newlines == 0 && !at_line_start;
+            let blank_line_before = newlines > 1 && !items.is_empty();
+            at_line_start = false;
+
+            let (line, column) = (self.line, self.column);
+            let node = match self.peek() {
+                None => {
+                    return match open {
+                        None => Ok(items),
+                        Some((line, column)) => {
+                            Err(FormatError::UnclosedParenthesis { line, column })
+                        }
+                    }
+                }
+                Some(')') => {
+                    return match open {
+                        None => Err(FormatError::UnexpectedClosingParenthesis { line, column }),
+                        Some(_) => {
+                            self.bump();
+                            Ok(items)
+                        }
+                    };
+                }
+                Some('(') => {
+                    self.bump();
+                    Node::List(self.read_items(Some((line, column)))?)
+                }
+                Some(';') => {
+                    let comment = self.take_while(|c| c != '\n');
+                    Node::Comment(comment.trim_end().to_string())
+                }
+                Some(_) => {
+                    let atom = self.take_while(|c| !c.is_whitespace() && !"();".contains(c));
+                    Node::Atom(atom.to_string())
+                }
+            };
+
+            items.push(Item {
+                node,
+                blank_line_before,
+                same_line,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn atom(text: &str) -> Node {
+        Node::Atom(text.to_string())
+    }
+
+    #[test]
+    fn read_works() {
+        let items = read("(at ?x) ; where\n\n; next\n(in ?y)").unwrap();
+        assert_eq!(items.len(), 4);
+        assert!(matches!(&items[0].node, Node::List(list) if list[0].node == atom("at")));
+        assert_eq!(items[1].node, Node::Comment("; where".to_string()));
+        assert!(items[1].same_line);
+        assert!(!items[2].same_line);
+        assert!(items[2].blank_line_before);
+        assert_eq!(items[3].node.head(), Some("in"));
+    }
+
+    #[test]
+    fn unbalanced_parentheses_are_reported() {
+        assert_eq!(
+            read("(define\n  (domain x)").unwrap_err(),
+            FormatError::UnclosedParenthesis { line: 1, column: 1 }
+        );
+        assert_eq!(
+            read("(a))").unwrap_err(),
+            FormatError::UnexpectedClosingParenthesis { line: 1, column: 4 }
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! Writes a syntax tree according to the [`FormatOptions`].
+
+use crate::format::reader::{Item, Node};
+use crate::format::{FormatOptions, SectionOrder};
+
+/// Lists that keep their first list argument on the opening line when broken up,
+/// e.g. `(define (domain name)` or `(forall (?x - t)`.
+const HEAD_WITH_LIST_ARGUMENT: &[&str] = &["define", "forall", "exists"];
+
+pub(crate) struct Writer<'a> {
+    options: &'a FormatOptions,
+    out: String,
+    /// Whether the current line ends in a comment, in which case nothing else
+    /// may be written to it.
+    in_comment: bool,
+}
+
+impl<'a> Writer<'a> {
+    pub fn new(options: &'a FormatOptions) -> Self {
+        Self {
+            options,
+            out: String::new(),
+            in_comment: false,
+        }
+    }
+
+    /// Writes all top-level elements and returns the formatted text.
+    pub fn write(mut self, items: &[Item]) -> String {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                if item.same_line && item.node.is_comment() {
+                    self.write_trailing_comment(&item.node);
+                    continue;
+                }
+                self.newline(0, item.blank_line_before);
+            }
+            self.write_node(&item.node, 0);
+        }
+
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.out
+    }
+
+    fn column(&self) -> usize {
+        match self.out.rfind('\n') {
+            Some(index) => self.out[index + 1..].chars().count(),
+            None => self.out.chars().count(),
+        }
+    }
+
+    fn newline(&mut self, level: usize, blank_line: bool) {
+        if blank_line {
+            self.out.push('\n');
+        }
+        self.out.push('\n');
+        self.out
+            .extend(std::iter::repeat(' ').take(level * self.options.indent));
+        self.in_comment = false;
+    }
+
+    fn push(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    fn write_trailing_comment(&mut self, node: &Node) {
+        self.push(" ");
+        self.write_node(node, 0);
+    }
+
+    fn write_node(&mut self, node: &Node, level: usize) {
+        match node {
+            Node::Atom(atom) => self.push(atom),
+            Node::Comment(comment) => {
+                self.push(comment);
+                self.in_comment = true;
+            }
+            Node::List(items) => self.write_list(node, items, level),
+        }
+    }
+
+    fn write_list(&mut self, node: &Node, items: &[Item], level: usize) {
+        let is_define = node
+            .head()
+            .map_or(false, |head| head.eq_ignore_ascii_case("define"));
+        if !is_define {
+            if let Some(flat) = flat(node) {
+                if self.column() + flat.len() <= self.options.line_width {
+                    self.push(&flat);
+                    return;
+                }
+            }
+        }
+
+        let sorted;
+        let items = if is_define && self.options.section_order == SectionOrder::Sorted {
+            sorted = sort_sections(items);
+            &sorted
+        } else {
+            items
+        };
+
+        self.push("(");
+        if items
+            .iter()
+            .all(|item| matches!(item.node, Node::Atom(_) | Node::Comment(_)))
+        {
+            self.fill(items, level);
+        } else {
+            self.break_up(items, level);
+        }
+
+        if self.in_comment || is_define {
+            self.newline(level, false);
+        }
+        self.push(")");
+    }
+
+    /// Writes the elements of a list that only holds atoms, packing as many
+    /// of them onto each line as the line width allows.
+    fn fill(&mut self, items: &[Item], level: usize) {
+        for (i, item) in items.iter().enumerate() {
+            match &item.node {
+                Node::Comment(_) => {
+                    if item.same_line && i > 0 {
+                        self.write_trailing_comment(&item.node);
+                    } else {
+                        if i > 0 {
+                            self.newline(level + 1, item.blank_line_before);
+                        }
+                        self.write_node(&item.node, level + 1);
+                    }
+                }
+                Node::Atom(atom) => {
+                    if i == 0 {
+                        // The opening parenthesis was just written.
+                    } else if self.in_comment
+                        || self.column() + 1 + atom.len() > self.options.line_width
+                    {
+                        self.newline(level + 1, false);
+                    } else {
+                        self.push(" ");
+                    }
+                    self.push(atom);
+                }
+                Node::List(_) => unreachable!("only atoms and comments are filled"),
+            }
+        }
+    }
+
+    /// Writes the elements of a list one per line, keeping the leading atoms on the
+    /// opening line and keywords such as `:parameters` next to their value.
+    fn break_up(&mut self, items: &[Item], level: usize) {
+        let mut index = 0;
+
+        if let Some(head) = items.first().and_then(|item| item.node.as_atom()) {
+            self.push(head);
+            index = 1;
+
+            while let Some(atom) = items.get(index).and_then(|item| item.node.as_atom()) {
+                if atom.starts_with(':') {
+                    break;
+                }
+                self.push(" ");
+                self.push(atom);
+                index += 1;
+            }
+
+            if HEAD_WITH_LIST_ARGUMENT
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(head))
+            {
+                if let Some(argument) = items.get(index).map(|item| &item.node) {
+                    if let Some(flat) = flat(argument).filter(|_| argument.head().is_some()) {
+                        if self.column() + 1 + flat.len() <= self.options.line_width {
+                            self.push(" ");
+                            self.push(&flat);
+                            index += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        while index < items.len() {
+            let item = &items[index];
+            index += 1;
+
+            if item.same_line && item.node.is_comment() && !self.out.ends_with('(') {
+                self.write_trailing_comment(&item.node);
+                continue;
+            }
+
+            self.newline(level + 1, item.blank_line_before);
+            self.write_node(&item.node, level + 1);
+
+            // Keep keywords on the same line as their value, e.g. `:parameters (?x)`.
+            let is_keyword = item.node.as_atom().map_or(false, |a| a.starts_with(':'));
+            if let Some(next) = items.get(index).filter(|_| is_keyword) {
+                if let Node::List(_) = next.node {
+                    self.push(" ");
+                    self.write_node(&next.node, level + 1);
+                    index += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the single-line representation of the node, or `None` if it contains comments.
+fn flat(node: &Node) -> Option<String> {
+    match node {
+        Node::Atom(atom) => Some(atom.clone()),
+        Node::Comment(_) => None,
+        Node::List(items) => {
+            let mut out = String::from("(");
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(' ');
+                }
+                out.push_str(&flat(&item.node)?);
+            }
+            out.push(')');
+            Some(out)
+        }
+    }
+}
+
+/// Sorts the sections of a `(define ...)` list into the order given by the PDDL BNF.
+///
+/// Comments on their own lines move along with the section that follows them,
+/// comments on the same line move along with the section that precedes them.
+fn sort_sections(items: &[Item]) -> Vec<Item> {
+    // Keep `define` and the `(domain ...)` or `(problem ...)` name in place.
+    let mut head: Vec<Item> = items.iter().take(2).cloned().collect();
+    let mut groups: Vec<(usize, Vec<Item>)> = Vec::new();
+    let mut pending = Vec::new();
+
+    for item in items.iter().skip(2) {
+        if item.node.is_comment() {
+            if item.same_line && pending.is_empty() {
+                match groups.last_mut() {
+                    Some((_, group)) => group.push(item.clone()),
+                    None => head.push(item.clone()),
+                }
+            } else {
+                pending.push(item.clone());
+            }
+            continue;
+        }
+
+        pending.push(item.clone());
+        groups.push((section_rank(&item.node), std::mem::take(&mut pending)));
+    }
+
+    groups.sort_by_key(|(rank, _)| *rank);
+    head.extend(groups.into_iter().flat_map(|(_, group)| group));
+    head.extend(pending);
+    head
+}
+
+fn section_rank(node: &Node) -> usize {
+    let head = node.head().unwrap_or_default().to_ascii_lowercase();
+    match head.as_str() {
+        ":domain" | ":extends" => 0,
+        ":requirements" => 1,
+        ":types" => 2,
+        ":constants" | ":objects" => 3,
+        ":predicates" | ":init" => 4,
+        ":timeless" | ":goal" => 5,
+        ":functions" => 6,
+        ":constraints" => 7,
+        ":metric" => 8,
+        ":length" => 9,
+        ":action" | ":durative-action" | ":derived" => 10,
+        _ => 11,
+    }
+}
@@ -0,0 +1,209 @@
+//! Contains the symbol table of a domain via the [`DomainIndex`] type.
+
+use crate::index::{
+    ActionId, ActionSignature, FunctionId, FunctionSignature, ObjectId, PredicateId, Signature,
+    SymbolTable, TypeId,
+};
+use crate::types::{Domain, Name, StructureDef, Type, TypeHierarchy, Typed};
+
+/// The predicates, functions, types, actions and constants of a [`Domain`], each
+/// identified by a dense numeric identifier.
+///
+/// Symbols are numbered in order of their declaration. If a name is declared more than
+/// once, its first declaration is used. Actions and durative actions share the same
+/// namespace.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Type};
+/// use pddl::index::DomainIndex;
+///
+/// let domain = Domain::from_str(r#"(define (domain logistics)
+///     (:requirements :typing)
+///     (:types truck location)
+///     (:predicates (at ?t - truck ?l - location) (road ?from ?to - location))
+///     (:action drive
+///         :parameters (?t - truck ?from ?to - location)
+///         :precondition (and (at ?t ?from) (road ?from ?to))
+///         :effect (and (not (at ?t ?from)) (at ?t ?to))))"#).unwrap();
+///
+/// let index = DomainIndex::new(&domain);
+///
+/// let at = index.predicate("at").unwrap();
+/// assert_eq!(index.predicates()[at].arity(), 2);
+/// assert_eq!(index.predicates()[at].parameters(), [Type::from("truck"), Type::from("location")]);
+///
+/// let drive = index.action("drive").unwrap();
+/// assert_eq!(index.actions()[drive].arity(), 3);
+/// assert!(index.predicate("in").is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DomainIndex {
+    predicates: SymbolTable<PredicateId, Signature>,
+    functions: SymbolTable<FunctionId, FunctionSignature>,
+    types: SymbolTable<TypeId, Name>,
+    actions: SymbolTable<ActionId, ActionSignature>,
+    constants: SymbolTable<ObjectId, Typed<Name>>,
+    hierarchy: TypeHierarchy,
+}
+
+impl DomainIndex {
+    /// Indexes the declarations of the `domain`.
+    pub fn new(domain: &Domain) -> Self {
+        let mut predicates = SymbolTable::new();
+        for predicate in domain.predicates().iter() {
+            let name = predicate.predicate();
+            predicates.insert(
+                name,
+                Signature::new((**name).clone(), predicate.variables()),
+            );
+        }
+
+        let mut functions = SymbolTable::new();
+        for function in domain.functions().iter() {
+            let skeleton = function.value_ref();
+            let name = skeleton.symbol();
+            let signature = Signature::new((**name).clone(), skeleton.variables());
+            let r#type = (**function.type_ref()).clone();
+            functions.insert(name, FunctionSignature::new(signature, r#type));
+        }
+
+        let hierarchy = TypeHierarchy::new(domain.types());
+        let mut types = SymbolTable::new();
+        for r#type in hierarchy.types() {
+            types.insert(r#type, r#type.clone());
+        }
+
+        let mut actions = SymbolTable::new();
+        for def in domain.structure().iter() {
+            let (name, parameters, durative) = match def {
+                StructureDef::Action(action) => (&**action.symbol(), action.parameters(), false),
+                StructureDef::DurativeAction(action) => {
+                    (&**action.symbol(), action.parameters(), true)
+                }
+                StructureDef::Derived(_) => continue,
+            };
+            let signature = Signature::new(name.clone(), parameters);
+            actions.insert(name, ActionSignature::new(signature, durative));
+        }
+
+        let mut constants = SymbolTable::new();
+        for constant in domain.constants().iter() {
+            constants.insert(constant.value(), constant.clone());
+        }
+
+        Self {
+            predicates,
+            functions,
+            types,
+            actions,
+            constants,
+            hierarchy,
+        }
+    }
+
+    /// Gets the identifier of the predicate with the given name.
+    pub fn predicate(&self, name: &str) -> Option<PredicateId> {
+        self.predicates.get(name)
+    }
+
+    /// Gets the identifier of the function with the given name.
+    pub fn function(&self, name: &str) -> Option<FunctionId> {
+        self.functions.get(name)
+    }
+
+    /// Gets the identifier of the type with the given name.
+    pub fn type_(&self, name: &str) -> Option<TypeId> {
+        self.types.get(name)
+    }
+
+    /// Gets the identifier of the action or durative action with the given name.
+    pub fn action(&self, name: &str) -> Option<ActionId> {
+        self.actions.get(name)
+    }
+
+    /// Gets the identifier of the constant with the given name.
+    pub fn constant(&self, name: &str) -> Option<ObjectId> {
+        self.constants.get(name)
+    }
+
+    /// Gets the declared predicates.
+    pub const fn predicates(&self) -> &SymbolTable<PredicateId, Signature> {
+        &self.predicates
+    }
+
+    /// Gets the declared functions.
+    pub const fn functions(&self) -> &SymbolTable<FunctionId, FunctionSignature> {
+        &self.functions
+    }
+
+    /// Gets the types of the [`hierarchy`](Self::hierarchy), starting with `object`.
+    pub const fn types(&self) -> &SymbolTable<TypeId, Name> {
+        &self.types
+    }
+
+    /// Gets the declared actions and durative actions.
+    pub const fn actions(&self) -> &SymbolTable<ActionId, ActionSignature> {
+        &self.actions
+    }
+
+    /// Gets the declared constants along with their types.
+    pub const fn constants(&self) -> &SymbolTable<ObjectId, Typed<Name>> {
+        &self.constants
+    }
+
+    /// Gets the resolved subtype relation of the declared types.
+    pub const fn hierarchy(&self) -> &TypeHierarchy {
+        &self.hierarchy
+    }
+
+    /// Gets the declared type of the constant with the given name.
+    pub fn constant_type(&self, name: &str) -> Option<&Type> {
+        self.constants.lookup(name).map(Typed::type_)
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::index::SymbolId;
+    use crate::Parser;
+
+    #[test]
+    fn assigns_dense_ids_in_declaration_order() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :typing :fluents :durative-actions)
+                (:types b - a c)
+                (:constants x - b y)
+                (:predicates (p) (q ?x - a) (p ?x ?y))
+                (:functions (f ?x - c) - number (g) - a)
+                (:action one :parameters () :effect (p))
+                (:durative-action two :parameters (?x - c) :duration (= ?duration 1)
+                    :condition (and) :effect (at end (p))))"#,
+        )
+        .unwrap();
+        let index = DomainIndex::new(&domain);
+
+        assert_eq!(index.predicates().len(), 2);
+        assert_eq!(index.predicate("q").map(SymbolId::index), Some(1));
+        assert_eq!(index.predicates()[index.predicate("p").unwrap()].arity(), 0);
+
+        let g = index.function("g").unwrap();
+        assert_eq!(index.functions()[g].type_(), &Type::from("a"));
+        assert_eq!(index.functions()[g].arity(), 0);
+
+        assert_eq!(index.type_("object").map(SymbolId::index), Some(0));
+        assert_eq!(index.types().len(), 4);
+        assert!(index.hierarchy().is_subtype(&"b".into(), &"a".into()));
+
+        let two = index.action("two").unwrap();
+        assert_eq!(two.index(), 1);
+        assert!(index.actions()[two].is_durative());
+        assert_eq!(index.actions()[two].parameters(), [Type::from("c")]);
+
+        assert_eq!(index.constant("y").map(SymbolId::index), Some(1));
+        assert_eq!(index.constant_type("x"), Some(&Type::from("b")));
+        assert_eq!(index.constant_type("y"), Some(&Type::OBJECT));
+    }
+}
@@ -0,0 +1,118 @@
+//! Contains the numeric identifiers of indexed symbols.
+
+use std::fmt::{Display, Formatter};
+
+/// A dense numeric identifier of a symbol in a [`SymbolTable`](crate::index::SymbolTable).
+///
+/// Identifiers of a table are assigned consecutively, starting at zero, and can therefore
+/// be used to index vectors or bit sets holding per-symbol data.
+pub trait SymbolId: Copy {
+    /// Constructs the identifier of the symbol at the given position of its table.
+    fn new(index: u32) -> Self;
+
+    /// Gets the position of the symbol in its table.
+    fn index(self) -> usize;
+}
+
+/// The identifier of a predicate of a [`DomainIndex`](crate::index::DomainIndex).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PredicateId(u32);
+
+/// The identifier of a function of a [`DomainIndex`](crate::index::DomainIndex).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct FunctionId(u32);
+
+/// The identifier of a type of a [`DomainIndex`](crate::index::DomainIndex).
+///
+/// The implicit `object` type always has the identifier zero.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TypeId(u32);
+
+/// The identifier of an action or durative action of a [`DomainIndex`](crate::index::DomainIndex).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ActionId(u32);
+
+/// The identifier of a constant of a [`DomainIndex`](crate::index::DomainIndex) or an object
+/// of a [`ProblemIndex`](crate::index::ProblemIndex).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ObjectId(u32);
+
+impl SymbolId for PredicateId {
+    fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl SymbolId for FunctionId {
+    fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl SymbolId for TypeId {
+    fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl SymbolId for ActionId {
+    fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl SymbolId for ObjectId {
+    fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Display for PredicateId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "p{}", self.0)
+    }
+}
+
+impl Display for FunctionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "f{}", self.0)
+    }
+}
+
+impl Display for TypeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "t{}", self.0)
+    }
+}
+
+impl Display for ActionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a{}", self.0)
+    }
+}
+
+impl Display for ObjectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "o{}", self.0)
+    }
+}
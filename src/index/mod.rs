@@ -0,0 +1,20 @@
+//! Provides symbol tables that map the names declared by domains and problems to
+//! dense numeric identifiers.
+//!
+//! A [`DomainIndex`] resolves the predicates, functions, types, actions and constants
+//! of a [`Domain`](crate::Domain) once, so that consumers such as grounders can look
+//! up symbols and their [`Signature`]s in constant time and store per-symbol data in
+//! vectors indexed by identifier. A [`ProblemIndex`] adds the objects of a
+//! [`Problem`](crate::Problem).
+
+mod domain_index;
+mod ids;
+mod problem_index;
+mod signature;
+mod symbol_table;
+
+pub use domain_index::DomainIndex;
+pub use ids::{ActionId, FunctionId, ObjectId, PredicateId, SymbolId, TypeId};
+pub use problem_index::ProblemIndex;
+pub use signature::{ActionSignature, FunctionSignature, Signature};
+pub use symbol_table::SymbolTable;
@@ -0,0 +1,89 @@
+//! Contains the symbol table of a problem via the [`ProblemIndex`] type.
+
+use crate::index::{DomainIndex, ObjectId, SymbolTable};
+use crate::types::{Domain, Name, Problem, Type, Typed};
+
+/// The [`DomainIndex`] of a domain along with the objects of a [`Problem`].
+///
+/// Objects are numbered with the domain's constants first, so that each constant has
+/// the same [`ObjectId`] as in the [`DomainIndex`], followed by the problem's objects
+/// in order of their declaration.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem, Type};
+/// use pddl::index::ProblemIndex;
+///
+/// let domain = Domain::from_str(r#"(define (domain logistics)
+///     (:types truck location)
+///     (:constants depot - location))"#).unwrap();
+///
+/// let problem = Problem::from_str(r#"(define (problem deliver)
+///     (:domain logistics)
+///     (:objects t1 t2 - truck home - location)
+///     (:init)
+///     (:goal (and)))"#).unwrap();
+///
+/// let index = ProblemIndex::new(&domain, &problem);
+/// assert_eq!(index.objects().len(), 4);
+/// assert_eq!(index.object("depot"), index.domain().constant("depot"));
+///
+/// let locations = index.objects_of_type(&Type::from("location"));
+/// assert_eq!(locations, [index.object("depot").unwrap(), index.object("home").unwrap()]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProblemIndex {
+    domain: DomainIndex,
+    objects: SymbolTable<ObjectId, Typed<Name>>,
+}
+
+impl ProblemIndex {
+    /// Indexes the declarations of the `domain` and the objects of the `problem`.
+    pub fn new(domain: &Domain, problem: &Problem) -> Self {
+        Self::from_domain_index(DomainIndex::new(domain), problem)
+    }
+
+    /// Adds the objects of the `problem` to an existing index of its domain.
+    pub fn from_domain_index(domain: DomainIndex, problem: &Problem) -> Self {
+        let mut objects = SymbolTable::new();
+        for (_, constant) in domain.constants().iter() {
+            objects.insert(constant.value(), constant.clone());
+        }
+        for object in problem.objects().iter() {
+            objects.insert(object.value(), object.clone());
+        }
+
+        Self { domain, objects }
+    }
+
+    /// Gets the index of the domain.
+    pub const fn domain(&self) -> &DomainIndex {
+        &self.domain
+    }
+
+    /// Gets the identifier of the constant or object with the given name.
+    pub fn object(&self, name: &str) -> Option<ObjectId> {
+        self.objects.get(name)
+    }
+
+    /// Gets the domain's constants and the problem's objects along with their types.
+    pub const fn objects(&self) -> &SymbolTable<ObjectId, Typed<Name>> {
+        &self.objects
+    }
+
+    /// Gets the declared type of the constant or object with the given name.
+    pub fn object_type(&self, name: &str) -> Option<&Type> {
+        self.objects.lookup(name).map(Typed::type_)
+    }
+
+    /// Gets the constants and objects whose declared type may be used where a value
+    /// of type `r#type` is expected.
+    pub fn objects_of_type(&self, r#type: &Type) -> Vec<ObjectId> {
+        let hierarchy = self.domain.hierarchy();
+        self.objects
+            .iter()
+            .filter(|(_, object)| hierarchy.is_assignable(object.type_(), r#type))
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
@@ -0,0 +1,115 @@
+//! Contains the declared parameters of indexed symbols via the [`Signature`] type.
+
+use crate::types::{Name, Type, TypedVariables};
+use std::ops::Deref;
+
+/// The name and parameter types of a predicate, function or action.
+///
+/// ## Usage
+/// Used by [`DomainIndex`](crate::index::DomainIndex).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Signature {
+    name: Name,
+    parameters: Vec<Type>,
+}
+
+impl Signature {
+    /// Constructs the signature of a symbol with the given parameters.
+    pub fn new(name: Name, parameters: &TypedVariables) -> Self {
+        Self {
+            name,
+            parameters: parameters
+                .iter()
+                .map(|parameter| parameter.type_().clone())
+                .collect(),
+        }
+    }
+
+    /// Gets the name of the symbol.
+    pub const fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Gets the types of the parameters.
+    pub fn parameters(&self) -> &[Type] {
+        &self.parameters
+    }
+
+    /// Gets the number of parameters.
+    pub fn arity(&self) -> usize {
+        self.parameters.len()
+    }
+}
+
+/// The signature and value type of a function.
+///
+/// ## Usage
+/// Used by [`DomainIndex`](crate::index::DomainIndex).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FunctionSignature {
+    signature: Signature,
+    r#type: Type,
+}
+
+impl FunctionSignature {
+    /// Constructs the signature of a function with the given parameters and value type.
+    pub const fn new(signature: Signature, r#type: Type) -> Self {
+        Self { signature, r#type }
+    }
+
+    /// Gets the name and parameter types of the function.
+    pub const fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Gets the type of the function's value, e.g. `number` for numeric fluents.
+    pub const fn type_(&self) -> &Type {
+        &self.r#type
+    }
+}
+
+impl Deref for FunctionSignature {
+    type Target = Signature;
+
+    fn deref(&self) -> &Self::Target {
+        &self.signature
+    }
+}
+
+/// The signature of an action or durative action.
+///
+/// ## Usage
+/// Used by [`DomainIndex`](crate::index::DomainIndex).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ActionSignature {
+    signature: Signature,
+    durative: bool,
+}
+
+impl ActionSignature {
+    /// Constructs the signature of an action with the given parameters.
+    pub const fn new(signature: Signature, durative: bool) -> Self {
+        Self {
+            signature,
+            durative,
+        }
+    }
+
+    /// Gets the name and parameter types of the action.
+    pub const fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Returns `true` if the action is a durative action.
+    pub const fn is_durative(&self) -> bool {
+        self.durative
+    }
+}
+
+impl Deref for ActionSignature {
+    type Target = Signature;
+
+    fn deref(&self) -> &Self::Target {
+        &self.signature
+    }
+}
@@ -0,0 +1,100 @@
+//! Contains name lookups via the [`SymbolTable`] type.
+
+use crate::index::SymbolId;
+use crate::types::Name;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::ops::Index;
+
+/// Symbols with dense numeric identifiers and constant-time lookup by name.
+///
+/// Names are looked up like [`Name`] compares them, i.e. without regard to case
+/// if the `case-folding` crate feature is enabled.
+/// If a name is inserted more than once, its first entry is kept.
+///
+/// ## Usage
+/// Used by [`DomainIndex`](crate::index::DomainIndex) and
+/// [`ProblemIndex`](crate::index::ProblemIndex).
+#[derive(Debug, Clone)]
+pub struct SymbolTable<I, T> {
+    entries: Vec<T>,
+    /// The identifier of each name, keyed by its [folded](fold) spelling.
+    ids: HashMap<Box<str>, u32>,
+    id: PhantomData<I>,
+}
+
+impl<I: SymbolId, T> SymbolTable<I, T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            ids: HashMap::new(),
+            id: PhantomData,
+        }
+    }
+
+    /// Adds the `entry` under the `name`, unless the name is already taken,
+    /// and returns the identifier of the name.
+    pub(crate) fn insert(&mut self, name: &Name, entry: T) -> I {
+        let key = Box::from(fold(name).as_ref());
+        if let Some(&id) = self.ids.get(&key) {
+            return I::new(id);
+        }
+
+        let id = u32::try_from(self.entries.len()).expect("too many symbols");
+        self.entries.push(entry);
+        self.ids.insert(key, id);
+        I::new(id)
+    }
+
+    /// Gets the identifier of the symbol with the given name.
+    pub fn get(&self, name: &str) -> Option<I> {
+        self.ids.get(fold(name).as_ref()).map(|&id| I::new(id))
+    }
+
+    /// Determines whether a symbol with the given name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Gets the entry of the symbol with the given name.
+    pub fn lookup(&self, name: &str) -> Option<&T> {
+        self.get(name).map(|id| &self[id])
+    }
+
+    /// Gets the number of symbols.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the table contains no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates the identifiers and entries of all symbols in order of their identifiers.
+    pub fn iter(&self) -> impl Iterator<Item = (I, &T)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (I::new(index as u32), entry))
+    }
+}
+
+impl<I: SymbolId, T> Index<I> for SymbolTable<I, T> {
+    type Output = T;
+
+    fn index(&self, id: I) -> &Self::Output {
+        &self.entries[id.index()]
+    }
+}
+
+/// Gets the key of a name in the lookup table, i.e. its lowercase spelling if
+/// the `case-folding` crate feature is enabled.
+fn fold(name: &str) -> Cow<'_, str> {
+    if cfg!(feature = "case-folding") && name.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        Cow::Owned(name.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(name)
+    }
+}
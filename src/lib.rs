@@ -7,6 +7,12 @@
 //! * `parser` - Enables parsing of PDDL types through the [`Parser`] trait.
 //! * `interning` - Enables string interning for [`Name`] types to reduce memory footprint.
 //!
+//! ## Optional crate features
+//!
+//! * `spans` - Records the source location of parsed domains, problems, structure definitions,
+//!   predicate definitions, init elements and goals; see the `Spanned` trait.
+//! * `case-folding` - Compares and hashes [`Name`] values without regard to case.
+//!
 //! ## Example
 //!
 //! The two core types of a PDDL are [`Domain`] and [`Problem`]. This example shows how to
@@ -72,21 +78,91 @@
 //! assert_eq!(problem.init().len(), 9);
 //! assert_eq!(problem.goals().len(), 3);
 //! ```
+//!
+//! ## Case sensitivity
+//!
+//! Like PDDL itself, the parser ignores the case of keywords such as `define`, `:action` or `and`.
+//! [`Name`] values keep their original spelling. By default they compare and hash exactly as
+//! spelled; with the `case-folding` crate feature enabled, they compare and hash without regard
+//! to case, so that e.g. `Depot` and `depot` name the same object.
+//!
+//! ## Printing
+//!
+//! All types implement [`Display`](std::fmt::Display) and print as valid PDDL. Parsing
+//! the printed output of a [`Domain`] or [`Problem`] yields an identical value:
+//!
+//! ```
+//! use pddl::{Parser, Problem};
+//!
+//! let problem = Problem::from_str(r#"(define (problem get-paid)
+//!     (:domain briefcase-world)
+//!     (:init (at B home) (at P home) (in P))
+//!     (:goal (and (at B office) (at P home))))"#).unwrap();
+//!
+//! let printed = problem.to_string();
+//! assert_eq!(Problem::from_str(&printed).unwrap(), problem);
+//! ```
+//!
+//! Where PDDL offers multiple ways of expressing the same construct, the parser settles on
+//! one of them. Values constructed by hand may therefore read back in their canonical form;
+//! for example, a [`GoalDefinition::Literal`] prints as `(not …)` and is read back as a
+//! [`GoalDefinition::Not`], and a [`PreferenceGD::Goal`] holding an `and` conjunction is
+//! read back as individual [`PreconditionGoalDefinition`] entries.
+//!
+//! ## Traversal
+//!
+//! The [`visit::Visit`] trait walks all nodes of a [`Domain`] or [`Problem`], calling a
+//! `visit_*` method per node type that implementations may override. The [`fold::Fold`]
+//! trait likewise consumes and rebuilds all nodes, allowing transformations to replace them.
+//!
+//! ## Indexing
+//!
+//! The [`index`] module resolves the symbols declared by a [`Domain`] and [`Problem`] into
+//! symbol tables with dense numeric identifiers, see [`index::DomainIndex`] and
+//! [`index::ProblemIndex`].
+//!
+//! ## Validation
+//!
+//! The [`validate`] module checks parsed values for semantic consistency, e.g. with
+//! [`validate::validate`] that a [`Problem`] only uses predicates declared by its [`Domain`].
+//! [`validate::validate_domain`] checks a [`Domain`] on its own, e.g. for unbound variables.
+//! [`validate::check_requirements`] compares the declared requirements with the ones actually used.
+//!
+//! ## Compilation
+//!
+//! The [`compile`] module rewrites domains and problems into equivalent ones that need
+//! fewer requirements, for planners that only support a subset of PDDL.
+//!
+//! ## Inheritance
+//!
+//! The [`resolve`] module merges a [`Domain`] with the domains named in its `:extends`
+//! section, see [`resolve::DomainRegistry`].
 
 // only enables the `doc_cfg` feature when
 // the `docsrs` configuration attribute is defined
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod compile;
+pub mod fold;
+pub mod format;
+pub mod index;
 #[cfg_attr(docsrs, doc(cfg(feature = "parser")))]
 #[cfg(feature = "parser")]
 pub mod parsers;
+pub mod resolve;
 mod types;
-pub(crate) mod visitor;
+pub mod validate;
+pub mod visit;
 
 // re-export Parser trait.
 #[cfg_attr(docsrs, doc(cfg(feature = "parser")))]
 #[cfg(feature = "parser")]
 pub use parsers::Parser;
 
+// re-export the owned parser error.
+#[cfg_attr(docsrs, doc(cfg(feature = "parser")))]
+#[cfg(feature = "parser")]
+pub use parsers::Error;
+
 // re-export types
 pub use types::*;
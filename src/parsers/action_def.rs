@@ -1,11 +1,12 @@
 //! Provides parsers for action definitions.
 
-use nom::bytes::complete::tag;
+use nom::bytes::complete::tag_no_case;
 use nom::character::complete::multispace1;
 use nom::combinator::{map, opt};
+use nom::error::context;
 use nom::sequence::{preceded, tuple};
 
-use crate::parsers::{empty_or, parens, prefix_expr, typed_list, ws, ParseResult, Span};
+use crate::parsers::{empty_or, parens, section_expr, spanned, typed_list, ws, ParseResult, Span};
 use crate::parsers::{parse_action_symbol, parse_effect, parse_pre_gd, parse_variable};
 use crate::types::ActionDefinition;
 
@@ -54,20 +55,21 @@ use crate::types::ActionDefinition;
 /// ```
 pub fn parse_action_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, ActionDefinition> {
     let precondition = preceded(
-        tag(":precondition"),
+        tag_no_case(":precondition"),
         preceded(multispace1, empty_or(parse_pre_gd)),
     );
     let effect = preceded(
-        tag(":effect"),
+        tag_no_case(":effect"),
         preceded(multispace1, empty_or(parse_effect)),
     );
     let action_def_body = tuple((opt(ws(precondition)), opt(ws(effect))));
     let parameters = preceded(
-        tag(":parameters"),
+        context(":parameters", tag_no_case(":parameters")),
         preceded(multispace1, parens(typed_list(parse_variable))),
     );
-    let action_def = prefix_expr(
+    let action_def = section_expr(
         ":action",
+        "action definition",
         tuple((
             parse_action_symbol,
             preceded(multispace1, parameters),
@@ -75,14 +77,17 @@ pub fn parse_action_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Acti
         )),
     );
 
-    map(action_def, |(symbol, params, (preconditions, effects))| {
-        ActionDefinition::new(
-            symbol,
-            params,
-            preconditions.flatten().into(),
-            effects.flatten(),
-        )
-    })(input.into())
+    spanned(map(
+        action_def,
+        |(symbol, params, (preconditions, effects))| {
+            ActionDefinition::new(
+                symbol,
+                params,
+                preconditions.flatten().into(),
+                effects.flatten(),
+            )
+        },
+    ))(input.into())
 }
 
 impl crate::parsers::Parser for ActionDefinition {
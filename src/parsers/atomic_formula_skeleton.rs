@@ -1,6 +1,6 @@
 //! Provides parsers for atomic formula skeletons.
 
-use crate::parsers::{parens, typed_list, ws, ParseResult, Span};
+use crate::parsers::{parens, spanned, typed_list, ws, ParseResult, Span};
 use crate::parsers::{parse_predicate, parse_variable};
 use crate::types::AtomicFormulaSkeleton;
 use nom::combinator::map;
@@ -25,10 +25,10 @@ use nom::sequence::tuple;
 pub fn parse_atomic_formula_skeleton<'a, T: Into<Span<'a>>>(
     input: T,
 ) -> ParseResult<'a, AtomicFormulaSkeleton> {
-    map(
+    spanned(map(
         parens(tuple((parse_predicate, ws(typed_list(parse_variable))))),
         AtomicFormulaSkeleton::from,
-    )(input.into())
+    ))(input.into())
 }
 
 impl crate::parsers::Parser for AtomicFormulaSkeleton {
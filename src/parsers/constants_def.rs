@@ -2,7 +2,7 @@
 
 use nom::combinator::map;
 
-use crate::parsers::{parse_name, prefix_expr, typed_list, ParseResult, Span};
+use crate::parsers::{parse_name, section_expr, typed_list, ParseResult, Span};
 use crate::types::Constants;
 
 /// Parses constant definitions, i.e. `(:constants <typed list (name)>)`.
@@ -21,9 +21,10 @@ use crate::types::Constants;
 /// ));
 /// ```
 pub fn parse_constants_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Constants> {
-    map(prefix_expr(":constants", typed_list(parse_name)), |vec| {
-        Constants::new(vec)
-    })(input.into())
+    map(
+        section_expr(":constants", "constants definition", typed_list(parse_name)),
+        Constants::new,
+    )(input.into())
 }
 
 impl crate::parsers::Parser for Constants {
@@ -1,13 +1,14 @@
 //! Provides parsers for durative action definitions.
 
-use crate::parsers::{empty_or, parens, prefix_expr, typed_list, ParseResult, Span};
+use crate::parsers::{empty_or, parens, section_expr, spanned, typed_list, ParseResult, Span};
 use crate::parsers::{
     parse_da_effect, parse_da_gd, parse_da_symbol, parse_duration_constraint, parse_variable,
 };
 use crate::types::DurativeActionDefinition;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::tag_no_case;
 use nom::character::complete::multispace1;
 use nom::combinator::map;
+use nom::error::context;
 use nom::sequence::{preceded, tuple};
 
 /// Parses a durative action definition.
@@ -54,25 +55,26 @@ use nom::sequence::{preceded, tuple};
 /// ```
 pub fn parse_da_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, DurativeActionDefinition> {
     let parameters = preceded(
-        tag(":parameters"),
+        context(":parameters", tag_no_case(":parameters")),
         preceded(multispace1, parens(typed_list(parse_variable))),
     );
 
     let duration = preceded(
-        tag(":duration"),
+        context(":duration", tag_no_case(":duration")),
         preceded(multispace1, parse_duration_constraint),
     );
     let condition = preceded(
-        tag(":condition"),
+        context(":condition", tag_no_case(":condition")),
         preceded(multispace1, empty_or(parse_da_gd)),
     );
     let effect = preceded(
-        tag(":effect"),
+        context(":effect", tag_no_case(":effect")),
         preceded(multispace1, empty_or(parse_da_effect)),
     );
 
-    let da_def = prefix_expr(
+    let da_def = section_expr(
         ":durative-action",
+        "durative action definition",
         tuple((
             parse_da_symbol,
             preceded(multispace1, parameters),
@@ -83,12 +85,12 @@ pub fn parse_da_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Durative
         )),
     );
 
-    map(
+    spanned(map(
         da_def,
         |(symbol, parameters, duration, condition, effect)| {
             DurativeActionDefinition::new(symbol, parameters, duration, condition, effect)
         },
-    )(input.into())
+    ))(input.into())
 }
 
 impl crate::parsers::Parser for DurativeActionDefinition {
@@ -4,8 +4,8 @@ use nom::character::complete::multispace1;
 use nom::combinator::map;
 use nom::sequence::{preceded, tuple};
 
-use crate::parsers::{parse_atomic_formula_skeleton, parse_gd};
-use crate::parsers::{prefix_expr, ParseResult, Span};
+use crate::parsers::{parse_atomic_formula_skeleton, parse_gd, spanned};
+use crate::parsers::{section_expr, ParseResult, Span};
 use crate::types::DerivedPredicate;
 
 /// Parses a derived predicate, i.e. `(:derived <atomic formula skeleton> <GD>)`.
@@ -28,16 +28,17 @@ use crate::types::DerivedPredicate;
 pub fn parse_derived_predicate<'a, T: Into<Span<'a>>>(
     input: T,
 ) -> ParseResult<'a, DerivedPredicate> {
-    map(
-        prefix_expr(
+    spanned(map(
+        section_expr(
             ":derived",
+            "derived predicate definition",
             tuple((
                 parse_atomic_formula_skeleton,
                 preceded(multispace1, parse_gd),
             )),
         ),
         DerivedPredicate::from,
-    )(input.into())
+    ))(input.into())
 }
 
 impl crate::parsers::Parser for DerivedPredicate {
@@ -1,21 +1,27 @@
 //! Provides parsers for domain definitions.
 
-use nom::character::complete::multispace1;
-use nom::combinator::{map, opt};
-use nom::sequence::{preceded, tuple};
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::error::context;
+use nom::sequence::tuple;
 
 use crate::parsers::{
     parse_constants_def, parse_domain_constraints_def, parse_functions_def, parse_predicates_def,
-    parse_require_def, parse_structure_def, ParseResult, Span,
+    parse_require_def, parse_structure_def, parse_timeless_def, ParseResult, Span,
 };
-use crate::parsers::{parse_name, parse_types_def, prefix_expr, space_separated_list1, ws2};
+use crate::parsers::{parse_name, parse_types_def, prefix_expr, section_expr, spanned};
+use crate::parsers::{space_separated_list1, unordered_sections, ws2};
 use crate::types::{
-    Constants, Domain, Functions, PredicateDefinitions, Requirements, StructureDefs,
+    Constants, Domain, Functions, Name, PredicateDefinitions, Requirements, StructureDef,
+    StructureDefs, Timeless,
 };
 use crate::types::{DomainConstraintsDef, Types};
 
 /// Parses a domain definition.
 ///
+/// The sections of the domain may appear in any order. Apart from action, durative action
+/// and derived predicate definitions, each section may appear at most once.
+///
 /// ## Example
 /// ```
 /// # use pddl::parsers::{parse_action_def, parse_domain, preamble::*};
@@ -60,56 +66,100 @@ use crate::types::{DomainConstraintsDef, Types};
 /// assert_eq!(domain.structure().len(), 3);
 /// ```
 pub fn parse_domain<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Domain> {
-    map(
-        ws2(prefix_expr(
-            "define",
-            tuple((
-                prefix_expr("domain", parse_name),
-                opt(preceded(
-                    multispace1,
-                    prefix_expr(":extends", space_separated_list1(parse_name)),
-                )),
-                opt(preceded(multispace1, parse_require_def)),
-                // :typing
-                opt(preceded(multispace1, parse_types_def)),
-                opt(preceded(multispace1, parse_constants_def)),
-                opt(preceded(multispace1, parse_predicates_def)),
-                // :fluents
-                opt(preceded(multispace1, parse_functions_def)),
-                // :constraints
-                opt(preceded(multispace1, parse_domain_constraints_def)),
-                opt(preceded(
-                    multispace1,
-                    map(
-                        space_separated_list1(parse_structure_def),
-                        StructureDefs::new,
-                    ),
+    context(
+        "domain definition",
+        ws2(spanned(map(
+            prefix_expr(
+                "define",
+                tuple((
+                    prefix_expr("domain", parse_name),
+                    unordered_sections(parse_domain_section, |section| {
+                        matches!(section, DomainSection::Structure(_))
+                    }),
                 )),
-            )),
-        )),
-        |(
-            name,
-            extends,
-            require,
-            types,
-            constants,
-            predicates,
-            functions,
-            constraints,
-            structure,
-        )| {
-            Domain::builder(name, structure.unwrap_or(StructureDefs::default()))
-                .with_extends(extends.unwrap_or(Vec::default()))
-                .with_requirements(require.unwrap_or(Requirements::default()))
-                .with_types(types.unwrap_or(Types::default()))
-                .with_constants(constants.unwrap_or(Constants::default()))
-                .with_predicates(predicates.unwrap_or(PredicateDefinitions::default()))
-                .with_functions(functions.unwrap_or(Functions::default()))
-                .with_constraints(constraints.unwrap_or(DomainConstraintsDef::default()))
-        },
+            ),
+            |(name, sections)| build_domain(name, sections),
+        ))),
     )(input.into())
 }
 
+/// A top-level section of a domain definition.
+enum DomainSection {
+    Extends(Vec<Name>),
+    Requirements(Requirements),
+    Types(Types),
+    Constants(Constants),
+    Predicates(PredicateDefinitions),
+    Timeless(Timeless),
+    Functions(Functions),
+    Constraints(DomainConstraintsDef),
+    Structure(Box<StructureDef>),
+}
+
+/// Parses any one of the sections of a domain definition.
+fn parse_domain_section(input: Span) -> ParseResult<DomainSection> {
+    alt((
+        map(
+            section_expr(
+                ":extends",
+                "extends definition",
+                space_separated_list1(parse_name),
+            ),
+            DomainSection::Extends,
+        ),
+        map(parse_require_def, DomainSection::Requirements),
+        // :typing
+        map(parse_types_def, DomainSection::Types),
+        map(parse_constants_def, DomainSection::Constants),
+        map(parse_predicates_def, DomainSection::Predicates),
+        map(parse_timeless_def, DomainSection::Timeless),
+        // :fluents
+        map(parse_functions_def, DomainSection::Functions),
+        // :constraints
+        map(parse_domain_constraints_def, DomainSection::Constraints),
+        map(parse_structure_def, |def| {
+            DomainSection::Structure(Box::new(def))
+        }),
+    ))(input)
+}
+
+/// Assembles a domain from its sections, given in any order.
+fn build_domain(name: Name, sections: Vec<DomainSection>) -> Domain {
+    let mut extends = Vec::default();
+    let mut requirements = Requirements::default();
+    let mut types = Types::default();
+    let mut constants = Constants::default();
+    let mut predicates = PredicateDefinitions::default();
+    let mut timeless = Timeless::default();
+    let mut functions = Functions::default();
+    let mut constraints = DomainConstraintsDef::default();
+    let mut structure = Vec::new();
+
+    for section in sections {
+        match section {
+            DomainSection::Extends(value) => extends = value,
+            DomainSection::Requirements(value) => requirements = value,
+            DomainSection::Types(value) => types = value,
+            DomainSection::Constants(value) => constants = value,
+            DomainSection::Predicates(value) => predicates = value,
+            DomainSection::Timeless(value) => timeless = value,
+            DomainSection::Functions(value) => functions = value,
+            DomainSection::Constraints(value) => constraints = value,
+            DomainSection::Structure(value) => structure.push(*value),
+        }
+    }
+
+    Domain::builder(name, StructureDefs::new(structure))
+        .with_extends(extends)
+        .with_requirements(requirements)
+        .with_types(types)
+        .with_constants(constants)
+        .with_predicates(predicates)
+        .with_functions(functions)
+        .with_constraints(constraints)
+        .with_timeless(timeless)
+}
+
 impl crate::parsers::Parser for Domain {
     type Item = Domain;
 
@@ -220,4 +270,73 @@ mod tests {
         assert!(domain.constraints().is_empty());
         assert_eq!(domain.structure().len(), 3);
     }
+
+    #[test]
+    fn test_parse_timeless() {
+        let input = r#"(define (domain world)
+              (:predicates (connected ?x ?y))
+              (:timeless (connected a b) (not (connected b a))))"#;
+
+        let (_, domain) = Domain::parse(input).unwrap();
+
+        assert_eq!(domain.predicates().len(), 1);
+        assert_eq!(domain.timeless().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_sections_in_any_order() {
+        let input = r#"(define (domain world)
+              (:action go :parameters (?x) :effect (at ?x))
+              (:constants home - location)
+              (:functions (distance ?x ?y))
+              (:predicates (at ?x))
+              (:types location)
+              (:requirements :typing))"#;
+
+        let domain = Domain::from_str(input).unwrap();
+
+        assert_eq!(domain.requirements().len(), 1);
+        assert_eq!(domain.types().len(), 1);
+        assert_eq!(domain.constants().len(), 1);
+        assert_eq!(domain.predicates().len(), 1);
+        assert_eq!(domain.functions().len(), 1);
+        assert_eq!(domain.structure().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_ignores_case() {
+        let input = r#"(DEFINE (DOMAIN Logistics)
+              (:Requirements :STRIPS :Typing)
+              (:TYPES Truck Location)
+              (:PREDICATES (AT ?t - Truck ?l - Location))
+              (:ACTION Drive
+                   :PARAMETERS (?t - Truck ?from ?to - Location)
+                   :PRECONDITION (AND (AT ?t ?from) (NOT (= ?from ?to)))
+                   :EFFECT (AND (AT ?t ?to) (NOT (AT ?t ?from)))))"#;
+
+        let domain = Domain::from_str(input).unwrap();
+        let lowercase = Domain::from_str(&input.to_lowercase()).unwrap();
+
+        assert_eq!(domain.to_string().to_lowercase(), lowercase.to_string());
+        #[cfg(feature = "case-folding")]
+        assert_eq!(domain, lowercase);
+        assert_eq!(domain.requirements().len(), 2);
+        assert!(domain.to_string().starts_with("(define (domain Logistics)"));
+    }
+
+    #[test]
+    fn test_parse_duplicate_section() {
+        let input = r#"(define (domain world)
+              (:types location)
+              (:predicates (at ?x))
+              (:types vehicle))"#;
+
+        let error = Domain::from_str(input).unwrap_err();
+
+        assert_eq!((error.line(), error.column()), (4, 16));
+        assert_eq!(
+            error.message(),
+            "duplicate section `:types` inside `(define ...)`"
+        );
+    }
 }
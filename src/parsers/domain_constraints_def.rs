@@ -2,7 +2,7 @@
 
 use nom::combinator::map;
 
-use crate::parsers::{parse_con_gd, prefix_expr, ParseResult, Span};
+use crate::parsers::{parse_con_gd, section_expr, ParseResult, Span};
 use crate::types::DomainConstraintsDef;
 
 /// Parses domain constraint definitions, i.e. `(:constraints <con-gd>)`.
@@ -22,7 +22,7 @@ pub fn parse_domain_constraints_def<'a, T: Into<Span<'a>>>(
     input: T,
 ) -> ParseResult<'a, DomainConstraintsDef> {
     map(
-        prefix_expr(":constraints", parse_con_gd),
+        section_expr(":constraints", "constraints definition", parse_con_gd),
         DomainConstraintsDef::new,
     )(input.into())
 }
@@ -0,0 +1,347 @@
+//! Contains the owned parser error via the [`Error`] type.
+
+use crate::parsers::{ParseError, Span};
+use nom::Slice;
+use nom_greedyerror::GreedyErrorKind;
+use std::fmt::{Display, Formatter};
+
+/// An error returned by [`Parser::from_str`](crate::Parser::from_str).
+///
+/// Unlike [`ParseError`], this type does not borrow the input. It records where parsing
+/// failed, which tokens were expected at that position and which PDDL construct was
+/// being parsed.
+///
+/// ## Example
+/// ```
+/// use pddl::{Domain, Parser};
+///
+/// let input = r#"(define (domain briefcase-world)
+///     (:action move :precondition (and)))"#;
+///
+/// let error = Domain::from_str(input).unwrap_err();
+/// assert_eq!(error.line(), 2);
+/// assert_eq!(error.column(), 19);
+/// assert_eq!(error.construct(), Some("action definition"));
+/// assert_eq!(
+///     error.message(),
+///     "expected `:parameters`, found `:precondition` inside `(:action move ...)`"
+/// );
+///
+/// let error = error.with_file("domain.pddl");
+/// assert_eq!(error.render(), r#"error: expected `:parameters`, found `:precondition` inside `(:action move ...)`
+///  --> domain.pddl:2:19
+///   |
+/// 2 |     (:action move :precondition (and)))
+///   |                   ^
+/// "#);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error(Box<Details>);
+
+/// The details of an [`Error`], boxed to keep results small.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Details {
+    file: Option<String>,
+    line: u32,
+    column: usize,
+    offset: usize,
+    expected: Vec<String>,
+    found: Option<String>,
+    construct: Option<&'static str>,
+    excerpt: Option<String>,
+    source_line: String,
+    duplicate: bool,
+}
+
+/// The context marking a section that may appear only once in a definition,
+/// e.g. a second `(:types ...)` section in a domain.
+pub(crate) const DUPLICATE_SECTION: &str = "duplicate section";
+
+impl Error {
+    /// Creates an owned error from the result of parsing the specified `input`.
+    pub fn new(input: &str, error: nom::Err<ParseError<'_>>) -> Self {
+        let error = match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => e,
+            nom::Err::Incomplete(_) => ParseError { errors: Vec::new() },
+        };
+
+        let end_of_input = Span::new(input).slice(input.len()..);
+        let position = error.errors.first().map_or(end_of_input, |(span, _)| *span);
+
+        let mut expected = Vec::new();
+        let mut construct = None;
+        let mut duplicate = false;
+        for (span, kind) in &error.errors {
+            let token = match kind {
+                GreedyErrorKind::Char(c) => Some(c.to_string()),
+                GreedyErrorKind::Context(DUPLICATE_SECTION) => {
+                    duplicate = true;
+                    None
+                }
+                GreedyErrorKind::Context(context) if is_construct(context) => {
+                    construct = construct.or(Some((*context, *span)));
+                    None
+                }
+                GreedyErrorKind::Context(token) => Some(token.to_string()),
+                GreedyErrorKind::Nom(_) => None,
+            };
+
+            if let Some(token) = token {
+                if span.location_offset() == position.location_offset()
+                    && !expected.contains(&token)
+                {
+                    expected.push(token);
+                }
+            }
+        }
+
+        let source_line = String::from_utf8_lossy(position.get_line_beginning());
+
+        Self(Box::new(Details {
+            file: None,
+            line: position.location_line(),
+            column: position.get_utf8_column(),
+            offset: position.location_offset(),
+            expected,
+            found: next_token(position.fragment()),
+            construct: construct.map(|(name, _)| name),
+            excerpt: construct.and_then(|(_, span)| excerpt(span.fragment())),
+            source_line: source_line.trim_end().to_string(),
+            duplicate,
+        }))
+    }
+
+    /// Sets the name of the file the input was read from.
+    pub fn with_file<S: Into<String>>(mut self, file: S) -> Self {
+        self.0.file = Some(file.into());
+        self
+    }
+
+    /// Gets the name of the file the input was read from, if known.
+    pub fn file(&self) -> Option<&str> {
+        self.0.file.as_deref()
+    }
+
+    /// Gets the one-based line number of the error position.
+    pub fn line(&self) -> u32 {
+        self.0.line
+    }
+
+    /// Gets the one-based column of the error position, counted in characters.
+    pub fn column(&self) -> usize {
+        self.0.column
+    }
+
+    /// Gets the byte offset of the error position.
+    pub fn offset(&self) -> usize {
+        self.0.offset
+    }
+
+    /// Gets the tokens that were expected at the error position, e.g. `:parameters`.
+    pub fn expected(&self) -> &[String] {
+        &self.0.expected
+    }
+
+    /// Gets the token found at the error position, or `None` at the end of the input.
+    pub fn found(&self) -> Option<&str> {
+        self.0.found.as_deref()
+    }
+
+    /// Gets the PDDL construct that was being parsed, e.g. `action definition`.
+    pub fn construct(&self) -> Option<&'static str> {
+        self.0.construct
+    }
+
+    /// Gets a human-readable description of the error, without its position.
+    pub fn message(&self) -> String {
+        let found = match &self.0.found {
+            Some(token) => format!("`{token}`"),
+            None => "end of input".to_string(),
+        };
+
+        let mut message = match self.0.expected.split_last() {
+            _ if self.0.duplicate => format!("duplicate section {found}"),
+            None => format!("unexpected {found}"),
+            Some((last, [])) => format!("expected `{last}`, found {found}"),
+            Some((last, rest)) => {
+                let rest: Vec<_> = rest.iter().map(|token| format!("`{token}`")).collect();
+                format!("expected {} or `{last}`, found {found}", rest.join(", "))
+            }
+        };
+
+        match (&self.0.excerpt, self.0.construct) {
+            (Some(excerpt), _) => message.push_str(&format!(" inside `{excerpt}`")),
+            (None, Some(construct)) => message.push_str(&format!(" in {construct}")),
+            (None, None) => {}
+        }
+        message
+    }
+
+    /// Renders the error along with the affected source line, marking the
+    /// error position with a caret.
+    pub fn render(&self) -> String {
+        let line = self.0.line.to_string();
+        let gutter = " ".repeat(line.len());
+        let indent: String = self
+            .0
+            .source_line
+            .chars()
+            .take(self.0.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        format!(
+            "error: {message}\n{gutter}--> {file}:{line}:{column}\n{gutter} |\n{line} | {source}\n{gutter} | {indent}^\n",
+            message = self.message(),
+            file = self.0.file.as_deref().unwrap_or("<input>"),
+            column = self.0.column,
+            source = self.0.source_line,
+        )
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0.file {
+            Some(file) => write!(
+                f,
+                "{file}:{}:{}: {}",
+                self.0.line,
+                self.0.column,
+                self.message()
+            ),
+            None => write!(
+                f,
+                "{} at line {}, column {}",
+                self.message(),
+                self.0.line,
+                self.0.column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Constructs whose excerpt includes the name following the keyword.
+const NAMED_CONSTRUCTS: &[&str] = &[":action", ":durative-action"];
+
+/// Determines whether a context names a PDDL construct, e.g. `action definition`,
+/// rather than an expected token such as `:parameters`.
+fn is_construct(context: &str) -> bool {
+    context.starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+/// Returns the token at the start of the input, or `None` if only whitespace remains.
+fn next_token(input: &str) -> Option<String> {
+    let input = input.trim_start();
+    let token = match input.chars().next()? {
+        c @ ('(' | ')') => c.to_string(),
+        _ => input
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    };
+    Some(token)
+}
+
+/// Returns the opening of the parenthesized expression at the start of the input,
+/// e.g. `(:types ...)`, including the name of named constructs such as `(:action move ...)`.
+fn excerpt(input: &str) -> Option<String> {
+    let mut input = input.trim_start();
+    while let Some(comment) = input.strip_prefix(';') {
+        input = comment
+            .split_once('\n')
+            .map_or("", |(_, rest)| rest)
+            .trim_start();
+    }
+
+    let inner = input.strip_prefix('(')?;
+    let head = next_token(inner).filter(|head| head != "(" && head != ")")?;
+    let rest = inner.trim_start()[head.len()..].trim_start();
+    let named = NAMED_CONSTRUCTS
+        .iter()
+        .any(|construct| construct.eq_ignore_ascii_case(&head));
+    match next_token(rest).filter(|_| named) {
+        Some(name) => Some(format!("({head} {name} ...)")),
+        None => Some(format!("({head} ...)")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::parse_domain;
+    use crate::{Domain, Parser, Problem};
+
+    #[test]
+    fn error_reports_expected_section_close() {
+        let input = "(define (domain d)\n  (:requirements :strips :wrong))";
+        let error = Domain::from_str(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 26));
+        assert_eq!(error.offset(), 44);
+        assert_eq!(error.expected(), [")"]);
+        assert_eq!(error.found(), Some(":wrong"));
+        assert_eq!(error.construct(), Some("requirements definition"));
+        assert_eq!(
+            error.to_string(),
+            "expected `)`, found `:wrong` inside `(:requirements ...)` at line 2, column 26"
+        );
+    }
+
+    #[test]
+    fn error_reports_end_of_input() {
+        let input = "(define (problem p) (:domain d)";
+        let error = Problem::from_str(input).unwrap_err();
+        assert_eq!(error.found(), None);
+        assert!(error.message().starts_with("unexpected end of input"));
+    }
+
+    #[test]
+    fn error_reports_trailing_input() {
+        let input = "(define (problem p) (:domain d) (:init) (:goal (and)))\n; done\n(junk";
+        let error = Problem::from_str(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(error.to_string(), "unexpected `(` at line 3, column 1");
+
+        // A stray parenthesis must not silently truncate the domain.
+        let input = "(define (domain d) (:predicates (p)))\n  (:action a :parameters ()))";
+        let error = Domain::from_str(input).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 3));
+        assert!(Domain::from_str("(define (domain d)) ; done\n").is_ok());
+    }
+
+    #[test]
+    fn error_is_owned() {
+        let error = {
+            let input = String::from("(define (domain d) (:types a - ))");
+            Error::new(&input, parse_domain(input.as_str()).unwrap_err())
+        };
+        assert_eq!(error.construct(), Some("types definition"));
+    }
+
+    #[test]
+    fn render_keeps_tabs() {
+        let input = "(define (domain d)\n\t(:action a :parameters (?x) :precondition (and) x))";
+        let error = Domain::from_str(input).unwrap_err().with_file("d.pddl");
+        assert_eq!(
+            error.to_string(),
+            format!("d.pddl:2:50: {}", error.message())
+        );
+        assert!(error.render().ends_with(&format!(
+            "2 | \t(:action a :parameters (?x) :precondition (and) x))\n  | \t{}^\n",
+            " ".repeat(48)
+        )));
+    }
+
+    #[test]
+    fn excerpt_skips_comments() {
+        assert_eq!(
+            excerpt("  ; comment\n (:action move\n :parameters ()"),
+            Some("(:action move ...)".to_string())
+        );
+        assert_eq!(excerpt("(:types a b)"), Some("(:types ...)".to_string()));
+        assert_eq!(excerpt("((a))"), None);
+    }
+}
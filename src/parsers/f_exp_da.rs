@@ -1,7 +1,7 @@
 //! Provides parsers for f-exp-da values..
 
 use nom::branch::alt;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::tag_no_case;
 use nom::character::complete::{char, multispace0, multispace1};
 use nom::combinator::map;
 use nom::sequence::{preceded, tuple};
@@ -38,7 +38,7 @@ use crate::types::FExpDa;
 ///```
 pub fn parse_f_exp_da<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, FExpDa> {
     // :duration-inequalities
-    let duration = map(tag("?duration"), |_| FExpDa::new_duration());
+    let duration = map(tag_no_case("?duration"), |_| FExpDa::new_duration());
 
     let binary_op = map(
         parens(tuple((
@@ -1,7 +1,7 @@
 //! Provides parsers for f-exps.
 
 use nom::branch::alt;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::tag_no_case;
 use nom::character::complete::multispace1;
 use nom::combinator::map;
 use nom::sequence::{preceded, terminated, tuple};
@@ -41,13 +41,13 @@ use crate::types::FExpT;
 /// ));
 ///```
 pub fn parse_f_exp_t<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, FExpT> {
-    let now = map(tag("#t"), |_| FExpT::new());
+    let now = map(tag_no_case("#t"), |_| FExpT::new());
     let scaled = map(
         prefix_expr(
             "*",
             alt((
-                preceded(tuple((tag("#t"), multispace1)), parse_f_exp),
-                terminated(parse_f_exp, tuple((multispace1, tag("#t")))),
+                preceded(tuple((tag_no_case("#t"), multispace1)), parse_f_exp),
+                terminated(parse_f_exp, tuple((multispace1, tag_no_case("#t")))),
             )),
         ),
         FExpT::new_scaled,
@@ -3,7 +3,7 @@
 use nom::combinator::map;
 
 use crate::parsers::{function_typed_list, parse_atomic_function_skeleton};
-use crate::parsers::{prefix_expr, ParseResult, Span};
+use crate::parsers::{section_expr, ParseResult, Span};
 use crate::types::Functions;
 
 /// Parses constant definitions, i.e. `(:constants <typed list (name)>)`.
@@ -29,8 +29,9 @@ use crate::types::Functions;
 /// ```
 pub fn parse_functions_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Functions> {
     map(
-        prefix_expr(
+        section_expr(
             ":functions",
+            "functions definition",
             function_typed_list(parse_atomic_function_skeleton),
         ),
         Functions::new,
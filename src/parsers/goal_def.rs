@@ -1,6 +1,6 @@
 //! Provides parsers for pre-GD goal definitions.
 
-use crate::parsers::{parse_pre_gd, prefix_expr, ParseResult, Span};
+use crate::parsers::{parse_pre_gd, section_expr, spanned, ParseResult, Span};
 use crate::types::GoalDef;
 use nom::combinator::map;
 
@@ -27,7 +27,10 @@ use nom::combinator::map;
 /// ));
 /// ```
 pub fn parse_problem_goal_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, GoalDef> {
-    map(prefix_expr(":goal", parse_pre_gd), GoalDef::new)(input.into())
+    spanned(map(
+        section_expr(":goal", "goal definition", parse_pre_gd),
+        GoalDef::new,
+    ))(input.into())
 }
 
 impl crate::parsers::Parser for GoalDef {
@@ -2,7 +2,9 @@
 
 use nom::combinator::map;
 
-use crate::parsers::{parse_init_el, prefix_expr, space_separated_list0, ParseResult, Span};
+#[cfg(feature = "spans")]
+use crate::parsers::with_source_span;
+use crate::parsers::{parse_init_el, section_expr, space_separated_list0, ParseResult, Span};
 use crate::types::InitElements;
 
 /// Parser for goal initial state definitions.
@@ -35,11 +37,22 @@ use crate::types::InitElements;
 /// ));
 /// ```
 pub fn parse_problem_init_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, InitElements> {
-    map(
-        prefix_expr(":init", space_separated_list0(parse_init_el)),
-        InitElements::new,
-    )(input.into())
+    #[cfg(not(feature = "spans"))]
+    let elements = map(space_separated_list0(parse_init_el), InitElements::new);
+
+    // Records the source location of each element, see `InitElements::span_of`.
+    #[cfg(feature = "spans")]
+    let elements = map(
+        space_separated_list0(with_source_span(parse_init_el)),
+        |elements| {
+            let (elements, spans): (Vec<_>, Vec<_>) = elements.into_iter().unzip();
+            InitElements::new(elements).with_spans(spans)
+        },
+    );
+
+    section_expr(":init", "init definition", elements)(input.into())
 }
+
 impl crate::parsers::Parser for InitElements {
     type Item = InitElements;
 
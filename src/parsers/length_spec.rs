@@ -4,7 +4,7 @@ use nom::character::complete::multispace0;
 use nom::combinator::{map, opt};
 use nom::sequence::{preceded, tuple};
 
-use crate::parsers::{prefix_expr, ParseResult, Span};
+use crate::parsers::{prefix_expr, section_expr, ParseResult, Span};
 use crate::types::LengthSpec;
 
 /// Parses a length specification. Deprecated since PDDL 2.1.
@@ -21,8 +21,9 @@ use crate::types::LengthSpec;
 pub fn parse_problem_length_spec<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, LengthSpec> {
     let serial = prefix_expr(":serial", nom::character::complete::u64);
     let parallel = prefix_expr(":parallel", nom::character::complete::u64);
-    let length = prefix_expr(
+    let length = section_expr(
         ":length",
+        "length specification",
         tuple((opt(serial), opt(preceded(multispace0, parallel)))),
     );
 
@@ -4,7 +4,7 @@ use nom::character::complete::multispace1;
 use nom::combinator::map;
 use nom::sequence::{preceded, tuple};
 
-use crate::parsers::{parse_metric_f_exp, parse_optimization, prefix_expr, ParseResult, Span};
+use crate::parsers::{parse_metric_f_exp, parse_optimization, section_expr, ParseResult, Span};
 use crate::types::MetricSpec;
 
 /// Parses a metric specification.
@@ -23,8 +23,9 @@ use crate::types::MetricSpec;
 pub fn parse_problem_metric_spec<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, MetricSpec> {
     // :numeric-fluents
     map(
-        prefix_expr(
+        section_expr(
             ":metric",
+            "metric specification",
             tuple((
                 parse_optimization,
                 preceded(multispace1, parse_metric_f_exp),
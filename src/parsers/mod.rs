@@ -27,6 +27,7 @@ mod domain_constraints_def;
 mod duration_constraint;
 mod effects;
 mod empty_or;
+mod error;
 mod f_assign_da;
 mod f_comp;
 mod f_exp;
@@ -63,6 +64,7 @@ mod pref_timed_gd;
 mod primitive_type;
 mod problem;
 mod problem_constraints_def;
+mod recovery;
 mod requirements;
 mod simple_duration_constraint;
 mod structure_def;
@@ -82,6 +84,8 @@ mod variable;
 pub(crate) use test_helpers::Match;
 pub use test_helpers::UnwrapValue;
 
+use nom::combinator::all_consuming;
+
 /// Provides the [`Parser::parse`] and [`Parser::from_str`] methods.
 pub trait Parser {
     type Item;
@@ -94,14 +98,33 @@ pub trait Parser {
         Self::parse(input)
     }
 
-    /// Uses the [`Parser::parse`] method to parse the input and, if successful,
-    /// discards the unparsed remaining input.
-    fn from_str(input: &str) -> Result<Self::Item, nom::Err<ParseError>> {
-        let (_, value) = Self::parse(input)?;
-        Ok(value)
+    /// Uses the [`Parser::parse`] method to parse the entire input.
+    ///
+    /// Only whitespace and comments may follow the parsed value; any other remaining
+    /// input is reported as an error at its position. Use [`Parser::parse`] to obtain
+    /// the unparsed remainder instead.
+    ///
+    /// Errors are converted into an owned [`Error`] describing the position and cause.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{Name, Parser};
+    /// assert_eq!(Name::from_str("  name ; comment\n").unwrap(), "name");
+    ///
+    /// let error = Name::from_str("name (junk").unwrap_err();
+    /// assert_eq!(error.column(), 6);
+    /// assert_eq!(error.message(), "unexpected `(`");
+    /// ```
+    fn from_str(input: &str) -> Result<Self::Item, Error> {
+        match all_consuming(ws2(Self::parse_span))(Span::new(input)) {
+            Ok((_, value)) => Ok(value),
+            Err(e) => Err(Error::new(input, e)),
+        }
     }
 }
 
+pub use error::Error;
+
 /// Input type for parsers.
 pub type Span<'a> = nom_locate::LocatedSpan<&'a str>;
 
@@ -115,7 +138,7 @@ pub type ParseResult<'a, T, E = ParseError<'a>> = nom::IResult<Span<'a>, T, E>;
 pub mod preamble {
     pub use crate::parsers::test_helpers::UnwrapValue;
     pub use crate::parsers::Parser;
-    pub use crate::parsers::{ParseError, ParseResult, Span};
+    pub use crate::parsers::{Error, ParseError, ParseResult, Span};
 }
 
 // Parsers.
@@ -179,6 +202,7 @@ pub use primitive_type::parse_primitive_type;
 pub use problem::parse_problem;
 pub use problem_constraints_def::parse_problem_constraints_def;
 pub use r#type::parse_type;
+pub use recovery::{parse_domain_with_recovery, parse_problem_with_recovery, Recovered};
 pub use requirements::{parse_require_def, parse_require_key};
 pub use simple_duration_constraint::parse_simple_duration_constraint;
 pub use structure_def::parse_structure_def;
@@ -198,9 +222,12 @@ pub use literal::literal;
 pub use typed_list::typed_list;
 
 // Utility parser combinators.
+#[cfg(feature = "spans")]
+pub(crate) use utilities::with_source_span;
 #[allow(unused_imports)]
 pub(crate) use utilities::{
-    parens, prefix_expr, space_separated_list0, space_separated_list1, ws, ws2,
+    parens, prefix_expr, section_expr, space_separated_list0, space_separated_list1, spanned,
+    unordered_sections, ws, ws2,
 };
 
 #[cfg(test)]
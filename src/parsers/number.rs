@@ -1,7 +1,7 @@
 //! Provides parsers for numbers, decimals and digits.
 
 use nom::character::complete::{char, digit1};
-use nom::combinator::{map, recognize};
+use nom::combinator::{map, opt, recognize};
 use nom::multi::many_m_n;
 use nom::number::complete::float;
 use nom::sequence::tuple;
@@ -10,7 +10,10 @@ use nom::Parser;
 use crate::parsers::{ParseResult, Span};
 use crate::types::Number;
 
-/// Parses a number, i.e. `<digit>⁺[<decimal>]`.
+/// Parses a number, i.e. `[-]<digit>⁺[<decimal>]`.
+///
+/// The PDDL grammar only defines non-negative numbers; the optional leading minus sign
+/// is accepted so that every [`Number`] prints as PDDL that parses back to the same value.
 ///
 /// ## Example
 /// ```
@@ -22,10 +25,18 @@ use crate::types::Number;
 ///
 /// assert!(parse_number(".0").is_err());
 /// assert!(parse_number(".").is_err());
-/// assert!(parse_number("-1").is_err());
+/// assert!(parse_number("-1").is_value((-1.0).into()));
+/// assert!(parse_number("-0.5").is_value((-0.5).into()));
+///
+/// assert!(parse_number("- 1").is_err());
+/// assert!(parse_number("-.5").is_err());
 ///```
 pub fn parse_number<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Number> {
-    let pattern = recognize(tuple((digit1, many_m_n(0, 1, parse_decimal))));
+    let pattern = recognize(tuple((
+        opt(char('-')),
+        digit1,
+        many_m_n(0, 1, parse_decimal),
+    )));
     let float = pattern.and_then(float);
     map(float, Number::new)(input.into())
 }
@@ -2,7 +2,7 @@
 
 use nom::combinator::map;
 
-use crate::parsers::{parse_name, prefix_expr, typed_list, ParseResult, Span};
+use crate::parsers::{parse_name, section_expr, typed_list, ParseResult, Span};
 use crate::types::Objects;
 
 /// Parser for goal object declarations.
@@ -23,7 +23,7 @@ pub fn parse_problem_objects_declaration<'a, T: Into<Span<'a>>>(
     input: T,
 ) -> ParseResult<'a, Objects> {
     map(
-        prefix_expr(":objects", typed_list(parse_name)),
+        section_expr(":objects", "objects declaration", typed_list(parse_name)),
         Objects::new,
     )(input.into())
 }
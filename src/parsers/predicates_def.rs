@@ -3,7 +3,7 @@
 use nom::combinator::map;
 
 use crate::parsers::{parse_atomic_formula_skeleton, ParseResult, Span};
-use crate::parsers::{prefix_expr, space_separated_list1};
+use crate::parsers::{section_expr, space_separated_list1};
 use crate::types::PredicateDefinitions;
 
 /// Parses predicate definitions, i.e. `(:predicates <atomic formula skeleton>⁺)`.
@@ -39,8 +39,9 @@ pub fn parse_predicates_def<'a, T: Into<Span<'a>>>(
     input: T,
 ) -> ParseResult<'a, PredicateDefinitions> {
     map(
-        prefix_expr(
+        section_expr(
             ":predicates",
+            "predicates definition",
             space_separated_list1(parse_atomic_formula_skeleton),
         ),
         PredicateDefinitions::new,
@@ -1,20 +1,29 @@
 //! Provides parsers for problem definitions.
 
-use nom::character::complete::multispace1;
-use nom::combinator::{map, opt};
-use nom::sequence::{preceded, tuple};
+use nom::branch::alt;
+use nom::character::complete::multispace0;
+use nom::combinator::map;
+use nom::error::{context, ErrorKind};
+use nom::sequence::preceded;
+use nom_greedyerror::GreedyErrorKind;
 
-use crate::parsers::{parse_name, prefix_expr, ws2, ParseResult, Span};
+use crate::parsers::{
+    ignore_eol_comment, parse_name, prefix_expr, section_expr, spanned, unordered_sections, ws2,
+    ParseError, ParseResult, Span,
+};
 use crate::parsers::{
     parse_problem_constraints_def, parse_problem_goal_def, parse_problem_init_def,
     parse_problem_length_spec, parse_problem_metric_spec, parse_problem_objects_declaration,
     parse_require_def,
 };
-use crate::types::{Objects, Problem};
+use crate::types::{GoalDef, InitElements, LengthSpec, MetricSpec, Name, Objects, Problem};
 use crate::types::{ProblemConstraintsDef, Requirements};
 
 /// Parses a problem definitions.
 ///
+/// The sections of the problem may appear in any order, but each section may appear at most
+/// once. The `:domain`, `:init` and `:goal` sections are mandatory.
+///
 /// ## Example
 /// ```
 /// # use pddl::parsers::{parse_action_def, parse_problem, preamble::*};
@@ -37,40 +46,107 @@ use crate::types::{ProblemConstraintsDef, Requirements};
 /// assert_eq!(problem.goals().len(), 3);
 /// ```
 pub fn parse_problem<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Problem> {
-    map(
-        ws2(prefix_expr(
-            "define",
-            tuple((
-                prefix_expr("problem", parse_name),
-                preceded(multispace1, prefix_expr(":domain", parse_name)),
-                opt(preceded(multispace1, parse_require_def)),
-                opt(preceded(multispace1, parse_problem_objects_declaration)),
-                preceded(multispace1, parse_problem_init_def),
-                preceded(multispace1, parse_problem_goal_def),
-                // :constraints
-                opt(preceded(multispace1, parse_problem_constraints_def)),
-                // :numeric-fluents
-                opt(preceded(multispace1, parse_problem_metric_spec)),
-                // Deprecated since PDDL 2.1
-                opt(preceded(multispace1, parse_problem_length_spec)),
-            )),
-        )),
-        |(name, domain, reqs, objects, init, goal, constraints, metric, length)| {
-            Problem::new(
-                name,
-                domain,
-                reqs.unwrap_or(Requirements::new([])), // TODO: Do we need to imply STRIPS if empty?
-                objects.unwrap_or(Objects::default()),
-                init,
-                goal,
-                constraints.unwrap_or(ProblemConstraintsDef::default()),
-                metric,
-                length,
-            )
-        },
+    context(
+        "problem definition",
+        ws2(spanned(prefix_expr("define", parse_problem_body))),
     )(input.into())
 }
 
+/// A top-level section of a problem definition.
+enum ProblemSection {
+    Domain(Name),
+    Requirements(Requirements),
+    Objects(Objects),
+    Init(InitElements),
+    Goal(GoalDef),
+    Constraints(ProblemConstraintsDef),
+    Metric(MetricSpec),
+    Length(LengthSpec),
+}
+
+/// Parses any one of the sections of a problem definition.
+fn parse_problem_section(input: Span) -> ParseResult<ProblemSection> {
+    alt((
+        map(
+            section_expr(":domain", "domain reference", parse_name),
+            ProblemSection::Domain,
+        ),
+        map(parse_require_def, ProblemSection::Requirements),
+        map(parse_problem_objects_declaration, ProblemSection::Objects),
+        map(parse_problem_init_def, ProblemSection::Init),
+        map(parse_problem_goal_def, ProblemSection::Goal),
+        // :constraints
+        map(parse_problem_constraints_def, ProblemSection::Constraints),
+        // :numeric-fluents
+        map(parse_problem_metric_spec, ProblemSection::Metric),
+        // Deprecated since PDDL 2.1
+        map(parse_problem_length_spec, ProblemSection::Length),
+    ))(input)
+}
+
+/// Parses the name and the sections of a problem definition, given in any order.
+fn parse_problem_body(input: Span) -> ParseResult<Problem> {
+    let (input, name) = prefix_expr("problem", parse_name)(input)?;
+    let (remaining, sections) = unordered_sections(parse_problem_section, |_| false)(input)?;
+
+    let mut domain = None;
+    let mut requirements = Requirements::new([]); // TODO: Do we need to imply STRIPS if empty?
+    let mut objects = Objects::default();
+    let mut init = None;
+    let mut goal = None;
+    let mut constraints = ProblemConstraintsDef::default();
+    let mut metric = None;
+    let mut length = None;
+
+    for section in sections {
+        match section {
+            ProblemSection::Domain(value) => domain = Some(value),
+            ProblemSection::Requirements(value) => requirements = value,
+            ProblemSection::Objects(value) => objects = value,
+            ProblemSection::Init(value) => init = Some(value),
+            ProblemSection::Goal(value) => goal = Some(value),
+            ProblemSection::Constraints(value) => constraints = value,
+            ProblemSection::Metric(value) => metric = Some(value),
+            ProblemSection::Length(value) => length = Some(value),
+        }
+    }
+
+    let (domain, init, goal) = match (domain, init, goal) {
+        (Some(domain), Some(init), Some(goal)) => (domain, init, goal),
+        (None, _, _) => return Err(missing_section(remaining, ":domain")),
+        (_, None, _) => return Err(missing_section(remaining, ":init")),
+        (_, _, None) => return Err(missing_section(remaining, ":goal")),
+    };
+
+    let problem = Problem::new(
+        name,
+        domain,
+        requirements,
+        objects,
+        init,
+        goal,
+        constraints,
+        metric,
+        length,
+    );
+    Ok((remaining, problem))
+}
+
+/// Reports a missing mandatory section at the end of the definition. If the definition
+/// does not end at `input`, the unexpected input is reported instead.
+fn missing_section<'a>(input: Span<'a>, keyword: &'static str) -> nom::Err<ParseError<'a>> {
+    let position = match preceded(multispace0, ignore_eol_comment)(input) {
+        Ok((position, _)) => position,
+        Err(e) => return e,
+    };
+
+    let mut errors = vec![(position, GreedyErrorKind::Nom(ErrorKind::Tag))];
+    if position.fragment().starts_with(')') {
+        errors.push((position, GreedyErrorKind::Context(keyword)));
+    }
+    nom::Err::Error(ParseError { errors })
+}
+
 impl crate::parsers::Parser for Problem {
     type Item = Problem;
 
@@ -127,4 +203,42 @@ mod tests {
         assert_eq!(problem.init().len(), 9);
         assert_eq!(problem.goals().len(), 3);
     }
+
+    #[test]
+    fn test_parse_sections_in_any_order() {
+        let input = r#"(define (problem get-paid)
+                 (:goal (at P office))
+                 (:objects P - physob)
+                 (:init (at P home))
+                 (:domain briefcase-world))"#;
+
+        let problem = Problem::from_str(input).unwrap();
+
+        assert_eq!(problem.domain(), &Name::new("briefcase-world"));
+        assert_eq!(problem.objects().len(), 1);
+        assert_eq!(problem.init().len(), 1);
+        assert_eq!(problem.goals().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_duplicate_section() {
+        let input = "(define (problem p) (:domain d) (:init) (:goal (a)) (:init (b)))";
+        let error = Problem::from_str(input).unwrap_err();
+        assert_eq!(error.column(), 54);
+        assert_eq!(
+            error.message(),
+            "duplicate section `:init` inside `(define ...)`"
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_section() {
+        let input = "(define (problem p) (:goal (a)) (:domain d))";
+        let error = Problem::from_str(input).unwrap_err();
+        assert_eq!(error.column(), 44);
+        assert_eq!(
+            error.message(),
+            "expected `:init`, found `)` inside `(define ...)`"
+        );
+    }
 }
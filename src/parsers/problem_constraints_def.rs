@@ -2,7 +2,7 @@
 
 use nom::combinator::map;
 
-use crate::parsers::{parse_pref_con_gd, prefix_expr, ParseResult, Span};
+use crate::parsers::{parse_pref_con_gd, section_expr, ParseResult, Span};
 use crate::types::ProblemConstraintsDef;
 
 /// Parses problem constraint definitions, i.e. `(:constraints <pref-con-GD>)`.
@@ -23,7 +23,7 @@ pub fn parse_problem_constraints_def<'a, T: Into<Span<'a>>>(
 ) -> ParseResult<'a, ProblemConstraintsDef> {
     // :constraints
     map(
-        prefix_expr(":constraints", parse_pref_con_gd),
+        section_expr(":constraints", "constraints definition", parse_pref_con_gd),
         ProblemConstraintsDef::new,
     )(input.into())
 }
@@ -0,0 +1,617 @@
+//! Provides error-recovering parsers for domains and problems.
+
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::error::{context, ErrorKind};
+use nom::sequence::preceded;
+use nom::Slice;
+use nom_greedyerror::GreedyErrorKind;
+
+use crate::parsers::error::DUPLICATE_SECTION;
+use crate::parsers::{
+    ignore_eol_comment, parse_constants_def, parse_domain_constraints_def, parse_functions_def,
+    parse_name, parse_predicates_def, parse_problem_constraints_def, parse_problem_goal_def,
+    parse_problem_init_def, parse_problem_length_spec, parse_problem_metric_spec,
+    parse_problem_objects_declaration, parse_require_def, parse_structure_def, parse_timeless_def,
+    parse_types_def, prefix_expr, section_expr, space_separated_list1, ws, Error, ParseError,
+    ParseResult, Span,
+};
+use crate::types::{Domain, GoalDef, InitElements, Problem, StructureDefs};
+
+/// The result of an error-recovering parse.
+///
+/// Holds the partially parsed value, i.e. all sections that could be parsed,
+/// along with the errors of all sections that could not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recovered<T> {
+    value: Option<T>,
+    errors: Vec<Error>,
+}
+
+impl<T> Recovered<T> {
+    /// Gets the parsed value, or `None` if not even the header of the definition
+    /// could be parsed.
+    pub fn value(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Gets all errors in the order they appear in the input.
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// Returns `true` if the input was parsed without any errors.
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty() && self.value.is_some()
+    }
+
+    /// Returns the parsed value and the errors.
+    pub fn into_parts(self) -> (Option<T>, Vec<Error>) {
+        (self.value, self.errors)
+    }
+
+    /// Returns the parsed value if there were no errors, or all errors otherwise.
+    pub fn into_result(self) -> Result<T, Vec<Error>> {
+        match self.value {
+            Some(value) if self.errors.is_empty() => Ok(value),
+            _ => Err(self.errors),
+        }
+    }
+}
+
+/// A top-level section of a domain or problem definition.
+struct Section {
+    keyword: &'static str,
+    /// Whether the section may appear multiple times, e.g. `(:action ...)`.
+    repeatable: bool,
+}
+
+impl Section {
+    const fn new(keyword: &'static str) -> Self {
+        Self {
+            keyword,
+            repeatable: false,
+        }
+    }
+
+    const fn repeatable(keyword: &'static str) -> Self {
+        Self {
+            keyword,
+            repeatable: true,
+        }
+    }
+}
+
+const DOMAIN_SECTIONS: &[Section] = &[
+    Section::new(":extends"),
+    Section::new(":requirements"),
+    Section::new(":types"),
+    Section::new(":constants"),
+    Section::new(":predicates"),
+    Section::new(":timeless"),
+    Section::new(":functions"),
+    Section::new(":constraints"),
+    Section::repeatable(":action"),
+    Section::repeatable(":durative-action"),
+    Section::repeatable(":derived"),
+];
+
+const PROBLEM_SECTIONS: &[Section] = &[
+    Section::new(":domain"),
+    Section::new(":requirements"),
+    Section::new(":objects"),
+    Section::new(":init"),
+    Section::new(":goal"),
+    Section::new(":constraints"),
+    Section::new(":metric"),
+    Section::new(":length"),
+];
+
+/// Parses a domain definition, recovering from errors.
+///
+/// Whenever a section such as `(:types ...)` or `(:action ...)` cannot be parsed, the error
+/// is recorded and parsing resumes at the next top-level section. The resulting domain
+/// contains all sections that were parsed successfully.
+///
+/// ## Example
+/// ```
+/// # use pddl::parsers::parse_domain_with_recovery;
+/// let input = r#"(define (domain briefcase-world)
+///     (:predicates (at ?x ?y) (in ?x))
+///     (:action put-in :parameters (?x ?l) :precondition (at ?x ?l) :effect (in ?x))
+///     (:action move :precondition (at ?x ?l))
+///     (:action take-out :parameters (?x) :effect (not (in ?x))
+///     (:action drop :parameters (?x) :effect (not (in ?x))))"#;
+///
+/// let recovered = parse_domain_with_recovery(input);
+/// let domain = recovered.value().unwrap();
+/// assert_eq!(domain.predicates().len(), 2);
+/// assert_eq!(domain.structure().len(), 2);
+///
+/// let errors = recovered.errors();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors[0].line(), 4);
+/// assert_eq!(errors[0].expected(), [":parameters"]);
+/// assert_eq!(errors[1].line(), 6);
+/// assert_eq!(errors[1].expected(), [")"]);
+/// assert_eq!(errors[1].construct(), Some("action definition"));
+/// ```
+pub fn parse_domain_with_recovery(input: &str) -> Recovered<Domain> {
+    let define = Span::new(input);
+    let mut header = context(
+        "domain definition",
+        preceded(
+            preceded(ws(char('(')), tag_no_case("define")),
+            ws(prefix_expr("domain", parse_name)),
+        ),
+    );
+
+    let (rest, name) = match header(define) {
+        Ok(result) => result,
+        Err(e) => return Recovered::failed(input, e),
+    };
+
+    let mut extends = None;
+    let mut requirements = None;
+    let mut types = None;
+    let mut constants = None;
+    let mut predicates = None;
+    let mut timeless = None;
+    let mut functions = None;
+    let mut constraints = None;
+    let mut structure = Vec::new();
+
+    let mut sections = Sections::new(input, define, "domain definition", DOMAIN_SECTIONS);
+    let end = sections.parse(rest, |keyword, input| match keyword {
+        ":extends" => store(
+            &mut extends,
+            section_expr(
+                ":extends",
+                "extends definition",
+                space_separated_list1(parse_name),
+            )(input),
+        ),
+        ":requirements" => store(&mut requirements, parse_require_def(input)),
+        ":types" => store(&mut types, parse_types_def(input)),
+        ":constants" => store(&mut constants, parse_constants_def(input)),
+        ":predicates" => store(&mut predicates, parse_predicates_def(input)),
+        ":timeless" => store(&mut timeless, parse_timeless_def(input)),
+        ":functions" => store(&mut functions, parse_functions_def(input)),
+        ":constraints" => store(&mut constraints, parse_domain_constraints_def(input)),
+        _ => parse_structure_def(input).map(|(remaining, def)| {
+            structure.push(def);
+            remaining
+        }),
+    });
+    sections.report_trailing(end);
+
+    let domain = Domain::builder(name, StructureDefs::new(structure))
+        .with_extends(extends.unwrap_or_default())
+        .with_requirements(requirements.unwrap_or_default())
+        .with_types(types.unwrap_or_default())
+        .with_constants(constants.unwrap_or_default())
+        .with_predicates(predicates.unwrap_or_default())
+        .with_functions(functions.unwrap_or_default())
+        .with_constraints(constraints.unwrap_or_default())
+        .with_timeless(timeless.unwrap_or_default());
+
+    Recovered {
+        value: Some(domain),
+        errors: sections.errors,
+    }
+}
+
+/// Parses a problem definition, recovering from errors.
+///
+/// Whenever a section such as `(:objects ...)` or `(:init ...)` cannot be parsed, the error
+/// is recorded and parsing resumes at the next top-level section. The resulting problem
+/// contains all sections that were parsed successfully; missing mandatory sections are
+/// reported as errors and left empty.
+///
+/// ## Example
+/// ```
+/// # use pddl::parsers::parse_problem_with_recovery;
+/// let input = r#"(define (problem get-paid)
+///     (:domain briefcase-world)
+///     (:objects home office - location)
+///     (:init (at B home) (at P ?home))
+///     (:goal (and (at B office) (at P home)))
+/// )"#;
+///
+/// let recovered = parse_problem_with_recovery(input);
+/// let problem = recovered.value().unwrap();
+/// assert_eq!(problem.objects().len(), 2);
+/// assert_eq!(problem.goals().len(), 2);
+/// assert!(problem.init().is_empty());
+///
+/// let errors = recovered.errors();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!((errors[0].line(), errors[0].column()), (4, 24));
+/// ```
+pub fn parse_problem_with_recovery(input: &str) -> Recovered<Problem> {
+    let define = Span::new(input);
+    let mut header = context(
+        "problem definition",
+        preceded(
+            preceded(ws(char('(')), tag_no_case("define")),
+            ws(prefix_expr("problem", parse_name)),
+        ),
+    );
+
+    let (rest, name) = match header(define) {
+        Ok(result) => result,
+        Err(e) => return Recovered::failed(input, e),
+    };
+
+    let mut domain = None;
+    let mut requirements = None;
+    let mut objects = None;
+    let mut init = None;
+    let mut goal = None;
+    let mut constraints = None;
+    let mut metric = None;
+    let mut length = None;
+
+    let mut sections = Sections::new(input, define, "problem definition", PROBLEM_SECTIONS);
+    let end = sections.parse(rest, |keyword, input| match keyword {
+        ":domain" => store(
+            &mut domain,
+            section_expr(":domain", "domain reference", parse_name)(input),
+        ),
+        ":requirements" => store(&mut requirements, parse_require_def(input)),
+        ":objects" => store(&mut objects, parse_problem_objects_declaration(input)),
+        ":init" => store(&mut init, parse_problem_init_def(input)),
+        ":goal" => store(&mut goal, parse_problem_goal_def(input)),
+        ":constraints" => store(&mut constraints, parse_problem_constraints_def(input)),
+        ":metric" => store(&mut metric, parse_problem_metric_spec(input)),
+        _ => store(&mut length, parse_problem_length_spec(input)),
+    });
+
+    // Mandatory sections that are missing entirely, rather than containing errors.
+    for (keyword, missing) in [
+        (":domain", domain.is_none()),
+        (":init", init.is_none()),
+        (":goal", goal.is_none()),
+    ] {
+        if missing && !sections.attempted.contains(&keyword) {
+            sections.report(end, Some(keyword));
+        }
+    }
+    sections.report_trailing(end);
+
+    let mut problem = Problem::builder(
+        name,
+        domain.unwrap_or_default(),
+        init.unwrap_or_else(|| InitElements::new(Vec::new())),
+        goal.unwrap_or_else(|| GoalDef::from_iter([])),
+    )
+    .with_requirements(requirements.unwrap_or_default())
+    .with_objects(objects.unwrap_or_default())
+    .with_constraints(constraints.unwrap_or_default());
+    if let Some(metric) = metric {
+        problem = problem.with_metric_spec(metric);
+    }
+    if let Some(length) = length {
+        problem = problem.with_length_spec(length);
+    }
+
+    let mut errors = sections.errors;
+    errors.sort_by_key(Error::offset);
+    Recovered {
+        value: Some(problem),
+        errors,
+    }
+}
+
+impl<T> Recovered<T> {
+    fn failed(input: &str, error: nom::Err<ParseError<'_>>) -> Self {
+        Self {
+            value: None,
+            errors: vec![Error::new(input, error)],
+        }
+    }
+}
+
+/// Stores the parsed value unless the section was already parsed before.
+fn store<'a, T>(
+    slot: &mut Option<T>,
+    result: ParseResult<'a, T>,
+) -> Result<Span<'a>, nom::Err<ParseError<'a>>> {
+    let (remaining, value) = result?;
+    slot.get_or_insert(value);
+    Ok(remaining)
+}
+
+/// Parses the top-level sections of a `(define ...)` expression.
+struct Sections<'a> {
+    input: &'a str,
+    /// The start of the `(define ...)` expression.
+    define: Span<'a>,
+    construct: &'static str,
+    sections: &'static [Section],
+    /// The keywords of all sections encountered so far.
+    attempted: Vec<&'static str>,
+    errors: Vec<Error>,
+}
+
+impl<'a> Sections<'a> {
+    fn new(
+        input: &'a str,
+        define: Span<'a>,
+        construct: &'static str,
+        sections: &'static [Section],
+    ) -> Self {
+        Self {
+            input,
+            define,
+            construct,
+            sections,
+            attempted: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Parses all sections up to the closing parenthesis of the definition using the
+    /// `parse` function, which receives the section keyword and the input starting with the
+    /// section. Returns the position at which parsing stopped.
+    fn parse<F>(&mut self, mut rest: Span<'a>, mut parse: F) -> Span<'a>
+    where
+        F: FnMut(&'static str, Span<'a>) -> Result<Span<'a>, nom::Err<ParseError<'a>>>,
+    {
+        loop {
+            rest = skip_whitespace(rest);
+            let keyword = match rest.fragment().chars().next() {
+                None => {
+                    self.report(rest, Some(")"));
+                    return rest;
+                }
+                Some(')') => return rest,
+                Some('(') => keyword(rest),
+                Some(_) => {
+                    self.report(rest, None);
+                    match self.resync(rest) {
+                        Some(next) => rest = next,
+                        None => return self.end(),
+                    }
+                    continue;
+                }
+            };
+
+            let section = keyword.and_then(|(span, keyword)| {
+                self.sections
+                    .iter()
+                    .find(|section| section.keyword.eq_ignore_ascii_case(keyword.fragment()))
+                    .map(|section| (span, section))
+            });
+
+            let Some((span, section)) = section else {
+                self.report(keyword.map_or(rest, |(_, keyword)| keyword), None);
+                match self.resync(rest) {
+                    Some(next) => rest = next,
+                    None => return self.end(),
+                }
+                continue;
+            };
+
+            // Sections may appear in any order, but only repeatable sections may appear twice.
+            if !section.repeatable && self.attempted.contains(&section.keyword) {
+                self.report_duplicate(span);
+            }
+            self.attempted.push(section.keyword);
+
+            match parse(section.keyword, rest) {
+                Ok(remaining) => rest = remaining,
+                Err(e) => {
+                    self.errors.push(Error::new(self.input, e));
+                    match self.resync(rest) {
+                        Some(next) => rest = next,
+                        None => return self.end(),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Records an error at the specified position, optionally naming the expected token.
+    fn report(&mut self, position: Span<'a>, expected: Option<&'static str>) {
+        let mut errors = vec![(position, GreedyErrorKind::Nom(ErrorKind::Tag))];
+        if let Some(token) = expected {
+            errors.push((position, GreedyErrorKind::Context(token)));
+        }
+        errors.push((self.define, GreedyErrorKind::Context(self.construct)));
+        let error = ParseError { errors };
+        self.errors
+            .push(Error::new(self.input, nom::Err::Error(error)));
+    }
+
+    /// Reports a repeated section whose keyword starts at `position`.
+    fn report_duplicate(&mut self, position: Span<'a>) {
+        let errors = vec![
+            (position, GreedyErrorKind::Context(DUPLICATE_SECTION)),
+            (self.define, GreedyErrorKind::Context(self.construct)),
+        ];
+        self.errors.push(Error::new(
+            self.input,
+            nom::Err::Error(ParseError { errors }),
+        ));
+    }
+
+    /// Reports any input other than whitespace and comments following the closing
+    /// parenthesis of the definition at `end`.
+    fn report_trailing(&mut self, end: Span<'a>) {
+        if !end.fragment().starts_with(')') {
+            return;
+        }
+
+        let rest = skip_whitespace(end.slice(1..));
+        if !rest.fragment().is_empty() {
+            let error = ParseError {
+                errors: vec![(rest, GreedyErrorKind::Nom(ErrorKind::Eof))],
+            };
+            self.errors
+                .push(Error::new(self.input, nom::Err::Error(error)));
+        }
+    }
+
+    /// Finds the start of the next section after the one starting at `position`.
+    fn resync(&self, position: Span<'a>) -> Option<Span<'a>> {
+        let fragment = position.fragment();
+        let mut offset = 1;
+        while offset < fragment.len() {
+            let rest = &fragment[offset..];
+            if rest.starts_with(';') {
+                offset += rest.find('\n').unwrap_or(rest.len());
+                continue;
+            }
+
+            if rest.starts_with('(') {
+                let candidate = position.slice(offset..);
+                let is_section = keyword(candidate).map_or(false, |(_, keyword)| {
+                    let keyword = *keyword.fragment();
+                    self.sections
+                        .iter()
+                        .any(|s| s.keyword.eq_ignore_ascii_case(keyword))
+                });
+                if is_section {
+                    return Some(candidate);
+                }
+            }
+
+            offset += rest.chars().next().map_or(1, char::len_utf8);
+        }
+        None
+    }
+
+    /// Returns the end of the input.
+    fn end(&self) -> Span<'a> {
+        self.define.slice(self.input.len()..)
+    }
+}
+
+/// Skips whitespace and comments.
+fn skip_whitespace(input: Span) -> Span {
+    match ws(ignore_eol_comment)(input) {
+        Ok((remaining, _)) => remaining,
+        Err(_) => input,
+    }
+}
+
+/// Returns the start of the section and its keyword, e.g. `:action`, for input starting
+/// with a parenthesized expression.
+fn keyword(input: Span) -> Option<(Span, Span)> {
+    let inner = skip_whitespace(input.slice(1..));
+    let length = inner
+        .fragment()
+        .find(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == ';')
+        .unwrap_or(inner.fragment().len());
+    (length > 0).then(|| (inner, inner.slice(..length)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    const DOMAIN: &str = r#"(define (domain briefcase-world)
+  (:requirements :strips :typing)
+  (:types location physob)
+  (:predicates (at ?x - physob ?y - location) (in ?x ?y - physob))
+  (:action move-briefcase
+    :parameters (?m ?l - location)
+    :precondition (at B ?m)
+    :effect (and (at B ?l) (not (at B ?m))))
+  (:action put-in
+    :parameters (?x - physob ?l - location)
+    :effect (in ?x))
+)"#;
+
+    #[test]
+    fn valid_input_has_no_errors() {
+        let recovered = parse_domain_with_recovery(DOMAIN);
+        assert!(recovered.is_ok());
+        assert_eq!(
+            recovered.into_result().unwrap(),
+            Domain::from_str(DOMAIN).unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_errors_in_all_sections() {
+        let input = DOMAIN
+            .replace("(:types location physob)", "(:types location - )")
+            .replace(":parameters (?m", ":params (?m")
+            .replace("(in ?x))\n)", "(in ?x ?))\n)");
+        let recovered = parse_domain_with_recovery(&input);
+        let lines: Vec<_> = recovered.errors().iter().map(Error::line).collect();
+        assert_eq!(lines, [3, 6, 11]);
+
+        let domain = recovered.value().unwrap();
+        assert_eq!(domain.requirements().len(), 2);
+        assert!(domain.types().is_empty());
+        assert_eq!(domain.predicates().len(), 2);
+        assert!(domain.structure().is_empty());
+    }
+
+    #[test]
+    fn reports_unknown_and_duplicate_sections() {
+        let input = DOMAIN
+            .replace("(:types location physob)", "(:typs location physob)")
+            .replace("(:action put-in", "(:requirements :adl)\n  (:action put-in");
+        let recovered = parse_domain_with_recovery(&input);
+        let errors = recovered.errors();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].found(), Some(":typs"));
+        assert_eq!(errors[1].found(), Some(":requirements"));
+        assert_eq!(
+            errors[1].message(),
+            "duplicate section `:requirements` inside `(define ...)`"
+        );
+        assert_eq!(recovered.value().unwrap().structure().len(), 2);
+    }
+
+    #[test]
+    fn accepts_sections_in_any_order() {
+        let input = DOMAIN.replace(
+            "(:action put-in",
+            "(:constants B - physob)\n  (:action put-in",
+        );
+        let recovered = parse_domain_with_recovery(&input);
+        assert!(recovered.is_ok());
+        assert_eq!(recovered.value().unwrap().constants().len(), 1);
+    }
+
+    #[test]
+    fn reports_missing_sections() {
+        let recovered = parse_problem_with_recovery("(define (problem p) (:domain d) (:init))");
+        let errors = recovered.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].expected(), [":goal"]);
+        assert_eq!(recovered.value().unwrap().domain(), "d");
+    }
+
+    #[test]
+    fn reports_unclosed_definition() {
+        let recovered =
+            parse_problem_with_recovery("(define (problem p) (:domain d) (:init) (:goal (a))");
+        let errors = recovered.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].expected(), [")"]);
+        assert_eq!(errors[0].found(), None);
+    }
+
+    #[test]
+    fn reports_trailing_input() {
+        let recovered = parse_domain_with_recovery("(define (domain d)) ; done\n (junk)");
+        let errors = recovered.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line(), errors[0].column()), (2, 2));
+        assert!(recovered.value().is_some());
+    }
+
+    #[test]
+    fn invalid_header_has_no_value() {
+        let recovered = parse_domain_with_recovery("(define (problem p))");
+        assert!(recovered.value().is_none());
+        assert_eq!(recovered.into_result().unwrap_err().len(), 1);
+    }
+}
@@ -1,7 +1,7 @@
 //! Provides parsers for assignment operations.
 
 use nom::branch::alt;
-use nom::bytes::complete::tag;
+use nom::bytes::complete::tag_no_case;
 use nom::combinator::map;
 
 use crate::parsers::{ParseResult, Span};
@@ -18,9 +18,13 @@ use crate::types::TimeSpecifier;
 /// assert!(parse_time_specifier("end").is_value(TimeSpecifier::End));
 ///```
 pub fn parse_time_specifier<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, TimeSpecifier> {
-    map(alt((tag(names::START), tag(names::END))), |x: Span| {
-        TimeSpecifier::try_from(*x.fragment()).expect("unhandled variant")
-    })(input.into())
+    map(
+        alt((tag_no_case(names::START), tag_no_case(names::END))),
+        |x: Span| {
+            TimeSpecifier::try_from(x.fragment().to_ascii_lowercase().as_str())
+                .expect("unhandled variant")
+        },
+    )(input.into())
 }
 
 impl crate::parsers::Parser for TimeSpecifier {
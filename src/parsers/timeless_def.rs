@@ -1,6 +1,6 @@
 //! Provides parsers for timeless definitions.
 
-use crate::parsers::{literal, parse_name, prefix_expr, space_separated_list1, ParseResult, Span};
+use crate::parsers::{literal, parse_name, section_expr, space_separated_list1, ParseResult, Span};
 use crate::types::Timeless;
 use nom::combinator::map;
 
@@ -36,7 +36,11 @@ use nom::combinator::map;
 /// ```
 pub fn parse_timeless_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Timeless> {
     map(
-        prefix_expr(":timeless", space_separated_list1(literal(parse_name))),
+        section_expr(
+            ":timeless",
+            "timeless definition",
+            space_separated_list1(literal(parse_name)),
+        ),
         Timeless::from_iter,
     )(input.into())
 }
@@ -2,7 +2,7 @@
 
 use nom::combinator::map;
 
-use crate::parsers::{parse_name, prefix_expr, typed_list, ParseResult, Span};
+use crate::parsers::{parse_name, section_expr, typed_list, ParseResult, Span};
 use crate::types::Types;
 
 /// Parses constant definitions, i.e. `(:constants <typed list (name)>)`.
@@ -21,9 +21,10 @@ use crate::types::Types;
 /// ));
 /// ```
 pub fn parse_types_def<'a, T: Into<Span<'a>>>(input: T) -> ParseResult<'a, Types> {
-    map(prefix_expr(":types", typed_list(parse_name)), |vec| {
-        Types::new(vec)
-    })(input.into())
+    map(
+        section_expr(":types", "types definition", typed_list(parse_name)),
+        Types::new,
+    )(input.into())
 }
 
 impl crate::parsers::Parser for Types {
@@ -1,10 +1,15 @@
 //! Utility parsers.
 
-use crate::parsers::{ignore_eol_comment, ParseResult, Span};
-use nom::bytes::complete::tag;
+use crate::parsers::error::DUPLICATE_SECTION;
+use crate::parsers::{ignore_eol_comment, ParseError, ParseResult, Span};
+use nom::bytes::complete::tag_no_case;
 use nom::character::complete::{char, multispace0, multispace1};
+use nom::combinator::cut;
+use nom::error::{context, ContextError};
 use nom::multi::{separated_list0, separated_list1};
-use nom::sequence::{delimited, preceded};
+use nom::sequence::{delimited, preceded, terminated};
+use nom_greedyerror::GreedyErrorKind;
+use std::mem::discriminant;
 
 /// A combinator that takes a parser `inner` and produces a parser that also
 /// consumes a leading `(name` and trailing `)`, returning the output of `inner`.
@@ -13,7 +18,130 @@ pub fn prefix_expr<'a, F, O>(name: &'a str, inner: F) -> impl FnMut(Span<'a>) ->
 where
     F: FnMut(Span<'a>) -> ParseResult<'a, O>,
 {
-    delimited(preceded(ws(tag("(")), tag(name)), ws(inner), ws(tag(")")))
+    delimited(
+        preceded(ws(char('(')), tag_no_case(name)),
+        ws(inner),
+        ws(char(')')),
+    )
+}
+
+/// Parses a section or definition introduced by a unique keyword, e.g. `(:action ...)`.
+///
+/// Unlike [`prefix_expr`], the parser commits to the expression once `name` was matched:
+/// any later error is turned into a [`nom::Err::Failure`] so that it is reported to the caller
+/// instead of being discarded by enclosing `opt` or `alt` parsers, and is annotated with the
+/// `construct` being parsed. A missing `name` is reported as the expected token.
+pub fn section_expr<'a, F, O>(
+    name: &'static str,
+    construct: &'static str,
+    inner: F,
+) -> impl FnMut(Span<'a>) -> ParseResult<'a, O>
+where
+    F: FnMut(Span<'a>) -> ParseResult<'a, O>,
+{
+    let mut keyword = preceded(ws(char('(')), context(name, tag_no_case(name)));
+    let mut body = cut(terminated(ws(inner), ws(char(')'))));
+    move |input: Span<'a>| {
+        let (remaining, _) = keyword(input)?;
+        body(remaining).map_err(|e| e.map(|e| ParseError::add_context(input, construct, e)))
+    }
+}
+
+/// A combinator that takes a parser `section`, which parses any one of the sections of a
+/// definition, and produces a parser for whitespace separated sections in any order.
+///
+/// Sections for which `repeatable` returns `false`, i.e. all but e.g. `(:action ...)`,
+/// may appear only once. A repeated section fails with an error at its keyword.
+pub fn unordered_sections<'a, F, O>(
+    mut section: F,
+    repeatable: fn(&O) -> bool,
+) -> impl FnMut(Span<'a>) -> ParseResult<'a, Vec<O>>
+where
+    F: FnMut(Span<'a>) -> ParseResult<'a, O>,
+{
+    move |mut input: Span<'a>| {
+        let mut sections: Vec<O> = Vec::new();
+        loop {
+            let start = match multispace1::<_, ParseError>(input) {
+                Ok((start, _)) => start,
+                Err(_) => return Ok((input, sections)),
+            };
+
+            let (remaining, value) = match section(start) {
+                Ok(result) => result,
+                Err(nom::Err::Error(_)) => return Ok((input, sections)),
+                Err(e) => return Err(e),
+            };
+
+            let duplicate = !repeatable(&value)
+                && sections
+                    .iter()
+                    .any(|other| discriminant(other) == discriminant(&value));
+            if duplicate {
+                let (keyword, _) = ws(char('('))(start)?;
+                let errors = vec![(keyword, GreedyErrorKind::Context(DUPLICATE_SECTION))];
+                return Err(nom::Err::Failure(ParseError { errors }));
+            }
+
+            sections.push(value);
+            input = remaining;
+        }
+    }
+}
+
+/// Records the source location of the parsed node if the `spans` crate feature is enabled.
+#[cfg(feature = "spans")]
+pub fn spanned<'a, F, O>(inner: F) -> impl FnMut(Span<'a>) -> ParseResult<'a, O>
+where
+    F: FnMut(Span<'a>) -> ParseResult<'a, O>,
+    O: crate::Spanned,
+{
+    nom::combinator::map(with_source_span(inner), |(value, span)| {
+        value.with_span(span)
+    })
+}
+
+/// Records the source location of the parsed node if the `spans` crate feature is enabled.
+#[cfg(not(feature = "spans"))]
+pub fn spanned<'a, F, O>(inner: F) -> impl FnMut(Span<'a>) -> ParseResult<'a, O>
+where
+    F: FnMut(Span<'a>) -> ParseResult<'a, O>,
+{
+    inner
+}
+
+/// Returns the parsed value along with its source location, excluding leading and
+/// trailing whitespace and comments.
+#[cfg(feature = "spans")]
+pub fn with_source_span<'a, F, O>(
+    mut inner: F,
+) -> impl FnMut(Span<'a>) -> ParseResult<'a, (O, crate::SourceSpan)>
+where
+    F: FnMut(Span<'a>) -> ParseResult<'a, O>,
+{
+    use nom::Slice;
+
+    move |input: Span<'a>| {
+        let (remaining, value) = inner(input)?;
+        let consumed = &input.fragment()[..remaining.location_offset() - input.location_offset()];
+
+        let mut content = consumed.trim_start();
+        while let Some(comment) = content.strip_prefix(';') {
+            content = comment
+                .find('\n')
+                .map_or("", |end| &comment[end..])
+                .trim_start();
+        }
+
+        let start = input.slice(consumed.len() - content.len()..);
+        let span = crate::SourceSpan::new(
+            start.location_offset(),
+            start.location_offset() + content.trim_end().len(),
+            start.location_line(),
+            start.get_utf8_column(),
+        );
+        Ok((remaining, (value, span)))
+    }
 }
 
 /// A combinator that takes a parser `inner` and produces a parser that also consumes leading whitespace,
@@ -84,6 +212,7 @@ mod tests {
     use super::*;
     use crate::parsers::{parse_name, Match};
     use crate::Name;
+    use nom::bytes::complete::tag;
     use nom::multi::separated_list1;
 
     #[test]
@@ -101,6 +230,22 @@ mod tests {
         assert!(parser(Span::new(input)).is_exactly(vec![Name::from("x"), Name::from("y")]));
     }
 
+    #[test]
+    fn section_expr_works() {
+        let mut parser = section_expr(":types", "types", space_separated_list1(parse_name));
+        assert!(
+            parser(Span::new("(:types x y)")).is_exactly(vec![Name::from("x"), Name::from("y")])
+        );
+        assert!(matches!(
+            parser(Span::new("(:constants x)")),
+            Err(nom::Err::Error(_))
+        ));
+        assert!(matches!(
+            parser(Span::new("(:types x ())")),
+            Err(nom::Err::Failure(_))
+        ));
+    }
+
     #[test]
     fn space_separated_list0_works() {
         let mut parser = space_separated_list0(parse_name);
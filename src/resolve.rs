@@ -0,0 +1,498 @@
+//! Resolves the `:extends` inheritance of domains.
+//!
+//! PDDL 1.2 allows a domain to inherit the declarations of other domains by naming them
+//! in its `:extends` section. A [`DomainRegistry`] holds a family of domains by name and
+//! merges a domain with everything it inherits into a single [`Domain`] without an
+//! `:extends` section.
+//!
+//! ## Example
+//! ```
+//! # use pddl::{Domain, Parser};
+//! use pddl::resolve::DomainRegistry;
+//!
+//! let base = Domain::from_str(r#"(define (domain vehicles)
+//!     (:requirements :typing)
+//!     (:types vehicle location)
+//!     (:predicates (at ?v - vehicle ?l - location)))"#).unwrap();
+//!
+//! let trucks = Domain::from_str(r#"(define (domain trucks)
+//!     (:extends vehicles)
+//!     (:types truck - vehicle)
+//!     (:action drive
+//!         :parameters (?t - truck ?from ?to - location)
+//!         :precondition (at ?t ?from)
+//!         :effect (and (not (at ?t ?from)) (at ?t ?to))))"#).unwrap();
+//!
+//! let registry = DomainRegistry::from_iter([base, trucks]);
+//! let (domain, diagnostics) = registry.resolve(&"trucks".into()).unwrap();
+//!
+//! assert!(diagnostics.is_empty());
+//! assert!(domain.extends().is_empty());
+//! assert_eq!(domain.types().len(), 3);
+//! assert_eq!(domain.predicates().len(), 1);
+//! assert_eq!(domain.structure().len(), 1);
+//! ```
+
+use crate::types::{
+    AtomicFormulaSkeleton, AtomicFunctionSkeleton, ConGD, Constants, Domain, DomainConstraintsDef,
+    FunctionTyped, FunctionTypedList, Functions, Name, NameLiteral, PredicateDefinitions,
+    Requirement, Requirements, StructureDef, StructureDefs, Timeless, Typed, TypedList, Types,
+};
+use crate::validate::{Diagnostic, SymbolKind};
+use std::collections::HashMap;
+
+/// A set of domains that may extend each other, identified by their names.
+#[derive(Debug, Default, Clone)]
+pub struct DomainRegistry {
+    domains: HashMap<Name, Domain>,
+}
+
+impl DomainRegistry {
+    /// Constructs an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `domain`, replacing and returning a domain of the same name.
+    pub fn insert(&mut self, domain: Domain) -> Option<Domain> {
+        self.domains.insert(domain.name().clone(), domain)
+    }
+
+    /// Gets the domain with the given name.
+    pub fn get(&self, name: &Name) -> Option<&Domain> {
+        self.domains.get(name)
+    }
+
+    /// Gets the number of domains.
+    pub fn len(&self) -> usize {
+        self.domains.len()
+    }
+
+    /// Returns `true` if the registry contains no domains.
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Merges the domain with the given name with all domains it extends, directly or
+    /// indirectly, or returns `None` if the registry does not contain the domain.
+    ///
+    /// The merged domain has the name, span and own declarations of the named domain, along
+    /// with the requirements, types, constants, predicates, functions, constraints, timeless
+    /// facts and structure definitions it inherits. Inherited declarations come first, with
+    /// each domain merged after all domains it extends, and in the order of the `:extends`
+    /// section otherwise. Each domain is merged at most once.
+    ///
+    /// The following issues are reported:
+    /// - domains that are extended but not part of the registry,
+    ///   as [`Diagnostic::UnknownParentDomain`],
+    /// - domains that extend themselves, directly or indirectly,
+    ///   as [`Diagnostic::CyclicExtends`],
+    /// - types, constants, predicates, functions and actions that a domain declares
+    ///   differently than a domain merged before, as [`Diagnostic::ConflictingDefinition`].
+    ///   The later declaration replaces the earlier one, so a domain's own declarations
+    ///   take precedence over inherited ones.
+    pub fn resolve(&self, name: &Name) -> Option<(Domain, Vec<Diagnostic>)> {
+        let domain = self.get(name)?;
+
+        let mut merger = Merger::default();
+        let mut order = Vec::new();
+        self.linearize(domain, &mut Vec::new(), &mut order, &mut merger.diagnostics);
+        for ancestor in order {
+            merger.merge(ancestor);
+        }
+        Some(merger.finish(domain))
+    }
+
+    /// Adds the domains extended by `domain` to `order`, followed by `domain` itself.
+    fn linearize<'a>(
+        &'a self,
+        domain: &'a Domain,
+        stack: &mut Vec<&'a Name>,
+        order: &mut Vec<&'a Domain>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        if order.iter().any(|merged| merged.name() == domain.name()) {
+            return;
+        }
+
+        stack.push(domain.name());
+        for parent in domain.extends() {
+            if let Some(start) = stack.iter().position(|&name| name == parent) {
+                let cycle = stack[start..].iter().map(|&name| name.clone()).collect();
+                report(diagnostics, Diagnostic::CyclicExtends(cycle));
+                continue;
+            }
+
+            match self.get(parent) {
+                Some(parent) => self.linearize(parent, stack, order, diagnostics),
+                None => report(
+                    diagnostics,
+                    Diagnostic::UnknownParentDomain {
+                        domain: domain.name().clone(),
+                        parent: parent.clone(),
+                    },
+                ),
+            }
+        }
+        stack.pop();
+        order.push(domain);
+    }
+}
+
+impl FromIterator<Domain> for DomainRegistry {
+    fn from_iter<T: IntoIterator<Item = Domain>>(iter: T) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+impl Extend<Domain> for DomainRegistry {
+    fn extend<T: IntoIterator<Item = Domain>>(&mut self, iter: T) {
+        for domain in iter {
+            self.insert(domain);
+        }
+    }
+}
+
+/// Adds the `diagnostic` unless it was already reported.
+fn report(diagnostics: &mut Vec<Diagnostic>, diagnostic: Diagnostic) {
+    if !diagnostics.contains(&diagnostic) {
+        diagnostics.push(diagnostic);
+    }
+}
+
+/// A declaration along with the domain it was taken from.
+struct Definition<T> {
+    /// The declared name, or `None` for declarations that may occur more than once.
+    name: Option<Name>,
+    domain: Name,
+    value: T,
+}
+
+/// The merged declarations of one kind.
+struct Definitions<T> {
+    kind: SymbolKind,
+    definitions: Vec<Definition<T>>,
+}
+
+impl<T: PartialEq> Definitions<T> {
+    const fn new(kind: SymbolKind) -> Self {
+        Self {
+            kind,
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Adds the `value` declared by `domain` under `name`.
+    ///
+    /// A declaration that is `equivalent` to a previous one of the same name is skipped;
+    /// otherwise it replaces the previous declaration of another domain and is reported
+    /// as a conflict. Repeated declarations within a single domain keep the first one.
+    fn define(
+        &mut self,
+        name: Option<&Name>,
+        domain: &Name,
+        value: T,
+        equivalent: fn(&T, &T) -> bool,
+    ) -> Option<Diagnostic> {
+        let previous =
+            self.definitions
+                .iter_mut()
+                .find(|definition| match (&definition.name, name) {
+                    (Some(previous), Some(name)) => previous == name,
+                    _ => definition.value == value,
+                });
+
+        let Some(previous) = previous else {
+            self.definitions.push(Definition {
+                name: name.cloned(),
+                domain: domain.clone(),
+                value,
+            });
+            return None;
+        };
+
+        if equivalent(&previous.value, &value) || &previous.domain == domain {
+            return None;
+        }
+
+        let diagnostic = Diagnostic::ConflictingDefinition {
+            kind: self.kind,
+            name: name.cloned().unwrap_or_default(),
+            domain: domain.clone(),
+            previous: std::mem::replace(&mut previous.domain, domain.clone()),
+        };
+        previous.value = value;
+        Some(diagnostic)
+    }
+
+    fn into_values(self) -> impl Iterator<Item = T> {
+        self.definitions
+            .into_iter()
+            .map(|definition| definition.value)
+    }
+}
+
+/// Merges the declarations of domains in order.
+struct Merger {
+    requirements: Vec<Requirement>,
+    types: Definitions<Typed<Name>>,
+    constants: Definitions<Typed<Name>>,
+    predicates: Definitions<AtomicFormulaSkeleton>,
+    functions: Definitions<FunctionTyped<AtomicFunctionSkeleton>>,
+    structure: Definitions<StructureDef>,
+    constraints: Vec<ConGD>,
+    timeless: Vec<NameLiteral>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Default for Merger {
+    fn default() -> Self {
+        Self {
+            requirements: Vec::new(),
+            types: Definitions::new(SymbolKind::Type),
+            constants: Definitions::new(SymbolKind::Constant),
+            predicates: Definitions::new(SymbolKind::Predicate),
+            functions: Definitions::new(SymbolKind::Function),
+            structure: Definitions::new(SymbolKind::Action),
+            constraints: Vec::new(),
+            timeless: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+impl Merger {
+    fn merge(&mut self, domain: &Domain) {
+        let origin = domain.name();
+        let mut conflicts = Vec::new();
+
+        for requirement in domain.requirements().iter() {
+            if !self.requirements.contains(requirement) {
+                self.requirements.push(*requirement);
+            }
+        }
+
+        for r#type in domain.types().iter() {
+            conflicts.extend(self.types.define(
+                Some(r#type.value()),
+                origin,
+                r#type.clone(),
+                same_type,
+            ));
+        }
+
+        for constant in domain.constants().iter() {
+            conflicts.extend(self.constants.define(
+                Some(constant.value()),
+                origin,
+                constant.clone(),
+                same_type,
+            ));
+        }
+
+        for predicate in domain.predicates().iter() {
+            conflicts.extend(self.predicates.define(
+                Some(predicate.predicate()),
+                origin,
+                predicate.clone(),
+                same_predicate,
+            ));
+        }
+
+        for function in domain.functions().iter() {
+            conflicts.extend(self.functions.define(
+                Some(function.value_ref().symbol()),
+                origin,
+                function.clone(),
+                same_function,
+            ));
+        }
+
+        for def in domain.structure().iter() {
+            let name: Option<&Name> = match def {
+                StructureDef::Action(action) => Some(action.symbol()),
+                StructureDef::DurativeAction(action) => Some(action.symbol()),
+                StructureDef::Derived(_) => None,
+            };
+            conflicts.extend(
+                self.structure
+                    .define(name, origin, def.clone(), PartialEq::eq),
+            );
+        }
+
+        let constraints = domain.constraints();
+        if !constraints.is_empty() && !self.constraints.contains(constraints) {
+            self.constraints.push(constraints.clone());
+        }
+
+        for literal in domain.timeless().iter() {
+            if !self.timeless.contains(literal) {
+                self.timeless.push(literal.clone());
+            }
+        }
+
+        for conflict in conflicts {
+            report(&mut self.diagnostics, conflict);
+        }
+    }
+
+    /// Builds the merged domain with the name and span of `domain`.
+    fn finish(self, domain: &Domain) -> (Domain, Vec<Diagnostic>) {
+        let mut constraints = self.constraints;
+        let constraints = match constraints.len() {
+            0 => DomainConstraintsDef::default(),
+            1 => DomainConstraintsDef::new(constraints.remove(0)),
+            _ => DomainConstraintsDef::new(ConGD::new_and(constraints)),
+        };
+
+        let merged = Domain {
+            name: domain.name.clone(),
+            extends: Vec::new(),
+            requirements: Requirements::new(self.requirements),
+            types: Types::new(TypedList::from_iter(self.types.into_values())),
+            constants: Constants::new(TypedList::from_iter(self.constants.into_values())),
+            predicates: PredicateDefinitions::new(self.predicates.into_values()),
+            functions: Functions::new(FunctionTypedList::from_iter(self.functions.into_values())),
+            constraints,
+            timeless: Timeless::new(self.timeless),
+            structure: StructureDefs::new(self.structure.into_values()),
+            span: domain.span,
+        };
+        (merged, self.diagnostics)
+    }
+}
+
+/// Determines whether two types or constants are declared with the same type.
+fn same_type(a: &Typed<Name>, b: &Typed<Name>) -> bool {
+    a.type_() == b.type_()
+}
+
+/// Determines whether two predicates have the same parameter types.
+fn same_predicate(a: &AtomicFormulaSkeleton, b: &AtomicFormulaSkeleton) -> bool {
+    let types = |predicate: &AtomicFormulaSkeleton| {
+        predicate
+            .variables()
+            .iter()
+            .map(|variable| variable.type_().clone())
+            .collect::<Vec<_>>()
+    };
+    types(a) == types(b)
+}
+
+/// Determines whether two functions have the same parameter and value types.
+fn same_function(
+    a: &FunctionTyped<AtomicFunctionSkeleton>,
+    b: &FunctionTyped<AtomicFunctionSkeleton>,
+) -> bool {
+    let types = |function: &FunctionTyped<AtomicFunctionSkeleton>| {
+        function
+            .value_ref()
+            .variables()
+            .iter()
+            .map(|variable| variable.type_().clone())
+            .collect::<Vec<_>>()
+    };
+    a.type_ref() == b.type_ref() && types(a) == types(b)
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn registry(domains: &[&str]) -> DomainRegistry {
+        domains
+            .iter()
+            .map(|domain| Domain::from_str(domain).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn merges_diamond_inheritance_once() {
+        let registry = registry(&[
+            "(define (domain base) (:requirements :strips) (:predicates (p)) (:constants c))",
+            r#"(define (domain left) (:extends base) (:requirements :typing)
+                (:predicates (q)) (:action a :parameters () :effect (p)))"#,
+            r#"(define (domain right) (:extends base) (:predicates (r))
+                (:constraints (always (p))))"#,
+            "(define (domain child) (:extends left right) (:predicates (s)))",
+        ]);
+
+        let (domain, diagnostics) = registry.resolve(&"child".into()).unwrap();
+        assert_eq!(diagnostics, []);
+        assert_eq!(domain.name(), "child");
+        assert_eq!(
+            domain.requirements(),
+            &Requirements::new([Requirement::Strips, Requirement::Typing])
+        );
+        let predicates: Vec<_> = domain
+            .predicates()
+            .iter()
+            .map(|predicate| predicate.predicate().to_string())
+            .collect();
+        assert_eq!(predicates, ["p", "q", "r", "s"]);
+        assert_eq!(domain.constants().len(), 1);
+        assert_eq!(domain.structure().len(), 1);
+        assert!(!domain.constraints().is_empty());
+    }
+
+    #[test]
+    fn reports_conflicting_redefinitions() {
+        let registry = registry(&[
+            r#"(define (domain base) (:types truck - vehicle vehicle)
+                (:predicates (at ?t - truck))
+                (:action go :parameters () :effect (at t)))"#,
+            r#"(define (domain child) (:extends base) (:types truck)
+                (:predicates (AT ?x - truck))
+                (:action go :parameters () :effect (not (at t))))"#,
+        ]);
+
+        let (domain, diagnostics) = registry.resolve(&"child".into()).unwrap();
+        assert_eq!(
+            diagnostics,
+            [
+                Diagnostic::ConflictingDefinition {
+                    kind: SymbolKind::Type,
+                    name: "truck".into(),
+                    domain: "child".into(),
+                    previous: "base".into()
+                },
+                Diagnostic::ConflictingDefinition {
+                    kind: SymbolKind::Action,
+                    name: "go".into(),
+                    domain: "child".into(),
+                    previous: "base".into()
+                },
+            ]
+        );
+
+        // The domain's own definitions take precedence.
+        assert_eq!(domain.types()[0], Typed::new_object("truck".into()));
+        assert_eq!(domain.structure().len(), 1);
+    }
+
+    #[test]
+    fn reports_unknown_and_cyclic_parents() {
+        let registry = registry(&[
+            "(define (domain a) (:extends b unknown) (:predicates (p)))",
+            "(define (domain b) (:extends a) (:predicates (q)))",
+        ]);
+
+        assert!(registry.resolve(&"c".into()).is_none());
+
+        let (domain, diagnostics) = registry.resolve(&"a".into()).unwrap();
+        assert_eq!(
+            diagnostics,
+            [
+                Diagnostic::CyclicExtends(vec!["a".into(), "b".into()]),
+                Diagnostic::UnknownParentDomain {
+                    domain: "a".into(),
+                    parent: "unknown".into()
+                },
+            ]
+        );
+        assert_eq!(domain.predicates().len(), 2);
+    }
+}
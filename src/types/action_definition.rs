@@ -1,8 +1,12 @@
 //! Contains action definitions via the [`ActionDefinition`] type.
 
+use crate::types::NodeSpan;
 use crate::types::TypedVariables;
 use crate::types::{ActionSymbol, Effects};
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
 use crate::PreconditionGoalDefinitions;
+use std::fmt::{Display, Formatter};
 
 /// An action definition.
 ///
@@ -10,10 +14,11 @@ use crate::PreconditionGoalDefinitions;
 /// Used by [`StructureDef`](crate::StructureDef).
 #[derive(Debug, Clone, PartialEq)]
 pub struct ActionDefinition {
-    symbol: ActionSymbol,
-    parameters: TypedVariables,
-    precondition: PreconditionGoalDefinitions,
-    effect: Option<Effects>,
+    pub(crate) symbol: ActionSymbol,
+    pub(crate) parameters: TypedVariables,
+    pub(crate) precondition: PreconditionGoalDefinitions,
+    pub(crate) effect: Option<Effects>,
+    pub(crate) span: NodeSpan,
 }
 
 impl ActionDefinition {
@@ -28,6 +33,7 @@ impl ActionDefinition {
             parameters,
             precondition,
             effect,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -53,3 +59,29 @@ impl AsRef<ActionSymbol> for ActionDefinition {
         &self.symbol
     }
 }
+
+impl Display for ActionDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:action {}", self.symbol)?;
+        write!(f, "\n  :parameters ({})", self.parameters)?;
+        if !self.precondition.is_empty() {
+            write!(f, "\n  :precondition {}", self.precondition)?;
+        }
+        if let Some(effect) = &self.effect {
+            write!(f, "\n  :effect {effect}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for ActionDefinition {
+    fn span(&self) -> Option<SourceSpan> {
+        self.span.0
+    }
+
+    fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = NodeSpan(Some(span));
+        self
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains action symbols via the [`ActionSymbol`] type.
 
 use crate::types::Name;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// An action symbol name.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`ActionDefinition`](crate::ActionDefinition).
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
-pub struct ActionSymbol(Name);
+pub struct ActionSymbol(pub(crate) Name);
 
 impl ActionSymbol {
     #[inline(always)]
@@ -17,6 +18,7 @@ impl ActionSymbol {
     }
 
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(name: &str) -> Self {
         Self(Name::new(name))
     }
@@ -27,7 +29,7 @@ impl ActionSymbol {
     }
 }
 
-impl<'a, T> From<T> for ActionSymbol
+impl<T> From<T> for ActionSymbol
 where
     T: Into<Name>,
 {
@@ -59,3 +61,9 @@ impl Deref for ActionSymbol {
         &self.0
     }
 }
+
+impl Display for ActionSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
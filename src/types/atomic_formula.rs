@@ -1,6 +1,8 @@
 //! Contains atomic formulae via the [`AtomicFormula`] type.
 
+use crate::types::display::write_each;
 use crate::types::Predicate;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// An atomic formula.
@@ -14,7 +16,7 @@ pub enum AtomicFormula<T> {
     Predicate(PredicateAtomicFormula<T>),
 }
 
-impl<'a, T> AtomicFormula<T> {
+impl<T> AtomicFormula<T> {
     pub const fn new_equality(first: T, second: T) -> Self {
         Self::Equality(EqualityAtomicFormula::new(first, second))
     }
@@ -29,14 +31,14 @@ impl<'a, T> AtomicFormula<T> {
 
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct EqualityAtomicFormula<T> {
-    first: T,
-    second: T,
+    pub(crate) first: T,
+    pub(crate) second: T,
 }
 
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct PredicateAtomicFormula<T> {
-    predicate: Predicate,
-    values: Vec<T>,
+    pub(crate) predicate: Predicate,
+    pub(crate) values: Vec<T>,
 }
 
 impl<T> EqualityAtomicFormula<T> {
@@ -71,13 +73,13 @@ impl<T> PredicateAtomicFormula<T> {
     }
 }
 
-impl<'a, T> From<EqualityAtomicFormula<T>> for AtomicFormula<T> {
+impl<T> From<EqualityAtomicFormula<T>> for AtomicFormula<T> {
     fn from(value: EqualityAtomicFormula<T>) -> Self {
         AtomicFormula::Equality(value)
     }
 }
 
-impl<'a, T> From<PredicateAtomicFormula<T>> for AtomicFormula<T> {
+impl<T> From<PredicateAtomicFormula<T>> for AtomicFormula<T> {
     fn from(value: PredicateAtomicFormula<T>) -> Self {
         AtomicFormula::Predicate(value)
     }
@@ -89,16 +91,39 @@ impl<T> From<(T, T)> for EqualityAtomicFormula<T> {
     }
 }
 
-impl<'a, T> From<(Predicate, Vec<T>)> for PredicateAtomicFormula<T> {
+impl<T> From<(Predicate, Vec<T>)> for PredicateAtomicFormula<T> {
     fn from(value: (Predicate, Vec<T>)) -> Self {
         PredicateAtomicFormula::new(value.0, value.1)
     }
 }
 
-impl<'a, T> Deref for PredicateAtomicFormula<T> {
+impl<T> Deref for PredicateAtomicFormula<T> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
         self.values()
     }
 }
+
+impl<T: Display> Display for AtomicFormula<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AtomicFormula::Equality(af) => write!(f, "{af}"),
+            AtomicFormula::Predicate(af) => write!(f, "{af}"),
+        }
+    }
+}
+
+impl<T: Display> Display for EqualityAtomicFormula<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(= {} {})", self.first, self.second)
+    }
+}
+
+impl<T: Display> Display for PredicateAtomicFormula<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}", self.predicate)?;
+        write_each(f, &self.values)?;
+        write!(f, ")")
+    }
+}
@@ -1,7 +1,11 @@
 //! Contains atomic formula skeletons via the [`AtomicFormulaSkeleton`] type.
 
+use crate::types::NodeSpan;
 use crate::types::Predicate;
 use crate::types::{Name, TypedVariables};
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
+use std::fmt::{Display, Formatter};
 
 /// An atomic formula skeleton.
 ///
@@ -9,8 +13,9 @@ use crate::types::{Name, TypedVariables};
 /// Used by [`PredicateDefinitions`](crate::PredicateDefinitions) and [`DerivedPredicate`](crate::DerivedPredicate).
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct AtomicFormulaSkeleton {
-    predicate: Predicate,
-    variables: TypedVariables,
+    pub(crate) predicate: Predicate,
+    pub(crate) variables: TypedVariables,
+    pub(crate) span: NodeSpan,
 }
 
 impl AtomicFormulaSkeleton {
@@ -18,6 +23,7 @@ impl AtomicFormulaSkeleton {
         Self {
             predicate,
             variables: formula,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -41,3 +47,25 @@ impl From<(Predicate, TypedVariables)> for AtomicFormulaSkeleton {
         AtomicFormulaSkeleton::new(value.0, value.1)
     }
 }
+
+impl Display for AtomicFormulaSkeleton {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.variables.is_empty() {
+            write!(f, "({})", self.predicate)
+        } else {
+            write!(f, "({} {})", self.predicate, self.variables)
+        }
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for AtomicFormulaSkeleton {
+    fn span(&self) -> Option<SourceSpan> {
+        self.span.0
+    }
+
+    fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = NodeSpan(Some(span));
+        self
+    }
+}
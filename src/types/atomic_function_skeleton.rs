@@ -2,6 +2,7 @@
 
 use crate::types::Typed;
 use crate::types::{FunctionSymbol, TypedVariables, Variable};
+use std::fmt::{Display, Formatter};
 
 /// A numeric fluent, similar to a predicate, is a variable which applies to zero or more objects
 /// and maintains a value throughout the duration of the plan.
@@ -33,9 +34,9 @@ use crate::types::{FunctionSymbol, TypedVariables, Variable};
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct AtomicFunctionSkeleton {
     /// The name of the fluent, e.g. `battery-level`.
-    symbol: FunctionSymbol,
+    pub(crate) symbol: FunctionSymbol,
     /// The list of parameters to the fluent, e.g. `?r - rover`.
-    variables: TypedVariables,
+    pub(crate) variables: TypedVariables,
 }
 
 impl AtomicFunctionSkeleton {
@@ -65,3 +66,13 @@ impl From<(FunctionSymbol, Vec<Typed<Variable>>)> for AtomicFunctionSkeleton {
         AtomicFunctionSkeleton::new(value.0, value.1.into())
     }
 }
+
+impl Display for AtomicFunctionSkeleton {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.variables.is_empty() {
+            write!(f, "({})", self.symbol)
+        } else {
+            write!(f, "({} {})", self.symbol, self.variables)
+        }
+    }
+}
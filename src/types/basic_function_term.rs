@@ -1,11 +1,13 @@
 //! Contains the [`BasicFunctionTerm`] type.
 
+use crate::types::display::write_each;
 use crate::types::{FunctionSymbol, Name};
+use std::fmt::{Display, Formatter};
 
 /// ## Usage
 /// Used by [`InitElement`](crate::InitElement).
 #[derive(Debug, Clone, PartialEq)]
-pub struct BasicFunctionTerm(FunctionSymbol, Vec<Name>);
+pub struct BasicFunctionTerm(pub(crate) FunctionSymbol, pub(crate) Vec<Name>);
 
 impl BasicFunctionTerm {
     pub fn new<N: IntoIterator<Item = Name>>(symbol: FunctionSymbol, names: N) -> Self {
@@ -34,3 +36,11 @@ impl AsRef<[Name]> for BasicFunctionTerm {
         self.names()
     }
 }
+
+impl Display for BasicFunctionTerm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}", self.0)?;
+        write_each(f, &self.1)?;
+        write!(f, ")")
+    }
+}
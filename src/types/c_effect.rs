@@ -2,6 +2,7 @@
 
 use crate::types::TypedVariables;
 use crate::types::{ConditionalEffect, Effects, GoalDefinition, PEffect};
+use std::fmt::{Display, Formatter};
 
 /// A (potentially conditional) effect. Occurs as part of [`Effects`].
 ///
@@ -151,3 +152,25 @@ impl TryInto<ForallCEffect> for CEffect {
         }
     }
 }
+
+impl Display for CEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CEffect::Effect(effect) => write!(f, "{effect}"),
+            CEffect::Forall(effect) => write!(f, "{effect}"),
+            CEffect::When(effect) => write!(f, "{effect}"),
+        }
+    }
+}
+
+impl Display for ForallCEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(forall ({}) {})", self.variables, self.effects)
+    }
+}
+
+impl Display for WhenCEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(when {} {})", self.condition, self.effect)
+    }
+}
@@ -1,6 +1,8 @@
 //! Contains the conditional problem/goal definition types [`ConGD`] and [`Con2GD`].
 
+use crate::types::display::write_each;
 use crate::types::{GoalDefinition, Number, TypedVariables};
+use std::fmt::{Display, Formatter};
 
 /// ## Usage
 /// Used by [`ConGD`](ConGD) itself, as well as [`PrefConGD`](crate::types::PrefConGD) and [`Con2GD`](Con2GD).
@@ -132,3 +134,35 @@ impl From<GoalDefinition> for Con2GD {
         Con2GD::new_goal(value)
     }
 }
+
+impl Display for ConGD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConGD::And(values) => {
+                write!(f, "(and")?;
+                write_each(f, values)?;
+                write!(f, ")")
+            }
+            ConGD::Forall(vars, gd) => write!(f, "(forall ({vars}) {gd})"),
+            ConGD::AtEnd(gd) => write!(f, "(at end {gd})"),
+            ConGD::Always(gd) => write!(f, "(always {gd})"),
+            ConGD::Sometime(gd) => write!(f, "(sometime {gd})"),
+            ConGD::Within(n, gd) => write!(f, "(within {n} {gd})"),
+            ConGD::AtMostOnce(gd) => write!(f, "(at-most-once {gd})"),
+            ConGD::SometimeAfter(a, b) => write!(f, "(sometime-after {a} {b})"),
+            ConGD::SometimeBefore(a, b) => write!(f, "(sometime-before {a} {b})"),
+            ConGD::AlwaysWithin(n, a, b) => write!(f, "(always-within {n} {a} {b})"),
+            ConGD::HoldDuring(from, to, gd) => write!(f, "(hold-during {from} {to} {gd})"),
+            ConGD::HoldAfter(n, gd) => write!(f, "(hold-after {n} {gd})"),
+        }
+    }
+}
+
+impl Display for Con2GD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Con2GD::Goal(gd) => write!(f, "{gd}"),
+            Con2GD::Nested(gd) => write!(f, "{gd}"),
+        }
+    }
+}
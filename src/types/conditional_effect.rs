@@ -1,7 +1,9 @@
 //! Contains conditional effects via the [`ConditionalEffect`] type.
 
+use crate::types::display::write_each;
 use crate::types::iterators::FlatteningIntoIterator;
 use crate::types::PEffect;
+use std::fmt::{Display, Formatter};
 
 /// A conditional effect as used by [`CEffect::When`](crate::types::CEffect::When) and [`TimedEffect::Conditional`](crate::types::TimedEffect::Conditional).
 ///
@@ -54,6 +56,19 @@ impl FromIterator<PEffect> for ConditionalEffect {
     }
 }
 
+impl Display for ConditionalEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConditionalEffect::Single(effect) => write!(f, "{effect}"),
+            ConditionalEffect::All(effects) => {
+                write!(f, "(and")?;
+                write_each(f, effects)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
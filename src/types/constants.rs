@@ -1,6 +1,7 @@
 //! Provides constant definitions via the [`Constants`] type.
 
 use crate::types::{Name, Typed, TypedNames};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A set of constants.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Domain`](crate::Domain).
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct Constants(TypedNames);
+pub struct Constants(pub(crate) TypedNames);
 
 impl Constants {
     pub const fn new(predicates: TypedNames) -> Self {
@@ -35,3 +36,13 @@ impl FromIterator<Typed<Name>> for Constants {
         Constants::new(TypedNames::from_iter(iter))
     }
 }
+
+impl Display for Constants {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "(:constants)")
+        } else {
+            write!(f, "(:constants {})", self.0)
+        }
+    }
+}
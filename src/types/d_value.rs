@@ -1,6 +1,7 @@
 //! Contains the [`DurationValue`] type.
 
 use crate::types::{FExp, Number};
+use std::fmt::{Display, Formatter};
 
 /// A duration value, either a [`Number`] or an [`FExp`](FExp).
 ///
@@ -37,3 +38,12 @@ impl From<FExp> for DurationValue {
         Self::FExp(value)
     }
 }
+
+impl Display for DurationValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurationValue::Number(n) => write!(f, "{n}"),
+            DurationValue::FExp(exp) => write!(f, "{exp}"),
+        }
+    }
+}
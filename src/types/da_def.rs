@@ -1,9 +1,13 @@
 //! Contains durative action definitions via the [`DurativeActionDefinition`] type.
 
+use crate::types::NodeSpan;
 use crate::types::TypedVariables;
 use crate::types::{
     DurationConstraint, DurativeActionEffect, DurativeActionGoalDefinition, DurativeActionSymbol,
 };
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
+use std::fmt::{Display, Formatter};
 
 /// A durative action represents an action which takes an amount
 /// of time to complete. The amount of time is expressible as either a value or as
@@ -25,11 +29,12 @@ use crate::types::{
 /// Used by [`StructureDef`](crate::StructureDef).
 #[derive(Debug, Clone, PartialEq)]
 pub struct DurativeActionDefinition {
-    symbol: DurativeActionSymbol,
-    parameters: TypedVariables,
-    duration: Option<DurationConstraint>,
-    condition: Option<DurativeActionGoalDefinition>,
-    effect: Option<DurativeActionEffect>,
+    pub(crate) symbol: DurativeActionSymbol,
+    pub(crate) parameters: TypedVariables,
+    pub(crate) duration: Option<DurationConstraint>,
+    pub(crate) condition: Option<DurativeActionGoalDefinition>,
+    pub(crate) effect: Option<DurativeActionEffect>,
+    pub(crate) span: NodeSpan,
 }
 
 impl DurativeActionDefinition {
@@ -46,6 +51,7 @@ impl DurativeActionDefinition {
             duration,
             condition,
             effect,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -144,3 +150,34 @@ impl AsRef<Option<DurativeActionEffect>> for DurativeActionDefinition {
         self.effect()
     }
 }
+
+impl Display for DurativeActionDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:durative-action {}", self.symbol)?;
+        write!(f, "\n  :parameters ({})", self.parameters)?;
+        match &self.duration {
+            Some(duration) => write!(f, "\n  :duration {duration}")?,
+            None => write!(f, "\n  :duration ()")?,
+        }
+        match &self.condition {
+            Some(condition) => write!(f, "\n  :condition {condition}")?,
+            None => write!(f, "\n  :condition ()")?,
+        }
+        match &self.effect {
+            Some(effect) => write!(f, "\n  :effect {effect})"),
+            None => write!(f, "\n  :effect ())"),
+        }
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for DurativeActionDefinition {
+    fn span(&self) -> Option<SourceSpan> {
+        self.span.0
+    }
+
+    fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = NodeSpan(Some(span));
+        self
+    }
+}
@@ -1,7 +1,9 @@
 //! Contains durative action effects via the [`DurativeActionEffect`] type.
 
+use crate::types::display::write_each;
 use crate::types::TypedVariables;
 use crate::types::{DurativeActionGoalDefinition, TimedEffect};
+use std::fmt::{Display, Formatter};
 
 /// A durative action effect used in [`DurativeActionDefinition`](crate::types::DurativeActionDefinition).
 ///
@@ -58,3 +60,18 @@ impl From<(DurativeActionGoalDefinition, TimedEffect)> for DurativeActionEffect
         Self::new_when(value.0, value.1)
     }
 }
+
+impl Display for DurativeActionEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurativeActionEffect::Timed(effect) => write!(f, "{effect}"),
+            DurativeActionEffect::All(effects) => {
+                write!(f, "(and")?;
+                write_each(f, effects)?;
+                write!(f, ")")
+            }
+            DurativeActionEffect::Forall(vars, effect) => write!(f, "(forall ({vars}) {effect})"),
+            DurativeActionEffect::When(gd, effect) => write!(f, "(when {gd} {effect})"),
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Contains durative action goal definitions via the [`DurativeActionGoalDefinition`] type.
 
+use crate::types::display::write_each;
 use crate::types::PrefTimedGD;
 use crate::types::TypedVariables;
+use std::fmt::{Display, Formatter};
 
 /// A durative action goal definition.
 ///
@@ -34,3 +36,17 @@ impl From<PrefTimedGD> for DurativeActionGoalDefinition {
         DurativeActionGoalDefinition::new_timed(value)
     }
 }
+
+impl Display for DurativeActionGoalDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurativeActionGoalDefinition::Timed(gd) => write!(f, "{gd}"),
+            DurativeActionGoalDefinition::And(gds) => {
+                write!(f, "(and")?;
+                write_each(f, gds)?;
+                write!(f, ")")
+            }
+            DurativeActionGoalDefinition::Forall(vars, gd) => write!(f, "(forall ({vars}) {gd})"),
+        }
+    }
+}
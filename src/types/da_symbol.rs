@@ -1,6 +1,7 @@
 //! Contains the [`DurativeActionSymbol`] type.
 
 use crate::types::Name;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A durative action symbol.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`DurativeActionDefinition`](crate::DurativeActionDefinition).
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
-pub struct DurativeActionSymbol(Name);
+pub struct DurativeActionSymbol(pub(crate) Name);
 
 impl DurativeActionSymbol {
     #[inline(always)]
@@ -17,6 +18,7 @@ impl DurativeActionSymbol {
     }
 
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(name: &str) -> Self {
         Self(Name::new(name))
     }
@@ -27,7 +29,7 @@ impl DurativeActionSymbol {
     }
 }
 
-impl<'a, T> From<T> for DurativeActionSymbol
+impl<T> From<T> for DurativeActionSymbol
 where
     T: Into<Name>,
 {
@@ -59,3 +61,9 @@ impl Deref for DurativeActionSymbol {
         &self.0
     }
 }
+
+impl Display for DurativeActionSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,17 +1,25 @@
 //! Contains derived predicates via the [`DerivedPredicate`] type.
 
+use crate::types::NodeSpan;
 use crate::types::{AtomicFormulaSkeleton, GoalDefinition};
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
+use std::fmt::{Display, Formatter};
 
 /// A derived predicate.
 ///
 /// ## Usage
 /// Used by [`StructureDef`](crate::StructureDef).
 #[derive(Debug, Clone, PartialEq)]
-pub struct DerivedPredicate(AtomicFormulaSkeleton, GoalDefinition);
+pub struct DerivedPredicate(
+    pub(crate) AtomicFormulaSkeleton,
+    pub(crate) GoalDefinition,
+    pub(crate) NodeSpan,
+);
 
 impl DerivedPredicate {
     pub const fn new(formula: AtomicFormulaSkeleton, gd: GoalDefinition) -> Self {
-        Self(formula, gd)
+        Self(formula, gd, NodeSpan::NONE)
     }
 
     pub const fn predicate(&self) -> &AtomicFormulaSkeleton {
@@ -40,3 +48,21 @@ impl AsRef<GoalDefinition> for DerivedPredicate {
         self.expression()
     }
 }
+
+impl Display for DerivedPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:derived {} {})", self.0, self.1)
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for DerivedPredicate {
+    fn span(&self) -> Option<SourceSpan> {
+        self.2 .0
+    }
+
+    fn with_span(mut self, span: SourceSpan) -> Self {
+        self.2 = NodeSpan(Some(span));
+        self
+    }
+}
@@ -0,0 +1,359 @@
+//! Contains the dependencies between derived predicates via the [`DerivedPredicateGraph`] type.
+
+use crate::types::{AtomicFormula, GoalDefinition, Literal, Name, StructureDef, StructureDefs};
+use std::collections::HashMap;
+
+/// The dependencies between the derived predicates of a domain.
+///
+/// A derived predicate depends on each derived predicate used in one of its definitions.
+/// The dependency is negative if the predicate is used under a negation, including the
+/// antecedent of an implication. Predicates without a `:derived` definition are not part
+/// of the graph. Predicate names are compared like [`Name`](crate::Name) compares them.
+///
+/// Derived predicates can only be evaluated if they are stratified, i.e. if no derived
+/// predicate depends negatively on itself, directly or indirectly. The
+/// [`strata`](Self::strata) then group the predicates so that each one only depends on
+/// predicates of the same or earlier strata, and negatively only on predicates of earlier
+/// strata.
+///
+/// ## Example
+/// ```
+/// # use pddl::{DerivedPredicateGraph, Domain, Name, Parser};
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :derived-predicates :negative-preconditions)
+///     (:predicates (edge ?x ?y) (path ?x ?y) (unreachable ?x ?y))
+///     (:derived (path ?x ?y) (edge ?x ?y))
+///     (:derived (path ?x ?y) (exists (?z) (and (edge ?x ?z) (path ?z ?y))))
+///     (:derived (unreachable ?x ?y) (not (path ?x ?y))))"#).unwrap();
+/// let graph = DerivedPredicateGraph::new(domain.structure());
+///
+/// assert!(graph.is_recursive(&"path".into()));
+/// assert_eq!(graph.negative_dependencies(&"unreachable".into()), [&Name::from("path")]);
+/// assert_eq!(graph.strata().unwrap(), [
+///     vec![&Name::from("path")],
+///     vec![&Name::from("unreachable")],
+/// ]);
+/// ```
+///
+/// ## Usage
+/// Built from the [`StructureDefs`] of a [`Domain`](crate::Domain).
+#[derive(Debug, Clone)]
+pub struct DerivedPredicateGraph {
+    /// The derived predicates in order of their first definition.
+    names: Vec<Name>,
+    /// The index of each derived predicate.
+    ids: HashMap<Name, usize>,
+    /// The derived predicates used by the definitions of each derived predicate, along with
+    /// whether they are used negatively.
+    dependencies: Vec<Vec<(usize, bool)>>,
+}
+
+impl DerivedPredicateGraph {
+    /// Collects the dependencies between the derived predicates defined in the `structure`.
+    pub fn new(structure: &StructureDefs) -> Self {
+        let mut graph = Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            dependencies: Vec::new(),
+        };
+        let derived: Vec<_> = structure
+            .iter()
+            .filter_map(|def| match def {
+                StructureDef::Derived(derived) => Some(derived),
+                _ => None,
+            })
+            .collect();
+
+        for derived in &derived {
+            let name = derived.predicate().name();
+            if !graph.ids.contains_key(name) {
+                graph.ids.insert(name.clone(), graph.names.len());
+                graph.names.push(name.clone());
+                graph.dependencies.push(Vec::new());
+            }
+        }
+
+        for derived in derived {
+            let id = graph.ids[derived.predicate().name()];
+            let mut dependencies = std::mem::take(&mut graph.dependencies[id]);
+            graph.collect(derived.expression(), false, &mut dependencies);
+            graph.dependencies[id] = dependencies;
+        }
+        graph
+    }
+
+    /// Adds the derived predicates used by the goal to the `dependencies`.
+    fn collect(&self, gd: &GoalDefinition, negated: bool, dependencies: &mut Vec<(usize, bool)>) {
+        let (af, negated) = match gd {
+            GoalDefinition::AtomicFormula(af)
+            | GoalDefinition::Literal(Literal::AtomicFormula(af)) => (af, negated),
+            GoalDefinition::Literal(Literal::NotAtomicFormula(af)) => (af, !negated),
+            GoalDefinition::And(gds) | GoalDefinition::Or(gds) => {
+                for gd in gds {
+                    self.collect(gd, negated, dependencies);
+                }
+                return;
+            }
+            GoalDefinition::Not(gd) => return self.collect(gd, !negated, dependencies),
+            GoalDefinition::Imply(antecedent, consequent) => {
+                self.collect(antecedent, !negated, dependencies);
+                return self.collect(consequent, negated, dependencies);
+            }
+            GoalDefinition::Exists(_, gd) | GoalDefinition::ForAll(_, gd) => {
+                return self.collect(gd, negated, dependencies)
+            }
+            GoalDefinition::FComp(_) => return,
+        };
+
+        if let AtomicFormula::Predicate(af) = af {
+            if let Some(&id) = self.ids.get(af.predicate()) {
+                if !dependencies.contains(&(id, negated)) {
+                    dependencies.push((id, negated));
+                }
+            }
+        }
+    }
+
+    /// Gets the derived predicates in order of their first definition.
+    pub fn predicates(&self) -> impl Iterator<Item = &Name> {
+        self.names.iter()
+    }
+
+    /// Determines whether the predicate has a `:derived` definition.
+    pub fn contains(&self, predicate: &Name) -> bool {
+        self.ids.contains_key(predicate)
+    }
+
+    /// Gets the derived predicates used by the definitions of the predicate.
+    pub fn dependencies(&self, predicate: &Name) -> Vec<&Name> {
+        self.filter_dependencies(predicate, |_| true)
+    }
+
+    /// Gets the derived predicates used negatively by the definitions of the predicate.
+    pub fn negative_dependencies(&self, predicate: &Name) -> Vec<&Name> {
+        self.filter_dependencies(predicate, |negated| negated)
+    }
+
+    fn filter_dependencies(&self, predicate: &Name, f: impl Fn(bool) -> bool) -> Vec<&Name> {
+        let Some(&id) = self.ids.get(predicate) else {
+            return Vec::new();
+        };
+        let mut dependencies = Vec::new();
+        for &(dependency, negated) in &self.dependencies[id] {
+            let name = &self.names[dependency];
+            if f(negated) && !dependencies.contains(&name) {
+                dependencies.push(name);
+            }
+        }
+        dependencies
+    }
+
+    /// Determines whether the predicate depends on itself, directly or indirectly.
+    pub fn is_recursive(&self, predicate: &Name) -> bool {
+        let Some(&id) = self.ids.get(predicate) else {
+            return false;
+        };
+        self.components()
+            .iter()
+            .find(|component| component.contains(&id))
+            .map_or(false, |component| self.is_cyclic(component))
+    }
+
+    /// Gets the groups of derived predicates that depend negatively on themselves, in
+    /// order of their first definition.
+    ///
+    /// Each group is a strongly connected component of the graph, i.e. each of its
+    /// predicates depends on every other predicate in the group. The domain is stratified
+    /// if there are no such groups.
+    pub fn negative_cycles(&self) -> Vec<Vec<&Name>> {
+        let mut cycles: Vec<_> = self
+            .components()
+            .into_iter()
+            .filter(|component| {
+                component.iter().any(|&id| {
+                    self.dependencies[id]
+                        .iter()
+                        .any(|&(dependency, negated)| negated && component.contains(&dependency))
+                })
+            })
+            .collect();
+        cycles.sort_unstable_by_key(|component| component[0]);
+        cycles
+            .into_iter()
+            .map(|component| component.into_iter().map(|id| &self.names[id]).collect())
+            .collect()
+    }
+
+    /// Gets the strata of the derived predicates, or `None` if there are
+    /// [negative cycles](Self::negative_cycles).
+    ///
+    /// Each derived predicate is placed in the lowest stratum such that it only depends on
+    /// predicates of the same or earlier strata, and negatively only on predicates of
+    /// earlier strata. The predicates of each stratum are ordered by their first definition.
+    pub fn strata(&self) -> Option<Vec<Vec<&Name>>> {
+        let mut strata = vec![0; self.names.len()];
+        for component in self.components() {
+            let mut stratum = 0;
+            for &id in &component {
+                for &(dependency, negated) in &self.dependencies[id] {
+                    if !component.contains(&dependency) {
+                        stratum = stratum.max(strata[dependency] + usize::from(negated));
+                    } else if negated {
+                        return None;
+                    }
+                }
+            }
+            for id in component {
+                strata[id] = stratum;
+            }
+        }
+
+        let mut result: Vec<Vec<&Name>> = Vec::new();
+        for (id, &stratum) in strata.iter().enumerate() {
+            if result.len() <= stratum {
+                result.resize_with(stratum + 1, Vec::new);
+            }
+            result[stratum].push(&self.names[id]);
+        }
+        Some(result)
+    }
+
+    /// Determines whether the strongly connected component contains a cycle.
+    fn is_cyclic(&self, component: &[usize]) -> bool {
+        component.len() > 1
+            || self.dependencies[component[0]]
+                .iter()
+                .any(|&(dependency, _)| dependency == component[0])
+    }
+
+    /// Gets the strongly connected components, each sorted by index, such that every
+    /// component only depends on itself and earlier components.
+    fn components(&self) -> Vec<Vec<usize>> {
+        let mut tarjan = Tarjan {
+            graph: self,
+            index: vec![None; self.names.len()],
+            low: vec![0; self.names.len()],
+            stack: Vec::new(),
+            on_stack: vec![false; self.names.len()],
+            next: 0,
+            components: Vec::new(),
+        };
+        for id in 0..self.names.len() {
+            if tarjan.index[id].is_none() {
+                tarjan.visit(id);
+            }
+        }
+        tarjan.components
+    }
+}
+
+/// The state of Tarjan's algorithm for strongly connected components.
+struct Tarjan<'a> {
+    graph: &'a DerivedPredicateGraph,
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+    next: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, id: usize) {
+        self.index[id] = Some(self.next);
+        self.low[id] = self.next;
+        self.next += 1;
+        self.stack.push(id);
+        self.on_stack[id] = true;
+
+        for &(dependency, _) in &self.graph.dependencies[id] {
+            match self.index[dependency] {
+                None => {
+                    self.visit(dependency);
+                    self.low[id] = self.low[id].min(self.low[dependency]);
+                }
+                Some(index) if self.on_stack[dependency] => {
+                    self.low[id] = self.low[id].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low[id]) == self.index[id] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == id {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            self.components.push(component);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::{Domain, Parser};
+
+    fn names(names: &[&str]) -> Vec<Name> {
+        names.iter().map(|&name| Name::from(name)).collect()
+    }
+
+    fn owned(groups: Vec<Vec<&Name>>) -> Vec<Vec<Name>> {
+        groups
+            .into_iter()
+            .map(|group| group.into_iter().cloned().collect())
+            .collect()
+    }
+
+    #[test]
+    fn stratifies_by_negative_dependencies() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:predicates (p) (a) (b) (c) (d) (e))
+                (:derived (a) (and (p) (not (b))))
+                (:derived (b) (imply (c) (p)))
+                (:derived (c) (or (d) (C)))
+                (:derived (d) (c))
+                (:derived (e) (p)))"#,
+        )
+        .unwrap();
+        let graph = DerivedPredicateGraph::new(domain.structure());
+
+        assert_eq!(graph.predicates().count(), 5);
+        assert!(!graph.contains(&"p".into()));
+        assert_eq!(graph.dependencies(&"a".into()), [&Name::from("b")]);
+        assert_eq!(graph.negative_dependencies(&"b".into()), [&Name::from("c")]);
+        assert!(graph.is_recursive(&"c".into()));
+        assert!(graph.is_recursive(&"d".into()));
+        assert!(!graph.is_recursive(&"a".into()));
+        assert!(graph.negative_cycles().is_empty());
+        assert_eq!(
+            owned(graph.strata().unwrap()),
+            [names(&["c", "d", "e"]), names(&["b"]), names(&["a"])]
+        );
+    }
+
+    #[test]
+    fn reports_negative_cycles() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:predicates (p) (a) (b) (c))
+                (:derived (a) (not (b)))
+                (:derived (b) (a))
+                (:derived (c) (and (p) (not (c)))))"#,
+        )
+        .unwrap();
+        let graph = DerivedPredicateGraph::new(domain.structure());
+
+        assert!(graph.strata().is_none());
+        assert_eq!(
+            owned(graph.negative_cycles()),
+            [names(&["a", "b"]), names(&["c"])]
+        );
+    }
+}
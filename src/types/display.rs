@@ -0,0 +1,69 @@
+//! Helpers for printing types back to their PDDL representation.
+
+use crate::types::Type;
+use std::fmt::{Display, Formatter};
+
+/// Writes each element preceded by a single space, e.g. ` a b c`.
+///
+/// This is the shape of most argument lists following an operator or symbol,
+/// such as `(p a b c)` or `(and a b c)`.
+pub(crate) fn write_each<T: Display>(f: &mut Formatter<'_>, values: &[T]) -> std::fmt::Result {
+    for value in values {
+        write!(f, " {value}")?;
+    }
+    Ok(())
+}
+
+/// Writes a typed list, e.g. `a b - t c d - u e`.
+///
+/// Consecutive elements of the same type share a single type annotation. Since the
+/// parser only accepts implicitly typed elements at the end of a list, the
+/// `implicit` type is only omitted for the trailing group.
+pub(crate) fn write_typed_list<'a, T: Display + 'a>(
+    f: &mut Formatter<'_>,
+    values: &[(&'a T, &'a Type)],
+    implicit: &Type,
+) -> std::fmt::Result {
+    let mut start = 0;
+    while start < values.len() {
+        let r#type = values[start].1;
+        let end = values[start..]
+            .iter()
+            .position(|(_, t)| *t != r#type)
+            .map_or(values.len(), |offset| start + offset);
+
+        if start > 0 {
+            write!(f, " ")?;
+        }
+
+        for (i, (value, _)) in values[start..end].iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{value}")?;
+        }
+
+        if end < values.len() || r#type != implicit {
+            write!(f, " - {type}")?;
+        }
+
+        start = end;
+    }
+    Ok(())
+}
+
+/// Writes the value with every line after the first indented by `indent`.
+pub(crate) fn write_indented<T: Display>(
+    f: &mut Formatter<'_>,
+    value: &T,
+    indent: &str,
+) -> std::fmt::Result {
+    let value = value.to_string();
+    for (i, line) in value.lines().enumerate() {
+        if i > 0 {
+            write!(f, "\n{indent}")?;
+        }
+        write!(f, "{line}")?;
+    }
+    Ok(())
+}
@@ -1,10 +1,15 @@
 //! Contains the [`Domain`] type.
 
+use crate::types::display::{write_each, write_indented};
+use crate::types::NodeSpan;
 use crate::types::{
     ConGD, Constants, DomainConstraintsDef, Functions, PredicateDefinitions, Requirements,
     StructureDefs, Timeless,
 };
 use crate::types::{Name, Types};
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
+use std::fmt::{Display, Formatter};
 
 /// The `Domain` type specifies a problem domain in which to plan.
 ///
@@ -62,38 +67,39 @@ use crate::types::{Name, Types};
 #[derive(Debug, Clone, PartialEq)]
 pub struct Domain {
     /// The domain name.
-    name: Name,
-    /// The domain extension list.
+    pub(crate) name: Name,
+    /// The domain extension list, see [`DomainRegistry`](crate::resolve::DomainRegistry).
     // TODO: PDDL 1.2 - deprecated?
-    extends: Vec<Name>,
+    pub(crate) extends: Vec<Name>,
     /// The specified requirements.
-    requirements: Requirements,
+    pub(crate) requirements: Requirements,
     /// The optional type declarations.
     ///
     /// ## Requirements
     /// Requires [Typing](crate::Requirement::Typing).
-    types: Types,
+    pub(crate) types: Types,
     /// The optional constant declarations.
-    constants: Constants,
+    pub(crate) constants: Constants,
     /// The predicate definitions.
-    predicates: PredicateDefinitions,
+    pub(crate) predicates: PredicateDefinitions,
     /// The optional function definitions.
     ///
     /// ## Requirements
     /// Requires [Fluents](crate::Requirement::Fluents).
-    functions: Functions,
+    pub(crate) functions: Functions,
     /// The optional constraint definitions.
     ///
     /// ## Requirements
     /// Requires [Constraints](crate::Requirement::Constraints).
-    constraints: DomainConstraintsDef,
+    pub(crate) constraints: DomainConstraintsDef,
     /// The optional timeless predicate definitions.
     // TODO: PDDL 1.2 - deprecated?
-    timeless: Timeless,
+    pub(crate) timeless: Timeless,
     /// The structure definition, i.e. [action](crate::ActionDefinition),
     /// [durative action](crate::DurativeActionDefinition), and/or
     /// [derived predicate](crate::DerivedPredicate) definitions.
-    structure: StructureDefs,
+    pub(crate) structure: StructureDefs,
+    pub(crate) span: NodeSpan,
 }
 
 impl Domain {
@@ -110,6 +116,7 @@ impl Domain {
             constraints: DomainConstraintsDef::default(),
             timeless: Timeless::default(),
             structure,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -208,6 +215,12 @@ impl Domain {
         self.constraints.value()
     }
 
+    /// Returns the optional timeless facts.
+    /// This is a PDDL 1.2 construct.
+    pub const fn timeless(&self) -> &Timeless {
+        &self.timeless
+    }
+
     /// Returns the domain structure definitions.
     pub const fn structure(&self) -> &StructureDefs {
         &self.structure
@@ -243,3 +256,52 @@ impl AsRef<StructureDefs> for Domain {
         &self.structure
     }
 }
+
+impl Display for Domain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(define (domain {})", self.name)?;
+        if !self.extends.is_empty() {
+            write!(f, "\n  (:extends")?;
+            write_each(f, &self.extends)?;
+            write!(f, ")")?;
+        }
+        if !self.requirements.is_empty() {
+            write!(f, "\n  {}", self.requirements)?;
+        }
+        if !self.types.is_empty() {
+            write!(f, "\n  {}", self.types)?;
+        }
+        if !self.constants.is_empty() {
+            write!(f, "\n  {}", self.constants)?;
+        }
+        if !self.predicates.is_empty() {
+            write!(f, "\n  {}", self.predicates)?;
+        }
+        if !self.timeless.is_empty() {
+            write!(f, "\n  {}", self.timeless)?;
+        }
+        if !self.functions.is_empty() {
+            write!(f, "\n  {}", self.functions)?;
+        }
+        if *self.constraints != ConGD::default() {
+            write!(f, "\n  {}", self.constraints)?;
+        }
+        for def in self.structure.iter() {
+            write!(f, "\n  ")?;
+            write_indented(f, def, "  ")?;
+        }
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for Domain {
+    fn span(&self) -> Option<SourceSpan> {
+        self.span.0
+    }
+
+    fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = NodeSpan(Some(span));
+        self
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains the [`DomainConstraintsDef`] type.
 
 use crate::types::ConGD;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A domain constraints definition; wraps a [`ConGD`].
@@ -11,7 +12,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Domain`](crate::Domain).
 #[derive(Debug, Default, Clone, PartialEq)]
-pub struct DomainConstraintsDef(ConGD);
+pub struct DomainConstraintsDef(pub(crate) ConGD);
 
 impl DomainConstraintsDef {
     pub const fn new(gd: ConGD) -> Self {
@@ -49,3 +50,9 @@ impl From<DomainConstraintsDef> for ConGD {
         val.0
     }
 }
+
+impl Display for DomainConstraintsDef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:constraints {})", self.0)
+    }
+}
@@ -1,7 +1,9 @@
 //! Contains duration constraints via the [`DurationConstraint`] type.
 
+use crate::types::display::write_each;
 use crate::types::iterators::FlatteningIntoIterator;
 use crate::types::SimpleDurationConstraint;
+use std::fmt::{Display, Formatter};
 
 /// ## Usage
 /// Used by [`DurativeActionDefinition`](crate::DurativeActionDefinition).
@@ -63,6 +65,19 @@ impl FromIterator<SimpleDurationConstraint> for DurationConstraint {
     }
 }
 
+impl Display for DurationConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurationConstraint::Single(constraint) => write!(f, "{constraint}"),
+            DurationConstraint::All(constraints) => {
+                write!(f, "(and")?;
+                write_each(f, constraints)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
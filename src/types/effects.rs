@@ -1,6 +1,8 @@
 //! Contains effects via the [`Effects`] type.
 
+use crate::types::display::write_each;
 use crate::types::CEffect;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// An effect. Occurs e.g. in a [`ActionDefinition`](crate::types::ActionDefinition).
@@ -11,7 +13,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`ActionDefinition`](crate::ActionDefinition).
 #[derive(Debug, Clone, PartialEq, Default)]
-pub struct Effects(Vec<CEffect>);
+pub struct Effects(pub(crate) Vec<CEffect>);
 
 impl Effects {
     /// Constructs a new instance from the value.
@@ -38,7 +40,7 @@ impl Effects {
     /// Returns an iterator over the list.
     ///
     /// The iterator yields all items from start to end.
-    pub fn iter(&self) -> std::slice::Iter<CEffect> {
+    pub fn iter(&self) -> std::slice::Iter<'_, CEffect> {
         self.0.iter()
     }
 
@@ -101,3 +103,16 @@ impl TryInto<CEffect> for Effects {
         self.try_get_single().ok_or(())
     }
 }
+
+impl Display for Effects {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0.as_slice() {
+            [effect] => write!(f, "{effect}"),
+            effects => {
+                write!(f, "(and")?;
+                write_each(f, effects)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
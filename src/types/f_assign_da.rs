@@ -1,6 +1,7 @@
 //! Contains the durative action assignment expressions via the [`FAssignDa`] type.
 
 use crate::types::{AssignOp, FExpDa, FHead};
+use std::fmt::{Display, Formatter};
 
 /// An timed effect assignment operation. Will perform the
 /// specified assignment `at` [`TimeSpecifier`](crate::TimeSpecifier) when
@@ -12,7 +13,7 @@ use crate::types::{AssignOp, FExpDa, FHead};
 /// ## Usage
 /// Used by [`TimedEffect`](crate::TimedEffect).
 #[derive(Debug, Clone, PartialEq)]
-pub struct FAssignDa(AssignOp, FHead, FExpDa);
+pub struct FAssignDa(pub(crate) AssignOp, pub(crate) FHead, pub(crate) FExpDa);
 
 impl FAssignDa {
     pub const fn new(comp: AssignOp, head: FHead, exp: FExpDa) -> Self {
@@ -40,3 +41,9 @@ impl From<(AssignOp, FHead, FExpDa)> for FAssignDa {
         FAssignDa::new(value.0, value.1, value.2)
     }
 }
+
+impl Display for FAssignDa {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} {} {})", self.0, self.1, self.2)
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains function expression comparisons via the [`FComp`] type.
 
 use crate::types::{BinaryComp, FExp};
+use std::fmt::{Display, Formatter};
 
 /// An fluent comparison used as part of a [`GoalDefinition`](crate::GoalDefinition)
 /// when [`NumericFluents`](crate::Requirement::NumericFluents) is allowed.
@@ -8,7 +9,7 @@ use crate::types::{BinaryComp, FExp};
 /// ## Usage
 /// Used by [`GoalDefinition`](crate::GoalDefinition).
 #[derive(Debug, Clone, PartialEq)]
-pub struct FComp(BinaryComp, FExp, FExp);
+pub struct FComp(pub(crate) BinaryComp, pub(crate) FExp, pub(crate) FExp);
 
 impl FComp {
     pub const fn new(comp: BinaryComp, lhs: FExp, rhs: FExp) -> Self {
@@ -36,3 +37,9 @@ impl From<(BinaryComp, FExp, FExp)> for FComp {
         FComp::new(value.0, value.1, value.2)
     }
 }
+
+impl Display for FComp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} {} {})", self.0, self.1, self.2)
+    }
+}
@@ -1,6 +1,8 @@
 //! Contains function expressions via the [`FExp`] type.
 
+use crate::types::display::write_each;
 use crate::types::{BinaryOp, FHead, MultiOp, Number};
+use std::fmt::{Display, Formatter};
 
 /// A function/fluent expression used e.g. in a [`DurationValue`](crate::types::DurationValue).
 ///
@@ -46,3 +48,19 @@ impl FExp {
         Self::Function(f_head)
     }
 }
+
+impl Display for FExp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FExp::Number(n) => write!(f, "{n}"),
+            FExp::Function(head) => write!(f, "{head}"),
+            FExp::Negative(value) => write!(f, "(- {value})"),
+            FExp::BinaryOp(op, lhs, rhs) => write!(f, "({op} {lhs} {rhs})"),
+            FExp::MultiOp(op, lhs, rhs) => {
+                write!(f, "({op} {lhs}")?;
+                write_each(f, rhs)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
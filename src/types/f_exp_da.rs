@@ -1,6 +1,8 @@
 //! Contains durative function expressions via the [`FExpDa`] type.
 
+use crate::types::display::write_each;
 use crate::types::{AssignOp, BinaryOp, FExp, FHead, MultiOp};
+use std::fmt::{Display, Formatter};
 
 /// ## Usage
 /// Used by [`FExpDa`] itself, as well as [`FAssignDa`](crate::FAssignDa).
@@ -43,3 +45,20 @@ impl From<FExp> for FExpDa {
         FExpDa::new_f_exp(value)
     }
 }
+
+impl Display for FExpDa {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FExpDa::Assign(op, head, exp) => write!(f, "({op} {head} {exp})"),
+            FExpDa::BinaryOp(op, lhs, rhs) => write!(f, "({op} {lhs} {rhs})"),
+            FExpDa::MultiOp(op, lhs, rhs) => {
+                write!(f, "({op} {lhs}")?;
+                write_each(f, rhs)?;
+                write!(f, ")")
+            }
+            FExpDa::Negative(value) => write!(f, "(- {value})"),
+            FExpDa::Duration => write!(f, "?duration"),
+            FExpDa::FExp(exp) => write!(f, "{exp}"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains timed function expressions via the [`FExpT`] type.
 
 use crate::types::FExp;
+use std::fmt::{Display, Formatter};
 
 /// An f-exp-t.
 ///
@@ -10,8 +11,9 @@ use crate::types::FExp;
 ///
 /// ## Usage
 /// Used by [`TimedEffect`](crate::TimedEffect).
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum FExpT {
+    #[default]
     Now,
     Scaled(FExp),
 }
@@ -26,14 +28,17 @@ impl FExpT {
     }
 }
 
-impl Default for FExpT {
-    fn default() -> Self {
-        Self::Now
-    }
-}
-
 impl From<FExp> for FExpT {
     fn from(value: FExp) -> Self {
         Self::Scaled(value)
     }
 }
+
+impl Display for FExpT {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FExpT::Now => write!(f, "#t"),
+            FExpT::Scaled(exp) => write!(f, "(* {exp} #t)"),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Contains function declarations via the [`FHead`] type.
 
+use crate::types::display::write_each;
 use crate::types::{FunctionSymbol, Term};
+use std::fmt::{Display, Formatter};
 
 /// A function declaration.
 ///
@@ -25,3 +27,16 @@ impl FHead {
         Self::WithTerms(symbol, terms.into_iter().collect())
     }
 }
+
+impl Display for FHead {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FHead::Simple(symbol) => write!(f, "({symbol})"),
+            FHead::WithTerms(symbol, terms) => {
+                write!(f, "({symbol}")?;
+                write_each(f, terms)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains function symbols via the [`FunctionSymbol`] type..
 
 use crate::types::Name;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A function symbol name.
@@ -9,7 +10,7 @@ use std::ops::Deref;
 /// Used by [`FunctionTerm`](crate::FunctionTerm), [`FHead`](crate::FHead),
 /// [`BasicFunctionTerm`](crate::BasicFunctionTerm) and [`MetricFExp`](crate::MetricFExp).
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
-pub struct FunctionSymbol(Name);
+pub struct FunctionSymbol(pub(crate) Name);
 
 impl FunctionSymbol {
     #[inline(always)]
@@ -18,6 +19,7 @@ impl FunctionSymbol {
     }
 
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(name: &str) -> Self {
         Self(Name::new(name))
     }
@@ -28,7 +30,7 @@ impl FunctionSymbol {
     }
 }
 
-impl<'a, T> From<T> for FunctionSymbol
+impl<T> From<T> for FunctionSymbol
 where
     T: Into<Name>,
 {
@@ -60,3 +62,9 @@ impl Deref for FunctionSymbol {
         &self.0
     }
 }
+
+impl Display for FunctionSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,7 +1,9 @@
 //! Contains function terms via the [`FunctionTerm`] type.
 
+use crate::types::display::write_each;
 use crate::types::term::Term;
 use crate::types::FunctionSymbol;
+use std::fmt::{Display, Formatter};
 
 /// A function term.
 ///
@@ -11,7 +13,7 @@ use crate::types::FunctionSymbol;
 /// ## Usage
 /// Used by [`Term`], and [`PEffect`](crate::PEffect).
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct FunctionTerm(FunctionSymbol, Vec<Term>);
+pub struct FunctionTerm(pub(crate) FunctionSymbol, pub(crate) Vec<Term>);
 
 impl FunctionTerm {
     pub fn new<I: IntoIterator<Item = Term>>(symbol: FunctionSymbol, terms: I) -> Self {
@@ -40,3 +42,11 @@ impl AsRef<Vec<Term>> for FunctionTerm {
         &self.1
     }
 }
+
+impl Display for FunctionTerm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}", self.0)?;
+        write_each(f, &self.1)?;
+        write!(f, ")")
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains function types via the [`FunctionType`] ... type.
 
 use crate::types::{PrimitiveType, Type};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A function type.
@@ -14,7 +15,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`FunctionTyped`](crate::FunctionTyped) in [`FunctionTypedList`](crate::FunctionTypedList).
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct FunctionType(Type);
+pub struct FunctionType(pub(crate) Type);
 
 impl FunctionType {
     pub const NUMBER: FunctionType = FunctionType::from(Type::NUMBER);
@@ -61,3 +62,9 @@ impl Deref for FunctionType {
         &self.0
     }
 }
+
+impl Display for FunctionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
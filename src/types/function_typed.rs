@@ -2,6 +2,7 @@
 
 use crate::types::FunctionType;
 use crate::types::Type;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A typed function element.
@@ -12,7 +13,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`FunctionTypedList`](crate::FunctionTypedList).
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct FunctionTyped<O>(O, FunctionType);
+pub struct FunctionTyped<O>(pub(crate) O, pub(crate) FunctionType);
 
 impl<O> FunctionTyped<O> {
     pub const fn new(value: O, r#type: FunctionType) -> Self {
@@ -49,3 +50,9 @@ impl<O> Deref for FunctionTyped<O> {
         self.value_ref()
     }
 }
+
+impl<O: Display> Display for FunctionTyped<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.0, self.1)
+    }
+}
@@ -1,6 +1,9 @@
 //! Contains typed function elements via the [`FunctionTypedList`] type.
 
+use crate::types::display::write_typed_list;
 use crate::types::function_typed::FunctionTyped;
+use crate::types::Type;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A list of typed elements.
@@ -28,15 +31,15 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Functions`](crate::Functions).
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct FunctionTypedList<T>(Vec<FunctionTyped<T>>);
+pub struct FunctionTypedList<T>(pub(crate) Vec<FunctionTyped<T>>);
 
-impl<'a, T> Default for FunctionTypedList<T> {
+impl<T> Default for FunctionTypedList<T> {
     fn default() -> Self {
         Self(Vec::default())
     }
 }
 
-impl<'a, T> FunctionTypedList<T> {
+impl<T> FunctionTypedList<T> {
     pub const fn new(list: Vec<FunctionTyped<T>>) -> Self {
         Self(list)
     }
@@ -47,19 +50,19 @@ impl<'a, T> FunctionTypedList<T> {
     }
 }
 
-impl<'a, T> From<Vec<FunctionTyped<T>>> for FunctionTypedList<T> {
+impl<T> From<Vec<FunctionTyped<T>>> for FunctionTypedList<T> {
     fn from(iter: Vec<FunctionTyped<T>>) -> Self {
         FunctionTypedList::new(iter)
     }
 }
 
-impl<'a, T> FromIterator<FunctionTyped<T>> for FunctionTypedList<T> {
+impl<T> FromIterator<FunctionTyped<T>> for FunctionTypedList<T> {
     fn from_iter<I: IntoIterator<Item = FunctionTyped<T>>>(iter: I) -> Self {
         FunctionTypedList::new(iter.into_iter().collect())
     }
 }
 
-impl<'a, T> Deref for FunctionTypedList<T> {
+impl<T> Deref for FunctionTypedList<T> {
     type Target = [FunctionTyped<T>];
 
     fn deref(&self) -> &Self::Target {
@@ -67,7 +70,7 @@ impl<'a, T> Deref for FunctionTypedList<T> {
     }
 }
 
-impl<'a, T> PartialEq<Vec<FunctionTyped<T>>> for FunctionTypedList<T>
+impl<T> PartialEq<Vec<FunctionTyped<T>>> for FunctionTypedList<T>
 where
     T: PartialEq,
 {
@@ -76,7 +79,7 @@ where
     }
 }
 
-impl<'a, T> PartialEq<[FunctionTyped<T>]> for FunctionTypedList<T>
+impl<T> PartialEq<[FunctionTyped<T>]> for FunctionTypedList<T>
 where
     T: PartialEq,
 {
@@ -84,3 +87,14 @@ where
         self.0.eq(other)
     }
 }
+
+impl<T: Display> Display for FunctionTypedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<_> = self
+            .0
+            .iter()
+            .map(|t| (t.value_ref(), t.type_ref().deref()))
+            .collect();
+        write_typed_list(f, &values, &Type::NUMBER)
+    }
+}
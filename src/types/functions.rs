@@ -1,6 +1,7 @@
 //! Provides function definitions via the [`Functions`] type.
 
 use crate::types::{AtomicFunctionSkeleton, FunctionTyped, FunctionTypedList};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A set of functions.
@@ -11,7 +12,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Domain`](crate::Domain).
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct Functions(FunctionTypedList<AtomicFunctionSkeleton>);
+pub struct Functions(pub(crate) FunctionTypedList<AtomicFunctionSkeleton>);
 
 impl Functions {
     pub const fn new(functions: FunctionTypedList<AtomicFunctionSkeleton>) -> Self {
@@ -43,3 +44,13 @@ impl FromIterator<FunctionTyped<AtomicFunctionSkeleton>> for Functions {
         Functions::new(FunctionTypedList::from_iter(iter))
     }
 }
+
+impl Display for Functions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "(:functions)")
+        } else {
+            write!(f, "(:functions {})", self.0)
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Contains goal definitions via the [`GoalDefinition`] type.
 
+use crate::types::display::write_each;
 use crate::types::TermLiteral;
-use crate::types::{AtomicFormula, FComp, Term, TypedVariables};
+use crate::types::{AtomicFormula, BinaryComp, FComp, Literal, Term, TypedVariables};
+use std::fmt::{Display, Formatter};
 
 /// A goal definition.
 ///
@@ -49,13 +51,15 @@ impl GoalDefinition {
 
     #[inline(always)]
     pub fn new_and<T: IntoIterator<Item = GoalDefinition>>(values: T) -> Self {
-        // TODO: Flatten `(and (and a b) (and x y))` into `(and a b c y)`.
+        // Nested conjunctions are kept as-is so that parsing stays lossless;
+        // see `simplify` for flattening `(and (and a b) (and x y))` into `(and a b x y)`.
         Self::And(values.into_iter().collect())
     }
 
     #[inline(always)]
     pub fn new_or<T: IntoIterator<Item = GoalDefinition>>(values: T) -> Self {
-        // TODO: Flatten `(or (or a b) (or x y))` into `(or a b c y)`.
+        // Nested disjunctions are kept as-is so that parsing stays lossless;
+        // see `simplify` for flattening `(or (or a b) (or x y))` into `(or a b x y)`.
         Self::Or(values.into_iter().collect())
     }
 
@@ -112,4 +116,312 @@ impl GoalDefinition {
             GoalDefinition::FComp(_) => false,
         }
     }
+
+    /// Converts the goal into its simplified negation normal form.
+    ///
+    /// Implications are replaced by disjunctions, and negations are pushed through
+    /// conjunctions, disjunctions and quantifiers until they only apply to atomic formulas,
+    /// which become negated [`Literal`]s. Negated numeric comparisons are replaced by the
+    /// opposite comparison, except for `(not (= a b))`. The result is then [simplified](Self::simplify).
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{GoalDefinition, Parser};
+    /// let gd = GoalDefinition::from_str("(not (and (p) (imply (q) (forall (?x) (r ?x)))))").unwrap();
+    /// assert_eq!(gd.to_nnf().to_string(), "(or (not (p)) (and (q) (exists (?x) (not (r ?x)))))");
+    /// ```
+    pub fn to_nnf(&self) -> Self {
+        self.nnf(false).simplify()
+    }
+
+    /// Converts the goal into disjunctive normal form and returns its disjuncts.
+    ///
+    /// The goal is converted into its [negation normal form](Self::to_nnf) and conjunctions
+    /// are distributed over disjunctions. Existential quantifiers are distributed over the
+    /// disjunctions of their body, while universal quantifiers are kept as-is since they
+    /// cannot be distributed. Each returned disjunct is [simplified](Self::simplify) and
+    /// thus contains no further disjunctions outside of universal quantifiers or
+    /// `(not (= a b))` comparisons. Duplicate disjuncts are removed, and a trivially false
+    /// goal has no disjuncts at all.
+    ///
+    /// The number of disjuncts may grow exponentially with the size of the goal.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{GoalDefinition, Parser};
+    /// let gd = GoalDefinition::from_str("(and (or (p) (q)) (not (and (r) (s))))").unwrap();
+    /// let disjuncts: Vec<_> = gd.to_dnf().iter().map(ToString::to_string).collect();
+    /// assert_eq!(disjuncts, [
+    ///     "(and (p) (not (r)))",
+    ///     "(and (p) (not (s)))",
+    ///     "(and (q) (not (r)))",
+    ///     "(and (q) (not (s)))",
+    /// ]);
+    /// ```
+    pub fn to_dnf(&self) -> Vec<Self> {
+        let mut disjuncts = Vec::new();
+        for conjuncts in self.to_nnf().dnf() {
+            let disjunct = Self::new_and(conjuncts).simplify();
+            if !disjuncts.contains(&disjunct) {
+                disjuncts.push(disjunct);
+            }
+        }
+        disjuncts
+    }
+
+    /// Builds the disjunctive normal form of a goal in negation normal form
+    /// as a list of conjunctions.
+    fn dnf(self) -> Vec<Vec<Self>> {
+        match self {
+            GoalDefinition::And(values) => {
+                values
+                    .into_iter()
+                    .fold(vec![vec![]], |conjunctions, value| {
+                        let disjuncts = value.dnf();
+                        conjunctions
+                            .iter()
+                            .flat_map(|conjunction| {
+                                disjuncts.iter().map(move |disjunct| {
+                                    conjunction.iter().chain(disjunct).cloned().collect()
+                                })
+                            })
+                            .collect()
+                    })
+            }
+            GoalDefinition::Or(values) => values.into_iter().flat_map(Self::dnf).collect(),
+            GoalDefinition::Exists(variables, value) => value
+                .dnf()
+                .into_iter()
+                .map(|conjuncts| {
+                    vec![Self::new_exists(
+                        variables.clone(),
+                        Self::new_and(conjuncts).simplify(),
+                    )]
+                })
+                .collect(),
+            value => vec![vec![value]],
+        }
+    }
+
+    /// Builds the negation normal form of the goal, or of its negation if `negated` is `true`.
+    fn nnf(&self, negated: bool) -> Self {
+        match self {
+            GoalDefinition::AtomicFormula(af)
+            | GoalDefinition::Literal(Literal::AtomicFormula(af)) => Self::atom(af, negated),
+            GoalDefinition::Literal(Literal::NotAtomicFormula(af)) => Self::atom(af, !negated),
+            GoalDefinition::And(values) | GoalDefinition::Or(values) => {
+                let values = values.iter().map(|value| value.nnf(negated)).collect();
+                if matches!(self, GoalDefinition::And(_)) != negated {
+                    GoalDefinition::And(values)
+                } else {
+                    GoalDefinition::Or(values)
+                }
+            }
+            GoalDefinition::Not(value) => value.nnf(!negated),
+            GoalDefinition::Imply(a, b) => {
+                if negated {
+                    GoalDefinition::And(vec![a.nnf(false), b.nnf(true)])
+                } else {
+                    GoalDefinition::Or(vec![a.nnf(true), b.nnf(false)])
+                }
+            }
+            GoalDefinition::Exists(variables, value) | GoalDefinition::ForAll(variables, value) => {
+                let value = value.nnf(negated);
+                if matches!(self, GoalDefinition::Exists(..)) != negated {
+                    Self::new_exists(variables.clone(), value)
+                } else {
+                    Self::new_forall(variables.clone(), value)
+                }
+            }
+            GoalDefinition::FComp(comp) if negated => {
+                let opposite = match comp.comparison() {
+                    BinaryComp::GreaterThan => BinaryComp::LessThanOrEqual,
+                    BinaryComp::LessThan => BinaryComp::GreaterOrEqual,
+                    BinaryComp::GreaterOrEqual => BinaryComp::LessThan,
+                    BinaryComp::LessThanOrEqual => BinaryComp::GreaterThan,
+                    BinaryComp::Equal => return Self::new_not(self.clone()),
+                };
+                Self::new_f_comp(FComp::new(
+                    opposite,
+                    comp.first().clone(),
+                    comp.second().clone(),
+                ))
+            }
+            GoalDefinition::FComp(_) => self.clone(),
+        }
+    }
+
+    fn atom(af: &AtomicFormula<Term>, negated: bool) -> Self {
+        if negated {
+            Self::new_literal(Literal::new_not(af.clone()))
+        } else {
+            Self::new_atomic_formula(af.clone())
+        }
+    }
+
+    /// Simplifies the goal without changing its meaning.
+    ///
+    /// Nested conjunctions and disjunctions are flattened, trivially true conjuncts such as
+    /// `(and)` are dropped, double negations are removed, and conjunctions or disjunctions
+    /// with a single element are replaced by that element. A disjunction with a trivially
+    /// true disjunct, as well as implications and universal quantifiers with a trivially
+    /// true body, become `(and)`. Empty disjunctions are false and therefore kept, and so
+    /// are existential quantifiers with a trivially true body, which are false if no
+    /// object has the type of their variables.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{GoalDefinition, Parser};
+    /// let gd = GoalDefinition::from_str("(and (and (p) (and)) (or (q) (or (r))) (not (not (s))))").unwrap();
+    /// assert_eq!(gd.simplify().to_string(), "(and (p) (or (q) (r)) (s))");
+    /// ```
+    pub fn simplify(self) -> Self {
+        match self {
+            GoalDefinition::And(values) => {
+                let mut conjuncts = Vec::with_capacity(values.len());
+                for value in values {
+                    match value.simplify() {
+                        GoalDefinition::And(nested) => conjuncts.extend(nested),
+                        value => conjuncts.push(value),
+                    }
+                }
+                Self::single_or(conjuncts, GoalDefinition::And)
+            }
+            GoalDefinition::Or(values) => {
+                let mut disjuncts = Vec::with_capacity(values.len());
+                for value in values {
+                    match value.simplify() {
+                        GoalDefinition::Or(nested) => disjuncts.extend(nested),
+                        value if value.is_true() => return value,
+                        value => disjuncts.push(value),
+                    }
+                }
+                Self::single_or(disjuncts, GoalDefinition::Or)
+            }
+            GoalDefinition::Not(value) => match value.simplify() {
+                GoalDefinition::Not(value) => *value,
+                GoalDefinition::Literal(Literal::NotAtomicFormula(af)) => {
+                    Self::new_atomic_formula(af)
+                }
+                value => Self::new_not(value),
+            },
+            GoalDefinition::Imply(a, b) => {
+                let (a, b) = (a.simplify(), b.simplify());
+                if a.is_true() || b.is_true() {
+                    b
+                } else {
+                    Self::new_imply(a, b)
+                }
+            }
+            GoalDefinition::Exists(variables, value) => {
+                Self::new_exists(variables, value.simplify())
+            }
+            GoalDefinition::ForAll(variables, value) => match value.simplify() {
+                value if value.is_true() => value,
+                value => Self::new_forall(variables, value),
+            },
+            value => value,
+        }
+    }
+
+    /// Returns the single element of `values`, or combines all of them using `combine`.
+    fn single_or(mut values: Vec<Self>, combine: fn(Vec<Self>) -> Self) -> Self {
+        if values.len() == 1 {
+            values.remove(0)
+        } else {
+            combine(values)
+        }
+    }
+
+    /// Returns `true` if the goal is an empty conjunction.
+    pub(crate) fn is_true(&self) -> bool {
+        matches!(self, GoalDefinition::And(values) if values.is_empty())
+    }
+}
+
+impl Display for GoalDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoalDefinition::AtomicFormula(af) => write!(f, "{af}"),
+            GoalDefinition::Literal(literal) => write!(f, "{literal}"),
+            GoalDefinition::And(values) => {
+                write!(f, "(and")?;
+                write_each(f, values)?;
+                write!(f, ")")
+            }
+            GoalDefinition::Or(values) => {
+                write!(f, "(or")?;
+                write_each(f, values)?;
+                write!(f, ")")
+            }
+            GoalDefinition::Not(value) => write!(f, "(not {value})"),
+            GoalDefinition::Imply(a, b) => write!(f, "(imply {a} {b})"),
+            GoalDefinition::Exists(vars, gd) => write!(f, "(exists ({vars}) {gd})"),
+            GoalDefinition::ForAll(vars, gd) => write!(f, "(forall ({vars}) {gd})"),
+            GoalDefinition::FComp(comp) => write!(f, "{comp}"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn nnf(input: &str) -> String {
+        GoalDefinition::from_str(input)
+            .unwrap()
+            .to_nnf()
+            .to_string()
+    }
+
+    fn simplify(input: &str) -> String {
+        GoalDefinition::from_str(input)
+            .unwrap()
+            .simplify()
+            .to_string()
+    }
+
+    #[test]
+    fn to_nnf_pushes_negations_to_atoms() {
+        assert_eq!(nnf("(not (not (p)))"), "(p)");
+        assert_eq!(nnf("(not (or (p) (not (q))))"), "(and (not (p)) (q))");
+        assert_eq!(nnf("(imply (p) (q))"), "(or (not (p)) (q))");
+        assert_eq!(nnf("(not (imply (p) (q)))"), "(and (p) (not (q)))");
+        assert_eq!(
+            nnf("(not (exists (?x - t) (forall (?y) (p ?x ?y))))"),
+            "(forall (?x - t) (exists (?y) (not (p ?x ?y))))"
+        );
+        assert_eq!(
+            nnf("(not (and (p) (or (q) (not (and (r) (s))))))"),
+            "(or (not (p)) (and (not (q)) (r) (s)))"
+        );
+    }
+
+    #[test]
+    fn to_nnf_negates_comparisons() {
+        assert_eq!(nnf("(not (< (f) 1))"), "(>= (f) 1)");
+        assert_eq!(nnf("(not (>= (f) 1))"), "(< (f) 1)");
+        assert_eq!(nnf("(not (= (f) 1))"), "(not (= (f) 1))");
+    }
+
+    #[test]
+    fn simplify_flattens_and_drops_trivial_goals() {
+        assert_eq!(simplify("(and (and) (and (p) (and (q))))"), "(and (p) (q))");
+        assert_eq!(simplify("(and (and (p)))"), "(p)");
+        assert_eq!(simplify("(or (p) (and) (q))"), "(and)");
+        assert_eq!(simplify("(or (p) (or (q) (r)))"), "(or (p) (q) (r))");
+        assert_eq!(simplify("(imply (p) (and))"), "(and)");
+        assert_eq!(simplify("(imply (and) (p))"), "(p)");
+        assert_eq!(simplify("(forall (?x) (and (and)))"), "(and)");
+
+        // The type of `?x` may have no objects, in which case the goal is false.
+        assert_eq!(
+            simplify("(exists (?x - t) (and (and)))"),
+            "(exists (?x - t) (and))"
+        );
+
+        // An empty disjunction is false and must not be dropped.
+        assert_eq!(simplify("(and (p) (or))"), "(and (p) (or))");
+    }
 }
@@ -1,7 +1,11 @@
 //! Contains the [`GoalDef`] type.
 
 use crate::types::pre_gd::PreconditionGoalDefinitions;
+use crate::types::NodeSpan;
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
 use crate::PreconditionGoalDefinition;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A problem goal definition; wraps a [`PreconditionGoalDefinitions`].
@@ -9,11 +13,11 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Problem`](crate::Problem).
 #[derive(Debug, Clone, PartialEq)]
-pub struct GoalDef(PreconditionGoalDefinitions);
+pub struct GoalDef(pub(crate) PreconditionGoalDefinitions, pub(crate) NodeSpan);
 
 impl GoalDef {
     pub const fn new(gd: PreconditionGoalDefinitions) -> Self {
-        Self(gd)
+        Self(gd, NodeSpan::NONE)
     }
 
     /// Gets the value.
@@ -59,3 +63,21 @@ impl From<GoalDef> for PreconditionGoalDefinitions {
         val.0
     }
 }
+
+impl Display for GoalDef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:goal {})", self.0)
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for GoalDef {
+    fn span(&self) -> Option<SourceSpan> {
+        self.1 .0
+    }
+
+    fn with_span(mut self, span: SourceSpan) -> Self {
+        self.1 = NodeSpan(Some(span));
+        self
+    }
+}
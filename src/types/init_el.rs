@@ -1,6 +1,7 @@
 //! Contains the [`InitElement`] type.
 
 use crate::types::{BasicFunctionTerm, Name, NameLiteral, Number};
+use std::fmt::{Display, Formatter};
 
 /// ## Usage
 /// Used by [`InitElements`](crate::InitElements) in [`Problem`](crate::Problem).
@@ -35,3 +36,14 @@ impl InitElement {
         Self::IsObject(term, value)
     }
 }
+
+impl Display for InitElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InitElement::Literal(literal) => write!(f, "{literal}"),
+            InitElement::At(time, literal) => write!(f, "(at {time} {literal})"),
+            InitElement::IsValue(term, value) => write!(f, "(= {term} {value})"),
+            InitElement::IsObject(term, value) => write!(f, "(= {term} {value})"),
+        }
+    }
+}
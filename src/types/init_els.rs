@@ -1,6 +1,10 @@
 //! Contains the [`InitElements`] type.
 
 use crate::types::InitElement;
+use crate::types::NodeSpans;
+#[cfg(feature = "spans")]
+use crate::types::SourceSpan;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A wrapper around a list of [`InitElement`] values.
@@ -8,17 +12,30 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Problem`](crate::Problem).
 #[derive(Debug, Clone, PartialEq)]
-pub struct InitElements(Vec<InitElement>);
+pub struct InitElements(pub(crate) Vec<InitElement>, pub(crate) NodeSpans);
 
 impl InitElements {
     pub const fn new(iter: Vec<InitElement>) -> Self {
-        Self(iter)
+        Self(iter, NodeSpans::NONE)
     }
 
     /// Gets the values.
     pub fn values(&self) -> &[InitElement] {
         self.0.as_slice()
     }
+
+    /// Gets the source location of the element at the specified index, if it was parsed.
+    #[cfg(feature = "spans")]
+    pub fn span_of(&self, index: usize) -> Option<SourceSpan> {
+        self.1 .0.get(index).copied()
+    }
+
+    /// Sets the source locations of the elements, in order.
+    #[cfg(feature = "spans")]
+    pub fn with_spans<I: IntoIterator<Item = SourceSpan>>(mut self, spans: I) -> Self {
+        self.1 .0 = spans.into_iter().collect();
+        self
+    }
 }
 
 impl Deref for InitElements {
@@ -34,3 +51,13 @@ impl FromIterator<InitElement> for InitElements {
         InitElements::new(iter.into_iter().collect())
     }
 }
+
+impl Display for InitElements {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:init")?;
+        for element in &self.0 {
+            write!(f, "\n  {element}")?;
+        }
+        write!(f, ")")
+    }
+}
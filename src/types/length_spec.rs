@@ -1,5 +1,7 @@
 //! Contains the [`LengthSpec`] type.
 
+use std::fmt::{Display, Formatter};
+
 /// Deprecated since PDDL 2.1.
 ///
 /// ## Usage
@@ -33,3 +35,16 @@ impl LengthSpec {
         self.parallel
     }
 }
+
+impl Display for LengthSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:length")?;
+        if let Some(serial) = self.serial {
+            write!(f, " (:serial {serial})")?;
+        }
+        if let Some(parallel) = self.parallel {
+            write!(f, " (:parallel {parallel})")?;
+        }
+        write!(f, ")")
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains literals via the [`Literal`] type.
 
 use crate::types::AtomicFormula;
+use std::fmt::{Display, Formatter};
 
 /// An [`AtomicFormula`] or its negated value.
 ///
@@ -12,7 +13,7 @@ pub enum Literal<T> {
     NotAtomicFormula(AtomicFormula<T>),
 }
 
-impl<'a, T> Literal<T> {
+impl<T> Literal<T> {
     pub const fn new(atomic_formula: AtomicFormula<T>) -> Self {
         Self::AtomicFormula(atomic_formula)
     }
@@ -26,12 +27,21 @@ impl<'a, T> Literal<T> {
     }
 }
 
-impl<'a, T> From<AtomicFormula<T>> for Literal<T> {
+impl<T> From<AtomicFormula<T>> for Literal<T> {
     fn from(value: AtomicFormula<T>) -> Self {
         Literal::new(value)
     }
 }
 
+impl<T: Display> Display for Literal<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Literal::AtomicFormula(af) => write!(f, "{af}"),
+            Literal::NotAtomicFormula(af) => write!(f, "(not {af})"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,8 @@
 //! Contains the [`MetricFExp`] type.
 
+use crate::types::display::write_each;
 use crate::types::{BinaryOp, FunctionSymbol, MultiOp, Name, Number, PreferenceName};
+use std::fmt::{Display, Formatter};
 
 /// A metric function expression.
 ///
@@ -56,3 +58,25 @@ impl MetricFExp {
         Self::IsViolated(pref)
     }
 }
+
+impl Display for MetricFExp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetricFExp::BinaryOp(op, lhs, rhs) => write!(f, "({op} {lhs} {rhs})"),
+            MetricFExp::MultiOp(op, lhs, rhs) => {
+                write!(f, "({op} {lhs}")?;
+                write_each(f, rhs)?;
+                write!(f, ")")
+            }
+            MetricFExp::Negative(value) => write!(f, "(- {value})"),
+            MetricFExp::Number(n) => write!(f, "{n}"),
+            MetricFExp::Function(symbol, names) => {
+                write!(f, "({symbol}")?;
+                write_each(f, names)?;
+                write!(f, ")")
+            }
+            MetricFExp::TotalTime => write!(f, "total-time"),
+            MetricFExp::IsViolated(name) => write!(f, "(is-violated {name})"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains the [`MetricSpec`] type.
 
 use crate::types::{MetricFExp, Optimization};
+use std::fmt::{Display, Formatter};
 
 /// A metric specification.
 ///
@@ -8,8 +9,8 @@ use crate::types::{MetricFExp, Optimization};
 /// Requires [Numeric Fluents](crate::Requirement::NumericFluents).
 #[derive(Debug, Clone, PartialEq)]
 pub struct MetricSpec {
-    optimization: Optimization,
-    exp: MetricFExp,
+    pub(crate) optimization: Optimization,
+    pub(crate) exp: MetricFExp,
 }
 
 impl MetricSpec {
@@ -27,3 +28,9 @@ impl MetricSpec {
         &self.exp
     }
 }
+
+impl Display for MetricSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:metric {} {})", self.optimization, self.exp)
+    }
+}
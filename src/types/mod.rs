@@ -21,6 +21,8 @@ mod da_effect;
 mod da_gd;
 mod da_symbol;
 mod derived_predicate;
+mod derived_predicate_graph;
+mod display;
 mod domain;
 mod domain_constraints_def;
 mod duration_constraint;
@@ -66,6 +68,7 @@ mod problem_constraints_def;
 pub(crate) mod requirement;
 mod requirements;
 mod simple_duration_constraint;
+mod source_span;
 mod structure_def;
 mod structure_defs;
 mod term;
@@ -73,9 +76,12 @@ pub(crate) mod time_specifier;
 mod timed_effect;
 mod timed_gd;
 mod timeless;
+mod timeline;
 mod r#type;
+mod type_hierarchy;
 mod typed;
 mod typed_list;
+#[allow(clippy::module_inception)]
 mod types;
 mod variable;
 
@@ -100,6 +106,7 @@ pub use da_effect::DurativeActionEffect;
 pub use da_gd::DurativeActionGoalDefinition;
 pub use da_symbol::DurativeActionSymbol;
 pub use derived_predicate::DerivedPredicate;
+pub use derived_predicate_graph::DerivedPredicateGraph;
 pub use domain::Domain;
 pub use domain_constraints_def::DomainConstraintsDef;
 pub use duration_constraint::DurationConstraint;
@@ -145,6 +152,9 @@ pub use r#type::{PrimitiveType, Type};
 pub use requirement::Requirement;
 pub use requirements::Requirements;
 pub use simple_duration_constraint::SimpleDurationConstraint;
+#[cfg_attr(docsrs, doc(cfg(feature = "spans")))]
+#[cfg(feature = "spans")]
+pub use source_span::{SourceSpan, Spanned};
 pub use structure_def::StructureDef;
 pub use structure_defs::StructureDefs;
 pub use term::Term;
@@ -152,6 +162,8 @@ pub use time_specifier::TimeSpecifier;
 pub use timed_effect::TimedEffect;
 pub use timed_gd::TimedGD;
 pub use timeless::Timeless;
+pub use timeline::Timeline;
+pub use type_hierarchy::TypeHierarchy;
 pub use typed::{ToTyped, Typed};
 pub use typed_list::TypedList;
 pub use types::Types;
@@ -160,6 +172,7 @@ pub use variable::Variable;
 // Internal re-exports.
 #[allow(unused_imports)]
 pub(crate) use r#type::{TYPE_NUMBER, TYPE_OBJECT};
+pub(crate) use source_span::{NodeSpan, NodeSpans};
 
 pub type NameLiteral = Literal<Name>;
 pub type TermLiteral = Literal<Term>;
@@ -2,6 +2,7 @@
 
 use crate::types::{PrimitiveType, ToTyped, Type, Typed};
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
 #[cfg(feature = "interning")]
@@ -22,10 +23,25 @@ lazy_static::lazy_static! {
 /// [`FunctionSymbol`](crate::FunctionSymbol), [`ActionSymbol`](crate::ActionSymbol),
 /// [`PreferenceName`](crate::PreferenceName), [`Term`](crate::Term),
 /// [`DurativeActionSymbol`](crate::DurativeActionSymbol) and [`Objects`](crate::Objects).
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+///
+/// ## Case Sensitivity
+/// PDDL is case-insensitive. If the `case-folding` crate feature is enabled,
+/// names are compared and hashed without regard to (ASCII) case. Names always
+/// keep their original spelling for printing.
+///
+/// ```
+/// # use pddl::Name;
+/// let name = Name::new("Depot");
+/// # #[cfg(feature = "case-folding")]
+/// assert_eq!(name, Name::new("depot"));
+/// # #[cfg(not(feature = "case-folding"))]
+/// # assert_ne!(name, Name::new("depot"));
+/// assert_eq!(name.to_string(), "Depot");
+/// ```
+#[derive(Debug, Clone, Default)]
 pub struct Name(NameVariant);
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone)]
 enum NameVariant {
     String(InternedString),
     Static(&'static str),
@@ -162,24 +178,65 @@ impl Deref for Name {
     }
 }
 
+/// Compares two names, without regard to case if the `case-folding` feature is enabled.
+#[inline(always)]
+fn names_eq(lhs: &str, rhs: &str) -> bool {
+    #[cfg(feature = "case-folding")]
+    {
+        lhs.eq_ignore_ascii_case(rhs)
+    }
+
+    #[cfg(not(feature = "case-folding"))]
+    {
+        lhs == rhs
+    }
+}
+
+impl PartialEq for Name {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        names_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Name {}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        #[cfg(feature = "case-folding")]
+        {
+            for byte in self.0.bytes() {
+                state.write_u8(byte.to_ascii_lowercase());
+            }
+            // Terminate the name like `str` does, to keep sequences of names unambiguous.
+            state.write_u8(0xff);
+        }
+
+        #[cfg(not(feature = "case-folding"))]
+        {
+            (*self.0).hash(state);
+        }
+    }
+}
+
 impl PartialEq<str> for Name {
     #[inline(always)]
     fn eq(&self, other: &str) -> bool {
-        self.0.eq(other)
+        names_eq(&self.0, other)
     }
 }
 
 impl PartialEq<&str> for Name {
     #[inline(always)]
     fn eq(&self, other: &&str) -> bool {
-        self.0.eq(*other)
+        names_eq(&self.0, other)
     }
 }
 
 impl PartialEq<String> for Name {
     #[inline(always)]
     fn eq(&self, other: &String) -> bool {
-        self.0.eq(other.as_str())
+        names_eq(&self.0, other)
     }
 }
 
@@ -222,24 +279,6 @@ impl Deref for NameVariant {
     }
 }
 
-impl PartialEq<str> for NameVariant {
-    fn eq(&self, other: &str) -> bool {
-        match self {
-            NameVariant::Static(str) => (*str).eq(other),
-            NameVariant::String(str) => {
-                #[cfg(feature = "interning")]
-                {
-                    str.as_ref().eq(other)
-                }
-                #[cfg(not(feature = "interning"))]
-                {
-                    str.eq(other)
-                }
-            }
-        }
-    }
-}
-
 impl Debug for NameVariant {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self)
@@ -255,16 +294,49 @@ impl Display for NameVariant {
     }
 }
 
+impl Display for Name {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use nom_greedyerror::AsStr;
 
     #[test]
     fn map_to_static_works() {
         let object = Name::map_to_static("object").expect("mapping works");
         let number = Name::map_to_static("number").expect("mapping works");
-        assert!(std::ptr::eq(object.as_str(), well_known::OBJECT));
-        assert!(std::ptr::eq(number.as_str(), well_known::NUMBER));
+        assert!(std::ptr::eq(object, well_known::OBJECT));
+        assert!(std::ptr::eq(number, well_known::NUMBER));
+    }
+
+    #[test]
+    #[cfg(feature = "case-folding")]
+    fn names_ignore_case() {
+        use std::collections::HashSet;
+
+        let names: HashSet<_> = ["truck1", "TRUCK1", "Truck1", "depot"]
+            .into_iter()
+            .map(Name::new)
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&Name::new("Depot")));
+        assert_ne!(Name::new("truck1"), Name::new("truck2"));
+        assert_eq!(Name::new("OBJECT"), Name::new_static("object"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "case-folding"))]
+    fn names_respect_case() {
+        use std::collections::HashSet;
+
+        let names: HashSet<_> = ["truck1", "TRUCK1", "truck1"]
+            .into_iter()
+            .map(Name::new)
+            .collect();
+        assert_eq!(names.len(), 2);
+        assert_ne!(Name::new("Depot"), "depot");
     }
 }
@@ -1,6 +1,7 @@
 //! Contains the [`Objects`] type.
 
 use crate::types::{Name, Typed, TypedNames};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A list of objects.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Problem`](crate::Problem).
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
-pub struct Objects(TypedNames);
+pub struct Objects(pub(crate) TypedNames);
 
 impl Objects {
     // TODO: Convert to const again that takes `TypedNames` directly.
@@ -41,3 +42,13 @@ impl Deref for Objects {
         &self.0
     }
 }
+
+impl Display for Objects {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "(:objects)")
+        } else {
+            write!(f, "(:objects {})", self.0)
+        }
+    }
+}
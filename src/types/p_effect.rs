@@ -1,6 +1,7 @@
 //! Contains p-effects.
 
 use crate::types::{AssignOp, AtomicFormula, FExp, FHead, FunctionTerm, Term};
+use std::fmt::{Display, Formatter};
 
 /// A p-effect. Occurs as part of a [`CEffect`](crate::types::CEffect) (within an [`Effect`](crate::types::Effects))
 /// or a [`ConditionalEffect`](crate::types::ConditionalEffect).
@@ -36,3 +37,15 @@ impl PEffect {
         Self::AssignObjectFluent(f_term, term)
     }
 }
+
+impl Display for PEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PEffect::AtomicFormula(af) => write!(f, "{af}"),
+            PEffect::NotAtomicFormula(af) => write!(f, "(not {af})"),
+            PEffect::AssignNumericFluent(op, head, exp) => write!(f, "({op} {head} {exp})"),
+            PEffect::AssignObjectFluent(fun, Some(term)) => write!(f, "(assign {fun} {term})"),
+            PEffect::AssignObjectFluent(fun, None) => write!(f, "(assign {fun} undefined)"),
+        }
+    }
+}
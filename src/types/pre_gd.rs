@@ -1,7 +1,9 @@
 //! Contains precondition goal definitions.
 
-use crate::types::TypedVariables;
+use crate::types::display::write_each;
+use crate::types::{GoalDefinition, TypedVariables};
 use crate::types::{Preference, PreferenceGD};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// Zero, one or many precondition goal definitions.
@@ -9,7 +11,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`GoalDef`](crate::GoalDef), as well as [`ActionDefinition`](crate::ActionDefinition).
 #[derive(Debug, Clone, PartialEq, Default)]
-pub struct PreconditionGoalDefinitions(Vec<PreconditionGoalDefinition>);
+pub struct PreconditionGoalDefinitions(pub(crate) Vec<PreconditionGoalDefinition>);
 
 impl PreconditionGoalDefinitions {
     /// Constructs a new instance from the provided vector of values.
@@ -41,7 +43,7 @@ impl PreconditionGoalDefinitions {
     /// Returns an iterator over the list.
     ///
     /// The iterator yields all items from start to end.
-    pub fn iter(&self) -> std::slice::Iter<PreconditionGoalDefinition> {
+    pub fn iter(&self) -> std::slice::Iter<'_, PreconditionGoalDefinition> {
         self.0.iter()
     }
 
@@ -54,6 +56,98 @@ impl PreconditionGoalDefinitions {
             None
         }
     }
+
+    /// Converts all goals into their simplified negation normal form,
+    /// see [`GoalDefinition::to_nnf`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{Parser, PreconditionGoalDefinitions};
+    /// let gds = PreconditionGoalDefinitions::from_str("(and (not (or (p) (q))) (forall (?x) (and)))").unwrap();
+    /// assert_eq!(gds.to_nnf().to_string(), "(and (not (p)) (not (q)))");
+    /// ```
+    pub fn to_nnf(&self) -> Self {
+        self.iter()
+            .map(PreconditionGoalDefinition::to_nnf)
+            .collect::<Self>()
+            .simplify()
+    }
+
+    /// Converts the goals into disjunctive normal form and returns its disjuncts,
+    /// see [`GoalDefinition::to_dnf`].
+    ///
+    /// Preferences and universally quantified goals are kept as-is in every disjunct.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{Parser, PreconditionGoalDefinitions};
+    /// let gds = PreconditionGoalDefinitions::from_str("(and (p) (or (q) (r)))").unwrap();
+    /// let disjuncts: Vec<_> = gds.to_dnf().iter().map(ToString::to_string).collect();
+    /// assert_eq!(disjuncts, ["(and (p) (q))", "(and (p) (r))"]);
+    /// ```
+    pub fn to_dnf(&self) -> Vec<Self> {
+        let mut disjuncts: Vec<Vec<PreconditionGoalDefinition>> = vec![vec![]];
+        for value in self.iter() {
+            let alternatives = match value {
+                PreconditionGoalDefinition::Preference(PreferenceGD::Goal(gd)) => gd
+                    .to_dnf()
+                    .into_iter()
+                    .map(|gd| PreconditionGoalDefinition::new_preference(gd.into()))
+                    .collect(),
+                value => vec![value.to_nnf()],
+            };
+            disjuncts = disjuncts
+                .iter()
+                .flat_map(|disjunct| {
+                    alternatives.iter().map(move |alternative| {
+                        let mut disjunct = disjunct.clone();
+                        disjunct.push(alternative.clone());
+                        disjunct
+                    })
+                })
+                .collect();
+        }
+
+        let mut result = Vec::with_capacity(disjuncts.len());
+        for disjunct in disjuncts {
+            let disjunct = Self::new(disjunct).simplify();
+            if !result.contains(&disjunct) {
+                result.push(disjunct);
+            }
+        }
+        result
+    }
+
+    /// Simplifies all goals, see [`GoalDefinition::simplify`].
+    ///
+    /// Conjunctions are split into individual entries, and entries whose goal is
+    /// trivially true are dropped. Preferences are kept even if their goal is trivially true.
+    pub fn simplify(self) -> Self {
+        let mut values = Vec::with_capacity(self.len());
+        for value in self {
+            match value {
+                PreconditionGoalDefinition::Preference(PreferenceGD::Goal(gd)) => {
+                    match gd.simplify() {
+                        GoalDefinition::And(gds) => values.extend(
+                            gds.into_iter()
+                                .map(|gd| PreconditionGoalDefinition::new_preference(gd.into())),
+                        ),
+                        gd => values.push(PreconditionGoalDefinition::new_preference(gd.into())),
+                    }
+                }
+                PreconditionGoalDefinition::Preference(pref) => {
+                    values.push(PreconditionGoalDefinition::new_preference(pref.simplify()))
+                }
+                PreconditionGoalDefinition::Forall(variables, gds) => {
+                    let gds = gds.simplify();
+                    if !gds.is_empty() {
+                        values.push(PreconditionGoalDefinition::new_forall(variables, gds));
+                    }
+                }
+            }
+        }
+        Self::new(values)
+    }
 }
 
 impl FromIterator<PreconditionGoalDefinition> for PreconditionGoalDefinitions {
@@ -102,10 +196,7 @@ impl From<Option<PreconditionGoalDefinition>> for PreconditionGoalDefinitions {
 
 impl From<Option<PreconditionGoalDefinitions>> for PreconditionGoalDefinitions {
     fn from(value: Option<PreconditionGoalDefinitions>) -> Self {
-        match value {
-            None => PreconditionGoalDefinitions::default(),
-            Some(values) => values,
-        }
+        value.unwrap_or_default()
     }
 }
 
@@ -155,6 +246,17 @@ impl PreconditionGoalDefinition {
     pub const fn new_forall(variables: TypedVariables, gd: PreconditionGoalDefinitions) -> Self {
         Self::Forall(variables, gd)
     }
+
+    /// Converts the goals into their simplified negation normal form,
+    /// see [`GoalDefinition::to_nnf`].
+    pub fn to_nnf(&self) -> Self {
+        match self {
+            PreconditionGoalDefinition::Preference(pref) => Self::new_preference(pref.to_nnf()),
+            PreconditionGoalDefinition::Forall(variables, gds) => {
+                Self::new_forall(variables.clone(), gds.to_nnf())
+            }
+        }
+    }
 }
 
 impl From<PreferenceGD> for PreconditionGoalDefinition {
@@ -168,3 +270,25 @@ impl From<Preference> for PreconditionGoalDefinition {
         PreconditionGoalDefinition::new_preference(PreferenceGD::from_preference(value))
     }
 }
+
+impl Display for PreconditionGoalDefinitions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0.as_slice() {
+            [gd] => write!(f, "{gd}"),
+            gds => {
+                write!(f, "(and")?;
+                write_each(f, gds)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl Display for PreconditionGoalDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreconditionGoalDefinition::Preference(gd) => write!(f, "{gd}"),
+            PreconditionGoalDefinition::Forall(vars, gds) => write!(f, "(forall ({vars}) {gds})"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains predicates via the [`Predicate`] type.
 
 use crate::types::Name;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A predicate name.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`AtomicFormulaSkeleton`](crate::AtomicFormulaSkeleton) and [`AtomicFormula`](crate::AtomicFormula).
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
-pub struct Predicate(Name);
+pub struct Predicate(pub(crate) Name);
 
 impl Predicate {
     #[inline(always)]
@@ -17,6 +18,7 @@ impl Predicate {
     }
 
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(name: &str) -> Self {
         Self(Name::new(name))
     }
@@ -32,7 +34,7 @@ impl Predicate {
     }
 }
 
-impl<'a, T> From<T> for Predicate
+impl<T> From<T> for Predicate
 where
     T: Into<Name>,
 {
@@ -64,3 +66,9 @@ impl Deref for Predicate {
         &self.0
     }
 }
+
+impl Display for Predicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
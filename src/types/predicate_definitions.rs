@@ -1,6 +1,8 @@
 //! Provides predicate definitions.
 
+use crate::types::display::write_each;
 use crate::types::AtomicFormulaSkeleton;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A set of predicate definitions.
@@ -8,7 +10,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Domain`](crate::Domain).
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct PredicateDefinitions(Vec<AtomicFormulaSkeleton>);
+pub struct PredicateDefinitions(pub(crate) Vec<AtomicFormulaSkeleton>);
 
 impl PredicateDefinitions {
     pub fn new<I: IntoIterator<Item = AtomicFormulaSkeleton>>(predicates: I) -> Self {
@@ -44,3 +46,11 @@ impl FromIterator<AtomicFormulaSkeleton> for PredicateDefinitions {
         PredicateDefinitions::new(iter)
     }
 }
+
+impl Display for PredicateDefinitions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:predicates")?;
+        write_each(f, &self.0)?;
+        write!(f, ")")
+    }
+}
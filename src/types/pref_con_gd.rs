@@ -1,6 +1,8 @@
 //! Contains the type [`PrefConGD`].
 
+use crate::types::display::write_each;
 use crate::types::{ConGD, PreferenceName, TypedVariables};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A list of [`PrefConGD`] values. This represents the `(and ...)` variant
@@ -12,7 +14,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`ProblemConstraintsDef`](crate::ProblemConstraintsDef).
 #[derive(Debug, Clone, PartialEq, Default)]
-pub struct PrefConGDs(Vec<PrefConGD>);
+pub struct PrefConGDs(pub(crate) Vec<PrefConGD>);
 
 impl PrefConGDs {
     /// Constructs a new instance from the provided vector of values.
@@ -55,7 +57,7 @@ impl PrefConGDs {
     /// Returns an iterator over the list.
     ///
     /// The iterator yields all items from start to end.
-    pub fn iter(&self) -> std::slice::Iter<PrefConGD> {
+    pub fn iter(&self) -> std::slice::Iter<'_, PrefConGD> {
         self.0.iter()
     }
 
@@ -128,10 +130,7 @@ impl From<Option<PrefConGD>> for PrefConGDs {
 
 impl From<Option<PrefConGDs>> for PrefConGDs {
     fn from(value: Option<PrefConGDs>) -> Self {
-        match value {
-            None => PrefConGDs::default(),
-            Some(values) => values,
-        }
+        value.unwrap_or_default()
     }
 }
 
@@ -190,3 +189,27 @@ impl PrefConGD {
         }
     }
 }
+
+impl Display for PrefConGDs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0.as_slice() {
+            [gd] => write!(f, "{gd}"),
+            gds => {
+                write!(f, "(and")?;
+                write_each(f, gds)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl Display for PrefConGD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrefConGD::Goal(gd) => write!(f, "{gd}"),
+            PrefConGD::Forall(vars, gds) => write!(f, "(forall ({vars}) {gds})"),
+            PrefConGD::Preference(Some(name), gd) => write!(f, "(preference {name} {gd})"),
+            PrefConGD::Preference(None, gd) => write!(f, "(preference {gd})"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains preference goal definitions.
 
 use crate::types::{GoalDefinition, Preference};
+use std::fmt::{Display, Formatter};
 
 /// A preferred goal definition.
 ///
@@ -22,6 +23,27 @@ impl PreferenceGD {
     pub fn from_preference(pref: Preference) -> Self {
         Self::Preference(pref)
     }
+
+    /// Converts the goal, or the goal of the preference, into its simplified negation
+    /// normal form, see [`GoalDefinition::to_nnf`].
+    pub fn to_nnf(&self) -> Self {
+        match self {
+            PreferenceGD::Goal(gd) => PreferenceGD::Goal(gd.to_nnf()),
+            PreferenceGD::Preference(pref) => {
+                PreferenceGD::Preference(Preference::new(pref.0.clone(), pref.1.to_nnf()))
+            }
+        }
+    }
+
+    /// Simplifies the goal, or the goal of the preference, see [`GoalDefinition::simplify`].
+    pub fn simplify(self) -> Self {
+        match self {
+            PreferenceGD::Goal(gd) => PreferenceGD::Goal(gd.simplify()),
+            PreferenceGD::Preference(Preference(name, gd)) => {
+                PreferenceGD::Preference(Preference::new(name, gd.simplify()))
+            }
+        }
+    }
 }
 
 impl From<GoalDefinition> for PreferenceGD {
@@ -35,3 +57,12 @@ impl From<Preference> for PreferenceGD {
         PreferenceGD::from_preference(value)
     }
 }
+
+impl Display for PreferenceGD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreferenceGD::Goal(gd) => write!(f, "{gd}"),
+            PreferenceGD::Preference(pref) => write!(f, "{pref}"),
+        }
+    }
+}
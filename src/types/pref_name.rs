@@ -1,6 +1,7 @@
 //! Provides names for preferences.
 
 use crate::types::Name;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A name of a preference.
@@ -9,7 +10,7 @@ use std::ops::Deref;
 /// Used by [`PrefGD`](crate::PreferenceGD), [`PrefTimedGD`](crate::PrefTimedGD),
 /// [`PrefConGD`](crate::PrefConGD) and [`MetricFExp`](crate::MetricFExp).
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct PreferenceName(Name);
+pub struct PreferenceName(pub(crate) Name);
 
 impl PreferenceName {
     #[inline(always)]
@@ -18,6 +19,7 @@ impl PreferenceName {
     }
 
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(name: &str) -> Self {
         Self(Name::new(name))
     }
@@ -41,7 +43,7 @@ impl Deref for PreferenceName {
     }
 }
 
-impl<'a, T> From<T> for PreferenceName
+impl<T> From<T> for PreferenceName
 where
     T: Into<Name>,
 {
@@ -49,3 +51,9 @@ where
         PreferenceName::new(value.into())
     }
 }
+
+impl Display for PreferenceName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains the [`PrefTimedGD`] type.
 
 use crate::types::{PreferenceName, TimedGD};
+use std::fmt::{Display, Formatter};
 
 /// A (preferred) timed goal definition.
 ///
@@ -35,3 +36,13 @@ impl From<(Option<PreferenceName>, TimedGD)> for PrefTimedGD {
         PrefTimedGD::Preference(value.0, value.1)
     }
 }
+
+impl Display for PrefTimedGD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrefTimedGD::Required(gd) => write!(f, "{gd}"),
+            PrefTimedGD::Preference(Some(name), gd) => write!(f, "(preference {name} {gd})"),
+            PrefTimedGD::Preference(None, gd) => write!(f, "(preference {gd})"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Contains preferences.
 
 use crate::types::{GoalDefinition, PreferenceName};
+use std::fmt::{Display, Formatter};
 
 /// A preference.
 ///
@@ -10,7 +11,7 @@ use crate::types::{GoalDefinition, PreferenceName};
 /// ## Usage
 /// Used by [`PreferenceGD`](crate::PreferenceGD).
 #[derive(Debug, Clone, PartialEq)]
-pub struct Preference(Option<PreferenceName>, GoalDefinition); // TODO: A similar type is used for PrefConGD
+pub struct Preference(pub(crate) Option<PreferenceName>, pub(crate) GoalDefinition); // TODO: A similar type is used for PrefConGD
 
 impl Preference {
     pub const fn new(name: Option<PreferenceName>, gd: GoalDefinition) -> Self {
@@ -49,3 +50,12 @@ impl From<GoalDefinition> for Preference {
         Self::new(None, value)
     }
 }
+
+impl Display for Preference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(name) => write!(f, "(preference {name} {})", self.1),
+            None => write!(f, "(preference {})", self.1),
+        }
+    }
+}
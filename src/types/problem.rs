@@ -1,10 +1,15 @@
 //! Contains the [`Problem`] type.
 
+use crate::types::display::write_indented;
+use crate::types::NodeSpan;
 use crate::types::{
     GoalDef, InitElements, LengthSpec, MetricSpec, Name, Objects, ProblemConstraintsDef,
     Requirements,
 };
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
 use crate::{PreconditionGoalDefinitions, PrefConGDs};
+use std::fmt::{Display, Formatter};
 
 /// A domain-specific problem declaration.
 ///
@@ -33,35 +38,37 @@ use crate::{PreconditionGoalDefinitions, PrefConGDs};
 #[derive(Debug, Clone, PartialEq)]
 pub struct Problem {
     // The problem name.
-    name: Name,
+    pub(crate) name: Name,
     /// The name of the [`Domain`] this problem belongs to.
-    domain: Name,
+    pub(crate) domain: Name,
     /// The optional list of requirements.
-    requires: Requirements,
+    pub(crate) requires: Requirements,
     /// The optional list of object declarations.
-    objects: Objects,
+    pub(crate) objects: Objects,
     /// The initial state definition.
-    init: InitElements,
+    pub(crate) init: InitElements,
     /// The goal definition.
-    goal: GoalDef,
+    pub(crate) goal: GoalDef,
     /// The optional list of constraints.
     ///
     /// ## Requirements
     /// Requires [Constraints](crate::Requirement::Constraints).
-    constraints: ProblemConstraintsDef,
+    pub(crate) constraints: ProblemConstraintsDef,
     /// The optional list of metrics specifications.
     ///
     /// ## Requirements
     /// Requires [Numeric Fluents](crate::Requirement::NumericFluents).
-    metric_spec: Option<MetricSpec>,
+    pub(crate) metric_spec: Option<MetricSpec>,
     /// The optional goal length specification.
     ///
     /// Deprecated since PDDL 2.1.
-    length_spec: Option<LengthSpec>,
+    pub(crate) length_spec: Option<LengthSpec>,
+    pub(crate) span: NodeSpan,
 }
 
 impl Problem {
     /// Creates a new [`Problem`] instance.
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         name: Name,
         domain: Name,
@@ -83,6 +90,7 @@ impl Problem {
             constraints,
             metric_spec,
             length_spec,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -103,6 +111,7 @@ impl Problem {
             constraints: ProblemConstraintsDef::default(),
             metric_spec: None,
             length_spec: None,
+            span: NodeSpan::NONE,
         }
     }
 
@@ -166,6 +175,11 @@ impl Problem {
         self.goal.value()
     }
 
+    /// Returns the goal definition of the problem, i.e. the `(:goal ...)` section.
+    pub const fn goal(&self) -> &GoalDef {
+        &self.goal
+    }
+
     /// Returns the optional constraints of the problem.
     /// ## Requirements
     /// Requires [Constraints](crate::Requirement::Constraints).
@@ -186,3 +200,41 @@ impl Problem {
         &self.length_spec
     }
 }
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(define (problem {})", self.name)?;
+        write!(f, "\n  (:domain {})", self.domain)?;
+        if !self.requires.is_empty() {
+            write!(f, "\n  {}", self.requires)?;
+        }
+        if !self.objects.is_empty() {
+            write!(f, "\n  {}", self.objects)?;
+        }
+        write!(f, "\n  ")?;
+        write_indented(f, &self.init, "  ")?;
+        write!(f, "\n  {}", self.goal)?;
+        if !self.constraints.is_empty() {
+            write!(f, "\n  {}", self.constraints)?;
+        }
+        if let Some(metric) = &self.metric_spec {
+            write!(f, "\n  {metric}")?;
+        }
+        if let Some(length) = &self.length_spec {
+            write!(f, "\n  {length}")?;
+        }
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for Problem {
+    fn span(&self) -> Option<SourceSpan> {
+        self.span.0
+    }
+
+    fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = NodeSpan(Some(span));
+        self
+    }
+}
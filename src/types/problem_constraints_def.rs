@@ -1,6 +1,7 @@
 //! Contains the [`ProblemConstraintsDef`] type.
 
 use crate::PrefConGDs;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A problem constraints definition; wraps a [`PrefConGD`].
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Requirements
 /// Requires [Constraints](crate::Requirement::Constraints).
 #[derive(Debug, Default, Clone, PartialEq)]
-pub struct ProblemConstraintsDef(PrefConGDs);
+pub struct ProblemConstraintsDef(pub(crate) PrefConGDs);
 
 impl ProblemConstraintsDef {
     pub const fn new(gd: PrefConGDs) -> Self {
@@ -46,3 +47,9 @@ impl From<ProblemConstraintsDef> for PrefConGDs {
         val.0
     }
 }
+
+impl Display for ProblemConstraintsDef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:constraints {})", self.0)
+    }
+}
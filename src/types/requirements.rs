@@ -1,7 +1,9 @@
 //! Provides function requirements.
 
+use crate::types::display::write_each;
 use crate::types::Requirement;
 use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A set of domain requirements.
@@ -49,7 +51,7 @@ use std::ops::Deref;
 /// assert!(effective.contains(&Requirement::Strips));
 /// ```
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct Requirements(Vec<Requirement>);
+pub struct Requirements(pub(crate) Vec<Requirement>);
 
 impl Requirements {
     /// Constructs a new set of requirements from the specified values.
@@ -96,3 +98,11 @@ impl FromIterator<Requirement> for Requirements {
         Requirements::new(iter)
     }
 }
+
+impl Display for Requirements {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:requirements")?;
+        write_each(f, &self.0)?;
+        write!(f, ")")
+    }
+}
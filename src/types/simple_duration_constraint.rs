@@ -1,6 +1,7 @@
 //! Contains the [`SimpleDurationConstraint`] type.
 
 use crate::types::{DOp, DurationValue, TimeSpecifier};
+use std::fmt::{Display, Formatter};
 
 /// A simple duration constraint.
 ///
@@ -35,3 +36,14 @@ impl From<(TimeSpecifier, SimpleDurationConstraint)> for SimpleDurationConstrain
         SimpleDurationConstraint::new_at(value.0, value.1)
     }
 }
+
+impl Display for SimpleDurationConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimpleDurationConstraint::Op(op, value) => write!(f, "({op} ?duration {value})"),
+            SimpleDurationConstraint::At(time, constraint) => {
+                write!(f, "(at {time} {constraint})")
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Contains source locations via the [`SourceSpan`] type.
+
+#[cfg(feature = "spans")]
+use std::ops::Range;
+
+/// The location of a node in the source text it was parsed from.
+///
+/// ## Usage
+/// Provided by [`Spanned::span`] if the `spans` crate feature is enabled.
+#[cfg(feature = "spans")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SourceSpan {
+    start: usize,
+    end: usize,
+    line: u32,
+    column: usize,
+}
+
+#[cfg(feature = "spans")]
+impl SourceSpan {
+    pub const fn new(start: usize, end: usize, line: u32, column: usize) -> Self {
+        Self {
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+
+    /// Gets the byte offset of the first character of the node.
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Gets the byte offset following the last character of the node.
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Gets the byte range of the node.
+    pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Gets the one-based line number of the first character of the node.
+    pub const fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Gets the one-based column of the first character of the node, counted in characters.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
+
+/// A node that records the location it was parsed from.
+///
+/// Source locations do not take part in comparisons: nodes parsed from different
+/// positions compare equal if their contents do.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Spanned};
+/// let input = r#"(define (domain briefcase-world)
+///     (:predicates (at ?x ?y))
+///     (:action take-out
+///         :parameters (?x)
+///         :effect (not (at ?x ?x))))"#;
+///
+/// let domain = Domain::from_str(input).unwrap();
+///
+/// let span = domain.predicates()[0].span().unwrap();
+/// assert_eq!((span.line(), span.column()), (2, 18));
+/// assert_eq!(&input[span.range()], "(at ?x ?y)");
+///
+/// let span = domain.structure()[0].span().unwrap();
+/// assert_eq!((span.line(), span.column()), (3, 5));
+/// assert!(input[span.range()].starts_with("(:action take-out"));
+/// ```
+#[cfg(feature = "spans")]
+pub trait Spanned {
+    /// Gets the source location of the node, if it was parsed.
+    fn span(&self) -> Option<SourceSpan>;
+
+    /// Sets the source location of the node.
+    fn with_span(self, span: SourceSpan) -> Self;
+}
+
+/// The source location of a node. Empty unless the `spans` crate feature is enabled.
+#[cfg(feature = "spans")]
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct NodeSpan(pub(crate) Option<SourceSpan>);
+
+/// The source location of a node. Empty unless the `spans` crate feature is enabled.
+#[cfg(not(feature = "spans"))]
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct NodeSpan;
+
+impl NodeSpan {
+    #[cfg(feature = "spans")]
+    pub const NONE: Self = Self(None);
+    #[cfg(not(feature = "spans"))]
+    pub const NONE: Self = Self;
+}
+
+/// The source locations of the elements of a list.
+/// Empty unless the `spans` crate feature is enabled.
+#[cfg(feature = "spans")]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NodeSpans(pub(crate) Vec<SourceSpan>);
+
+/// The source locations of the elements of a list.
+/// Empty unless the `spans` crate feature is enabled.
+#[cfg(not(feature = "spans"))]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NodeSpans;
+
+impl NodeSpans {
+    #[cfg(feature = "spans")]
+    pub const NONE: Self = Self(Vec::new());
+    #[cfg(not(feature = "spans"))]
+    pub const NONE: Self = Self;
+}
+
+impl PartialEq for NodeSpan {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NodeSpan {}
+
+impl PartialEq for NodeSpans {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NodeSpans {}
+
+#[cfg(all(test, feature = "spans", feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::{Parser, Problem};
+
+    const PROBLEM: &str = r#"
+(define (problem get-paid)
+    (:domain briefcase-world)
+    (:init (at B home)
+           ; the paycheck
+           (at P home))
+    (:goal (at P office)))
+"#;
+
+    #[test]
+    fn spans_point_into_the_source() {
+        let problem = Problem::from_str(PROBLEM).unwrap();
+
+        let span = problem.span().unwrap();
+        assert_eq!((span.line(), span.column()), (2, 1));
+        assert!(PROBLEM[span.range()].starts_with("(define"));
+        assert!(PROBLEM[span.range()].ends_with("office)))"));
+
+        let init = problem.init();
+        assert_eq!(&PROBLEM[init.span_of(0).unwrap().range()], "(at B home)");
+        let span = init.span_of(1).unwrap();
+        assert_eq!(&PROBLEM[span.range()], "(at P home)");
+        assert_eq!((span.line(), span.column()), (6, 12));
+        assert_eq!(init.span_of(2), None);
+
+        let span = problem.goal().span().unwrap();
+        assert_eq!(&PROBLEM[span.range()], "(:goal (at P office))");
+    }
+
+    #[test]
+    fn spans_do_not_affect_equality() {
+        let problem = Problem::from_str(PROBLEM).unwrap();
+        let reformatted = Problem::from_str(&problem.to_string()).unwrap();
+        assert_eq!(problem, reformatted);
+        assert_ne!(problem.init().span_of(1), reformatted.init().span_of(1));
+    }
+}
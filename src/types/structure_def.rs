@@ -1,12 +1,16 @@
 //! Contains definitions for domain structure definitions.
 
 use crate::types::{ActionDefinition, DerivedPredicate, DurativeActionDefinition};
+#[cfg(feature = "spans")]
+use crate::types::{SourceSpan, Spanned};
+use std::fmt::{Display, Formatter};
 
 /// A domain structure definition.
 ///
 /// ## Usage
 /// Used by [`StructureDefs`](crate::StructureDefs) in [`Domain`](crate::Domain).
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum StructureDef {
     Action(ActionDefinition),
     /// ## Requirements
@@ -46,3 +50,34 @@ impl From<DerivedPredicate> for StructureDef {
         StructureDef::new_derived(value)
     }
 }
+
+impl Display for StructureDef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StructureDef::Action(action) => write!(f, "{action}"),
+            StructureDef::DurativeAction(action) => write!(f, "{action}"),
+            StructureDef::Derived(predicate) => write!(f, "{predicate}"),
+        }
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for StructureDef {
+    fn span(&self) -> Option<SourceSpan> {
+        match self {
+            StructureDef::Action(action) => action.span(),
+            StructureDef::DurativeAction(action) => action.span(),
+            StructureDef::Derived(predicate) => predicate.span(),
+        }
+    }
+
+    fn with_span(self, span: SourceSpan) -> Self {
+        match self {
+            StructureDef::Action(action) => StructureDef::Action(action.with_span(span)),
+            StructureDef::DurativeAction(action) => {
+                StructureDef::DurativeAction(action.with_span(span))
+            }
+            StructureDef::Derived(predicate) => StructureDef::Derived(predicate.with_span(span)),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Provides structure definitions.
 
 use crate::types::StructureDef;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A set of structure definitions.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Domain`](crate::Domain).
 #[derive(Debug, Default, Clone, PartialEq)]
-pub struct StructureDefs(Vec<StructureDef>);
+pub struct StructureDefs(pub(crate) Vec<StructureDef>);
 
 impl StructureDefs {
     pub fn new<I: IntoIterator<Item = StructureDef>>(defs: I) -> Self {
@@ -40,3 +41,15 @@ impl FromIterator<StructureDef> for StructureDefs {
         StructureDefs::new(iter)
     }
 }
+
+impl Display for StructureDefs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, def) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{def}")?;
+        }
+        Ok(())
+    }
+}
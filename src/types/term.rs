@@ -1,6 +1,7 @@
 use crate::types::function_term::FunctionTerm;
 use crate::types::Name;
 use crate::types::Variable;
+use std::fmt::{Display, Formatter};
 
 /// A term, i.e. a [`Name`], [`Variable`] or [`FunctionTerm`].
 ///
@@ -45,3 +46,13 @@ impl From<FunctionTerm> for Term {
         Self::Function(value)
     }
 }
+
+impl Display for Term {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Term::Name(name) => write!(f, "{name}"),
+            Term::Variable(var) => write!(f, "{var}"),
+            Term::Function(fun) => write!(f, "{fun}"),
+        }
+    }
+}
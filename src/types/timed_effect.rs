@@ -1,6 +1,7 @@
 //! Contains the [`TimedEffect`] type.
 
 use crate::types::{AssignOpT, ConditionalEffect, FAssignDa, FExpT, FHead, TimeSpecifier};
+use std::fmt::{Display, Formatter};
 
 /// A timed effect, either conditional, continuous or derived from a fluent, e.g. [`DurativeActionEffect`](crate::types::DurativeActionEffect).
 ///
@@ -62,3 +63,13 @@ impl From<(AssignOpT, FHead, FExpT)> for TimedEffect {
         TimedEffect::ContinuousEffect(value.0, value.1, value.2)
     }
 }
+
+impl Display for TimedEffect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimedEffect::Conditional(time, effect) => write!(f, "(at {time} {effect})"),
+            TimedEffect::NumericFluent(time, effect) => write!(f, "(at {time} {effect})"),
+            TimedEffect::ContinuousEffect(op, head, exp) => write!(f, "({op} {head} {exp})"),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::types::{GoalDefinition, Interval, TimeSpecifier};
+use std::fmt::{Display, Formatter};
 
 /// A timed goal definition.
 ///
@@ -68,3 +69,12 @@ impl From<(Interval, GoalDefinition)> for TimedGD {
         TimedGD::Over(value.0, value.1)
     }
 }
+
+impl Display for TimedGD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimedGD::At(time, gd) => write!(f, "(at {time} {gd})"),
+            TimedGD::Over(interval, gd) => write!(f, "(over {interval} {gd})"),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Contains the [`Timeless`] type.
 
+use crate::types::display::write_each;
 use crate::types::NameLiteral;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A timeless predicate.
@@ -15,7 +17,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Domain`](crate::Domain).
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
-pub struct Timeless(Vec<NameLiteral>);
+pub struct Timeless(pub(crate) Vec<NameLiteral>);
 
 impl Timeless {
     pub fn new(literal: Vec<NameLiteral>) -> Self {
@@ -42,3 +44,11 @@ impl Deref for Timeless {
         &self.0
     }
 }
+
+impl Display for Timeless {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(:timeless")?;
+        write_each(f, &self.0)?;
+        write!(f, ")")
+    }
+}
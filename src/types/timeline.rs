@@ -0,0 +1,112 @@
+//! Contains the timed initial literals of a problem via the [`Timeline`] type.
+
+use crate::types::{InitElement, InitElements, NameLiteral, Number};
+
+/// The timed initial literals of a problem, ordered by time.
+///
+/// Each [`InitElement::At`] states that a literal becomes true, or false if negated, at
+/// the given time. The timeline groups these literals by their time points, which are
+/// sorted in ascending order. Literals at the same time keep the order of the `:init`
+/// section.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Number, Parser, Problem, Timeline};
+/// let problem = Problem::from_str(r#"(define (problem p) (:domain d)
+///     (:init (open) (at 10 (not (open))) (at 2.5 (lit)) (at 10 (closed)))
+///     (:goal (and)))"#).unwrap();
+/// let timeline = Timeline::new(problem.init());
+///
+/// assert_eq!(timeline.len(), 2);
+/// assert_eq!(timeline.times().collect::<Vec<_>>(), [Number::from(2.5), Number::from(10)]);
+/// assert_eq!(timeline.literals_at(10.into()).len(), 2);
+/// ```
+///
+/// ## Usage
+/// Built from the [`InitElements`] of a [`Problem`](crate::Problem).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Timeline {
+    /// The time points in ascending order, along with their literals.
+    events: Vec<(Number, Vec<NameLiteral>)>,
+}
+
+impl Timeline {
+    /// Collects the timed initial literals of the `init` section.
+    pub fn new(init: &InitElements) -> Self {
+        let mut events: Vec<(Number, Vec<NameLiteral>)> = Vec::new();
+        for element in init.iter() {
+            let InitElement::At(time, literal) = element else {
+                continue;
+            };
+            match events.binary_search_by(|(other, _)| other.cmp(time)) {
+                Ok(index) => events[index].1.push(literal.clone()),
+                Err(index) => events.insert(index, (*time, vec![literal.clone()])),
+            }
+        }
+        Self { events }
+    }
+
+    /// Gets the number of distinct time points.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if there are no timed initial literals.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Gets the time points in ascending order.
+    pub fn times(&self) -> impl Iterator<Item = Number> + '_ {
+        self.events.iter().map(|(time, _)| *time)
+    }
+
+    /// Gets the literals that take effect at the `time`, which is empty if there are none.
+    pub fn literals_at(&self, time: Number) -> &[NameLiteral] {
+        self.events
+            .binary_search_by(|(other, _)| other.cmp(&time))
+            .map_or(&[], |index| self.events[index].1.as_slice())
+    }
+
+    /// Gets the time points in ascending order along with their literals.
+    pub fn iter(&self) -> impl Iterator<Item = (Number, &[NameLiteral])> {
+        self.events
+            .iter()
+            .map(|(time, literals)| (*time, literals.as_slice()))
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::{Parser, Problem};
+
+    #[test]
+    fn groups_literals_by_time() {
+        let problem = Problem::from_str(
+            r#"(define (problem p) (:domain d)
+                (:init (at 5 (a)) (b) (at 1 (not (b))) (at 5 (not (c))) (at 3 (c)))
+                (:goal (and)))"#,
+        )
+        .unwrap();
+        let timeline = Timeline::new(problem.init());
+
+        let events: Vec<_> = timeline
+            .iter()
+            .map(|(time, literals)| {
+                let literals: Vec<_> = literals.iter().map(ToString::to_string).collect();
+                (time, literals)
+            })
+            .collect();
+        assert_eq!(
+            events,
+            [
+                (1.into(), vec!["(not (b))".to_string()]),
+                (3.into(), vec!["(c)".to_string()]),
+                (5.into(), vec!["(a)".to_string(), "(not (c))".to_string()]),
+            ]
+        );
+        assert!(timeline.literals_at(2.into()).is_empty());
+        assert!(Timeline::default().is_empty());
+    }
+}
@@ -1,7 +1,9 @@
 //! Contains types.
 
+use crate::types::display::write_each;
 use crate::types::iterators::FlatteningIntoIterator;
 use crate::types::Name;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// The `object` type.
@@ -19,7 +21,7 @@ pub const TYPE_NUMBER: PrimitiveType = PrimitiveType(Name::new_static("number"))
 /// ## Usage
 /// Used by [`Type`].
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
-pub struct PrimitiveType(Name);
+pub struct PrimitiveType(pub(crate) Name);
 
 /// A type selection from `<primitive-type> | (either <primitive-type>)`.
 ///
@@ -50,12 +52,36 @@ impl Type {
             Type::EitherOf(v) => v.len(),
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the primitive types a value of this type may have.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{PrimitiveType, Type};
+    /// assert_eq!(Type::from("truck").primitives(), [PrimitiveType::from("truck")]);
+    /// assert_eq!(Type::from(vec!["truck", "train"]).primitives().len(), 2);
+    /// ```
+    pub fn primitives(&self) -> &[PrimitiveType] {
+        match self {
+            Type::Exactly(t) => std::slice::from_ref(t),
+            Type::EitherOf(ts) => ts.as_slice(),
+        }
+    }
 }
 
 impl PrimitiveType {
     pub fn new(name: Name) -> Self {
         Self(name)
     }
+
+    /// Gets the name of the type.
+    pub const fn name(&self) -> &Name {
+        &self.0
+    }
 }
 
 impl Default for Type {
@@ -88,7 +114,7 @@ impl From<Vec<PrimitiveType>> for Type {
     }
 }
 
-impl<'a, P> FromIterator<P> for Type
+impl<P> FromIterator<P> for Type
 where
     P: Into<PrimitiveType>,
 {
@@ -97,7 +123,7 @@ where
     }
 }
 
-impl<'a, T> From<T> for PrimitiveType
+impl<T> From<T> for PrimitiveType
 where
     T: Into<Name>,
 {
@@ -133,6 +159,25 @@ impl IntoIterator for Type {
     }
 }
 
+impl Display for PrimitiveType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Exactly(t) => write!(f, "{t}"),
+            Type::EitherOf(ts) => {
+                write!(f, "(either")?;
+                write_each(f, ts)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,391 @@
+//! Contains the resolved subtype relation of a [`Types`] section via the [`TypeHierarchy`] type.
+
+use crate::types::{Constants, Name, Objects, Type, Types, TYPE_OBJECT};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// The index of the implicit `object` type.
+const OBJECT: usize = 0;
+
+/// The subtype relation of the types declared by a domain.
+///
+/// The hierarchy always contains the implicit root type `object`. Types declared
+/// without a parent, as well as parent types that are not declared themselves, are
+/// direct subtypes of `object`. A type declared as `- (either a b)` is a subtype of
+/// both `a` and `b`. Type names are compared like [`Name`](crate::Name) compares them.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Name, Parser, TypeHierarchy, Types};
+/// let types = Types::from_str("(:types truck train - vehicle vehicle - object location)").unwrap();
+/// let hierarchy = TypeHierarchy::new(&types);
+///
+/// assert!(hierarchy.is_subtype(&"truck".into(), &"vehicle".into()));
+/// assert!(hierarchy.is_subtype(&"truck".into(), &"object".into()));
+/// assert!(!hierarchy.is_subtype(&"vehicle".into(), &"truck".into()));
+///
+/// assert_eq!(hierarchy.ancestors(&"truck".into()), [&Name::from("vehicle"), &Name::from("object")]);
+/// assert_eq!(hierarchy.descendants(&"vehicle".into()), [&Name::from("truck"), &Name::from("train")]);
+/// ```
+///
+/// ## Usage
+/// Built from the [`Types`] of a [`Domain`](crate::Domain).
+#[derive(Debug, Clone)]
+pub struct TypeHierarchy {
+    /// The type names, starting with `object`.
+    names: Vec<Name>,
+    /// The index of each type name.
+    ids: HashMap<Name, usize>,
+    /// Whether a type is declared in the types section; `object` is always declared.
+    declared: Vec<bool>,
+    /// The direct parents of each type.
+    parents: Vec<Vec<usize>>,
+    /// The direct children of each type.
+    children: Vec<Vec<usize>>,
+    /// Pairs of types and their undeclared parent type.
+    undeclared: Vec<(usize, usize)>,
+}
+
+impl TypeHierarchy {
+    /// Resolves the subtype relation of the declared `types`.
+    pub fn new(types: &Types) -> Self {
+        let object = TYPE_OBJECT;
+        let mut hierarchy = Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            declared: Vec::new(),
+            parents: Vec::new(),
+            children: Vec::new(),
+            undeclared: Vec::new(),
+        };
+        hierarchy.insert(object.name());
+
+        for r#type in types.iter() {
+            let id = hierarchy.insert(r#type.value());
+            hierarchy.declared[id] = true;
+        }
+
+        for r#type in types.iter() {
+            let id = hierarchy.ids[r#type.value()];
+            for parent in r#type.type_().primitives() {
+                let parent = hierarchy.insert(parent.name());
+                if !hierarchy.declared[parent] && !hierarchy.undeclared.contains(&(id, parent)) {
+                    hierarchy.undeclared.push((id, parent));
+                }
+                if id != OBJECT {
+                    hierarchy.link(id, parent);
+                }
+            }
+        }
+
+        for id in 1..hierarchy.names.len() {
+            if !hierarchy.declared[id] {
+                hierarchy.link(id, OBJECT);
+            }
+        }
+        hierarchy
+    }
+
+    /// Adds the type unless it is already known and returns its index.
+    fn insert(&mut self, name: &Name) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.clone());
+        self.ids.insert(name.clone(), id);
+        self.declared.push(id == OBJECT);
+        self.parents.push(Vec::new());
+        self.children.push(Vec::new());
+        id
+    }
+
+    /// Makes `parent` a direct parent of `child`.
+    fn link(&mut self, child: usize, parent: usize) {
+        if !self.parents[child].contains(&parent) {
+            self.parents[child].push(parent);
+            self.children[parent].push(child);
+        }
+    }
+
+    /// Gets all types of the hierarchy, starting with `object`, followed by the
+    /// declared types and undeclared parent types in order of their first occurrence.
+    pub fn types(&self) -> impl Iterator<Item = &Name> {
+        self.names.iter()
+    }
+
+    /// Determines whether the hierarchy contains the type.
+    pub fn contains(&self, r#type: &Name) -> bool {
+        self.ids.contains_key(r#type)
+    }
+
+    /// Gets the direct parent types of the type.
+    pub fn parents(&self, r#type: &Name) -> Vec<&Name> {
+        self.neighbors(r#type, &self.parents)
+    }
+
+    /// Gets the direct child types of the type.
+    pub fn children(&self, r#type: &Name) -> Vec<&Name> {
+        self.neighbors(r#type, &self.children)
+    }
+
+    /// Gets all proper ancestors of the type, nearest first.
+    ///
+    /// Types that are not part of the hierarchy have no ancestors.
+    pub fn ancestors(&self, r#type: &Name) -> Vec<&Name> {
+        self.reachable(r#type, &self.parents)
+    }
+
+    /// Gets all proper descendants of the type, nearest first.
+    ///
+    /// Types that are not part of the hierarchy have no descendants.
+    pub fn descendants(&self, r#type: &Name) -> Vec<&Name> {
+        self.reachable(r#type, &self.children)
+    }
+
+    fn neighbors<'a>(&'a self, r#type: &Name, edges: &'a [Vec<usize>]) -> Vec<&'a Name> {
+        match self.ids.get(r#type) {
+            Some(&id) => edges[id].iter().map(|&id| &self.names[id]).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Collects the types reachable from the type via `edges` in breadth-first order.
+    fn reachable<'a>(&'a self, r#type: &Name, edges: &'a [Vec<usize>]) -> Vec<&'a Name> {
+        let Some(&start) = self.ids.get(r#type) else {
+            return Vec::new();
+        };
+
+        let mut seen = vec![false; self.names.len()];
+        seen[start] = true;
+        let mut pending = VecDeque::from([start]);
+        let mut reachable = Vec::new();
+        while let Some(id) = pending.pop_front() {
+            for &next in &edges[id] {
+                if !seen[next] {
+                    seen[next] = true;
+                    reachable.push(&self.names[next]);
+                    pending.push_back(next);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Determines whether `r#type` is `of` or one of its descendants.
+    ///
+    /// Every type, whether part of the hierarchy or not, is a subtype of `object`.
+    pub fn is_subtype(&self, r#type: &Name, of: &Name) -> bool {
+        r#type == of || self.ids.get(of) == Some(&OBJECT) || self.ancestors(r#type).contains(&of)
+    }
+
+    /// Determines whether a value of type `r#type` may be used where a value of type
+    /// `to` is expected, i.e. whether each of its primitive types is a subtype of one
+    /// of the primitive types of `to`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{Parser, Type, TypeHierarchy, Types};
+    /// let types = Types::from_str("(:types truck train - vehicle)").unwrap();
+    /// let hierarchy = TypeHierarchy::new(&types);
+    ///
+    /// let vehicle = Type::from("vehicle");
+    /// assert!(hierarchy.is_assignable(&Type::from(vec!["truck", "train"]), &vehicle));
+    /// assert!(!hierarchy.is_assignable(&vehicle, &Type::from(vec!["truck", "train"])));
+    /// ```
+    pub fn is_assignable(&self, r#type: &Type, to: &Type) -> bool {
+        r#type.primitives().iter().all(|r#type| {
+            to.primitives()
+                .iter()
+                .any(|to| self.is_subtype(r#type.name(), to.name()))
+        })
+    }
+
+    /// Gets the pairs of types and their parent types that are not declared themselves.
+    ///
+    /// Such parent types are treated as direct subtypes of `object`.
+    pub fn undeclared_parents(&self) -> Vec<(&Name, &Name)> {
+        self.undeclared
+            .iter()
+            .map(|&(id, parent)| (&self.names[id], &self.names[parent]))
+            .collect()
+    }
+
+    /// Gets the groups of types that are their own ancestors, in order of declaration.
+    ///
+    /// Each group is a strongly connected component of the hierarchy, i.e. each of its
+    /// types is both an ancestor and a descendant of every other type in the group.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{Name, Parser, TypeHierarchy, Types};
+    /// let types = Types::from_str("(:types a - b b - c c - a d - d e)").unwrap();
+    /// let hierarchy = TypeHierarchy::new(&types);
+    ///
+    /// assert_eq!(hierarchy.cycles(), [
+    ///     vec![&Name::from("a"), &Name::from("b"), &Name::from("c")],
+    ///     vec![&Name::from("d")],
+    /// ]);
+    /// ```
+    pub fn cycles(&self) -> Vec<Vec<&Name>> {
+        let mut cycles: Vec<Vec<usize>> = Vec::new();
+        let mut assigned = vec![false; self.names.len()];
+        for id in 0..self.names.len() {
+            if assigned[id] {
+                continue;
+            }
+
+            let ancestors = self.reachable_ids(id, &self.parents);
+            let descendants = self.reachable_ids(id, &self.children);
+            let mut component: Vec<usize> = ancestors.intersection(&descendants).copied().collect();
+            if component.is_empty() {
+                continue;
+            }
+
+            component.sort_unstable();
+            for &member in &component {
+                assigned[member] = true;
+            }
+            cycles.push(component);
+        }
+
+        cycles
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| &self.names[id]).collect())
+            .collect()
+    }
+
+    /// Collects the indices reachable from `start` via `edges`, including `start`
+    /// itself only if it lies on a cycle.
+    fn reachable_ids(&self, start: usize, edges: &[Vec<usize>]) -> HashSet<usize> {
+        let mut seen = HashSet::new();
+        let mut pending = edges[start].clone();
+        while let Some(id) = pending.pop() {
+            if seen.insert(id) {
+                pending.extend(&edges[id]);
+            }
+        }
+        seen
+    }
+
+    /// Gets the constants and objects whose declared type may be used where a value
+    /// of type `r#type` is expected, constants first and each name at most once.
+    ///
+    /// ## Example
+    /// ```
+    /// # use pddl::{Constants, Name, Objects, Parser, Type, TypeHierarchy, Types};
+    /// let types = Types::from_str("(:types truck train - vehicle location)").unwrap();
+    /// let constants = Constants::from_str("(:constants depot - location)").unwrap();
+    /// let objects = Objects::from_str("(:objects t1 - truck t2 - train home - location)").unwrap();
+    /// let hierarchy = TypeHierarchy::new(&types);
+    ///
+    /// assert_eq!(
+    ///     hierarchy.objects_of_type(&Type::from("vehicle"), &constants, &objects),
+    ///     [&Name::from("t1"), &Name::from("t2")]
+    /// );
+    /// assert_eq!(
+    ///     hierarchy.objects_of_type(&Type::from("location"), &constants, &objects),
+    ///     [&Name::from("depot"), &Name::from("home")]
+    /// );
+    /// ```
+    pub fn objects_of_type<'a>(
+        &self,
+        r#type: &Type,
+        constants: &'a Constants,
+        objects: &'a Objects,
+    ) -> Vec<&'a Name> {
+        let mut seen = HashSet::new();
+        constants
+            .iter()
+            .chain(objects.iter())
+            .filter(|object| self.is_assignable(object.type_(), r#type))
+            .map(|object| object.value())
+            .filter(|&name| seen.insert(name))
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn names(names: &[&str]) -> Vec<Name> {
+        names.iter().map(|&name| Name::from(name)).collect()
+    }
+
+    #[test]
+    fn resolves_either_parents() {
+        let types =
+            Types::from_str("(:types amphibian - (either car boat) car boat - vehicle vehicle)")
+                .unwrap();
+        let hierarchy = TypeHierarchy::new(&types);
+
+        assert!(hierarchy.is_subtype(&"amphibian".into(), &"car".into()));
+        assert!(hierarchy.is_subtype(&"amphibian".into(), &"boat".into()));
+        assert!(hierarchy.is_subtype(&"amphibian".into(), &"vehicle".into()));
+        assert!(!hierarchy.is_subtype(&"car".into(), &"boat".into()));
+        assert_eq!(
+            hierarchy.ancestors(&"amphibian".into()),
+            names(&["car", "boat", "vehicle", "object"])
+                .iter()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            hierarchy.descendants(&"object".into()),
+            names(&["vehicle", "car", "boat", "amphibian"])
+                .iter()
+                .collect::<Vec<_>>()
+        );
+        assert!(hierarchy.cycles().is_empty());
+    }
+
+    #[test]
+    fn reports_undeclared_parents() {
+        let types = Types::from_str("(:types truck - vehicle vehicle2 - thing)").unwrap();
+        let hierarchy = TypeHierarchy::new(&types);
+
+        assert_eq!(
+            hierarchy.undeclared_parents(),
+            [
+                (&Name::from("truck"), &Name::from("vehicle")),
+                (&Name::from("vehicle2"), &Name::from("thing")),
+            ]
+        );
+        assert!(hierarchy.contains(&"vehicle".into()));
+        assert_eq!(
+            hierarchy.ancestors(&"truck".into()),
+            [&Name::from("vehicle"), &Name::from("object")]
+        );
+    }
+
+    #[test]
+    fn queries_terminate_on_cycles() {
+        let types = Types::from_str("(:types a - b b - a c - a)").unwrap();
+        let hierarchy = TypeHierarchy::new(&types);
+
+        assert!(hierarchy.is_subtype(&"a".into(), &"b".into()));
+        assert!(hierarchy.is_subtype(&"b".into(), &"a".into()));
+        assert!(!hierarchy.is_subtype(&"a".into(), &"c".into()));
+        assert_eq!(
+            hierarchy.cycles(),
+            [vec![&Name::from("a"), &Name::from("b")]]
+        );
+        assert_eq!(
+            hierarchy.descendants(&"a".into()),
+            [&Name::from("b"), &Name::from("c")]
+        );
+    }
+
+    #[test]
+    fn unknown_types_are_objects() {
+        let hierarchy = TypeHierarchy::new(&Types::default());
+
+        assert!(hierarchy.is_subtype(&"thing".into(), &"object".into()));
+        assert!(!hierarchy.is_subtype(&"object".into(), &"thing".into()));
+        assert!(hierarchy.ancestors(&"thing".into()).is_empty());
+        assert_eq!(
+            hierarchy.types().collect::<Vec<_>>(),
+            [&Name::from("object")]
+        );
+    }
+}
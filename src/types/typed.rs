@@ -1,6 +1,7 @@
 //! Contains typed elements.
 
 use crate::types::{PrimitiveType, Type};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A typed element.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`TypedList`](crate::TypedList).
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct Typed<O>(O, Type);
+pub struct Typed<O>(pub(crate) O, pub(crate) Type);
 
 impl<O> Typed<O> {
     pub const fn new(value: O, r#type: Type) -> Self {
@@ -64,16 +65,22 @@ pub trait ToTyped<T> {
     ) -> Typed<T>;
 }
 
-impl<'a, O> From<O> for Typed<O> {
+impl<O> From<O> for Typed<O> {
     fn from(value: O) -> Self {
         Typed::new_object(value)
     }
 }
 
-impl<'a, O> Deref for Typed<O> {
+impl<O> Deref for Typed<O> {
     type Target = O;
 
     fn deref(&self) -> &Self::Target {
         self.value()
     }
 }
+
+impl<O: Display> Display for Typed<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.0, self.1)
+    }
+}
@@ -1,4 +1,7 @@
+use crate::types::display::write_typed_list;
+use crate::types::Type;
 use crate::types::Typed;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A list of typed elements.
@@ -25,7 +28,7 @@ use std::ops::Deref;
 /// [`Objects`](crate::Objects) in [`Problem`](crate::Problem), [`PrefConGD`](crate::PrefConGD) and
 /// [`ConGD`](crate::ConGD).
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct TypedList<T>(Vec<Typed<T>>);
+pub struct TypedList<T>(pub(crate) Vec<Typed<T>>);
 
 impl<T> TypedList<T> {
     pub const fn new(list: Vec<Typed<T>>) -> Self {
@@ -84,3 +87,10 @@ impl<T> IntoIterator for TypedList<T> {
         self.0.into_iter()
     }
 }
+
+impl<T: Display> Display for TypedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<_> = self.0.iter().map(|t| (t.value(), t.type_())).collect();
+        write_typed_list(f, &values, &Type::OBJECT)
+    }
+}
@@ -1,6 +1,7 @@
 //! Provides type definitions;
 
 use crate::types::TypedNames;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A set of types.
@@ -8,7 +9,7 @@ use std::ops::Deref;
 /// ## Usage
 /// Used by [`Domain`](crate::Domain).
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct Types(TypedNames);
+pub struct Types(pub(crate) TypedNames);
 
 impl Types {
     pub const fn new(predicates: TypedNames) -> Self {
@@ -34,3 +35,13 @@ impl From<TypedNames> for Types {
         Types::new(value)
     }
 }
+
+impl Display for Types {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "(:types)")
+        } else {
+            write!(f, "(:types {})", self.0)
+        }
+    }
+}
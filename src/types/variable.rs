@@ -1,6 +1,7 @@
 //! Contains variables.
 
 use crate::types::{Name, PrimitiveType, ToTyped, Type, Typed};
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
 /// A variable name.
@@ -13,7 +14,7 @@ use std::ops::Deref;
 /// [`DurativeActionGoalDefinition`](crate::DurativeActionGoalDefinition), [`DurativeActionEffect`](crate::DurativeActionEffect),
 /// [`PrefConGD`](crate::PrefConGD) and [`ConGD`](crate::ConGD).
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
-pub struct Variable(Name);
+pub struct Variable(pub(crate) Name);
 
 impl Variable {
     #[inline(always)]
@@ -22,6 +23,7 @@ impl Variable {
     }
 
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(name: &str) -> Self {
         Self(Name::new(name))
     }
@@ -49,7 +51,7 @@ impl ToTyped<Variable> for Variable {
     }
 }
 
-impl<'a, T> From<T> for Variable
+impl<T> From<T> for Variable
 where
     T: Into<Name>,
 {
@@ -81,3 +83,9 @@ impl Deref for Variable {
         &self.0
     }
 }
+
+impl Display for Variable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "?{}", self.0)
+    }
+}
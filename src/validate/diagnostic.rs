@@ -0,0 +1,297 @@
+//! Contains validation results via the [`Diagnostic`] type.
+
+use crate::types::{Name, PrimitiveType, Requirement, Term, Type, Variable};
+use std::fmt::{Display, Formatter};
+
+/// An issue found by validating a domain or problem.
+///
+/// ## Usage
+/// Returned by [`validate`](crate::validate::validate),
+/// [`validate_domain`](crate::validate::validate_domain),
+/// [`check_requirements`](crate::validate::check_requirements) and
+/// [`check_problem_requirements`](crate::validate::check_problem_requirements)
+/// and [`DomainRegistry::resolve`](crate::resolve::DomainRegistry::resolve).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Diagnostic {
+    /// The problem refers to a different domain than the one it is validated against.
+    DomainNameMismatch {
+        /// The name of the domain.
+        expected: Name,
+        /// The domain name given in the `:domain` section of the problem.
+        found: Name,
+    },
+    /// A predicate is used but not declared in the `:predicates` section.
+    UndeclaredPredicate(Name),
+    /// A function is used but not declared in the `:functions` section.
+    UndeclaredFunction(Name),
+    /// A predicate or function is used with a different number of arguments than declared.
+    ArityMismatch {
+        /// The predicate or function symbol.
+        symbol: Name,
+        /// The number of declared parameters.
+        expected: usize,
+        /// The number of arguments given.
+        found: usize,
+    },
+    /// An object is declared with a type that is not declared in the `:types` section.
+    UndeclaredType {
+        /// The object.
+        object: Name,
+        /// The undeclared type.
+        r#type: PrimitiveType,
+    },
+    /// An object is used but declared neither in the `:objects` nor the `:constants` section.
+    UndeclaredObject(Name),
+    /// A variable is used in an action, durative action or derived predicate without being
+    /// bound by its parameters or an enclosing quantifier.
+    FreeVariable {
+        /// The unbound variable.
+        variable: Variable,
+        /// The action, durative action or derived predicate using the variable.
+        structure: Name,
+    },
+    /// An argument of a predicate has a type that is not a subtype of the declared parameter type.
+    TypeMismatch {
+        /// The predicate.
+        predicate: Name,
+        /// The argument.
+        argument: Term,
+        /// The declared type of the parameter.
+        expected: Type,
+        /// The type of the argument.
+        found: Type,
+    },
+    /// Two actions or durative actions share a name.
+    DuplicateAction(Name),
+    /// Two predicates share a name.
+    DuplicatePredicate(Name),
+    /// A type is declared more than once.
+    DuplicateType(Name),
+    /// A type is declared with a parent type that is not declared in the `:types` section.
+    UndeclaredParentType {
+        /// The declared type.
+        r#type: Name,
+        /// The undeclared parent type.
+        parent: Name,
+    },
+    /// Types are declared as their own ancestors.
+    TypeCycle(Vec<Name>),
+    /// Derived predicates depend negatively on themselves, so that they cannot be stratified.
+    NegativeDerivedCycle(Vec<Name>),
+    /// The `?duration` variable is used outside of a durative action.
+    DurationOutsideDurativeAction {
+        /// The action or derived predicate using `?duration`.
+        structure: Name,
+    },
+    /// Content requires a requirement that is not declared.
+    UndeclaredRequirement(Requirement),
+    /// A requirement is declared but not required by any content.
+    UnusedRequirement(Requirement),
+    /// A domain extends a domain that is not known.
+    UnknownParentDomain {
+        /// The extending domain.
+        domain: Name,
+        /// The unknown domain named in the `:extends` section.
+        parent: Name,
+    },
+    /// Domains extend themselves, directly or indirectly.
+    CyclicExtends(Vec<Name>),
+    /// A domain declares a symbol differently than a domain it extends.
+    ConflictingDefinition {
+        /// The kind of the symbol.
+        kind: SymbolKind,
+        /// The symbol.
+        name: Name,
+        /// The domain whose declaration is used.
+        domain: Name,
+        /// The domain whose declaration is replaced.
+        previous: Name,
+    },
+}
+
+/// The kind of a declared symbol.
+///
+/// ## Usage
+/// Used by [`Diagnostic::ConflictingDefinition`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SymbolKind {
+    /// A type declared in the `:types` section.
+    Type,
+    /// A constant declared in the `:constants` section.
+    Constant,
+    /// A predicate declared in the `:predicates` section.
+    Predicate,
+    /// A function declared in the `:functions` section.
+    Function,
+    /// An action or durative action.
+    Action,
+}
+
+impl Display for SymbolKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SymbolKind::Type => write!(f, "type"),
+            SymbolKind::Constant => write!(f, "constant"),
+            SymbolKind::Predicate => write!(f, "predicate"),
+            SymbolKind::Function => write!(f, "function"),
+            SymbolKind::Action => write!(f, "action"),
+        }
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::DomainNameMismatch { expected, found } => write!(
+                f,
+                "problem refers to domain `{found}`, but the domain is named `{expected}`"
+            ),
+            Diagnostic::UndeclaredPredicate(predicate) => {
+                write!(f, "undeclared predicate `{predicate}`")
+            }
+            Diagnostic::UndeclaredFunction(function) => {
+                write!(f, "undeclared function `{function}`")
+            }
+            Diagnostic::ArityMismatch {
+                symbol,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{symbol}` expects {expected} argument{}, found {found}",
+                if *expected == 1 { "" } else { "s" }
+            ),
+            Diagnostic::UndeclaredType { object, r#type } => {
+                write!(f, "object `{object}` has undeclared type `{type}`")
+            }
+            Diagnostic::UndeclaredObject(object) => write!(f, "undeclared object `{object}`"),
+            Diagnostic::FreeVariable {
+                variable,
+                structure,
+            } => write!(f, "free variable `{variable}` in `{structure}`"),
+            Diagnostic::TypeMismatch {
+                predicate,
+                argument,
+                expected,
+                found,
+            } => write!(
+                f,
+                "argument `{argument}` of `{predicate}` has type `{found}`, expected `{expected}`"
+            ),
+            Diagnostic::DuplicateAction(action) => write!(f, "duplicate action `{action}`"),
+            Diagnostic::DuplicatePredicate(predicate) => {
+                write!(f, "duplicate predicate `{predicate}`")
+            }
+            Diagnostic::DuplicateType(r#type) => write!(f, "duplicate type `{type}`"),
+            Diagnostic::UndeclaredParentType { r#type, parent } => {
+                write!(f, "type `{type}` has undeclared parent type `{parent}`")
+            }
+            Diagnostic::TypeCycle(types) => {
+                write!(f, "cyclic type hierarchy involving ")?;
+                for (i, r#type) in types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{type}`")?;
+                }
+                Ok(())
+            }
+            Diagnostic::NegativeDerivedCycle(predicates) => {
+                write!(f, "negative cycle among derived predicates ")?;
+                for (i, predicate) in predicates.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{predicate}`")?;
+                }
+                Ok(())
+            }
+            Diagnostic::DurationOutsideDurativeAction { structure } => write!(
+                f,
+                "`?duration` used in `{structure}`, which is not a durative action"
+            ),
+            Diagnostic::UndeclaredRequirement(requirement) => {
+                write!(f, "requirement `{requirement}` is used but not declared")
+            }
+            Diagnostic::UnusedRequirement(requirement) => {
+                write!(f, "requirement `{requirement}` is declared but not used")
+            }
+            Diagnostic::UnknownParentDomain { domain, parent } => {
+                write!(f, "domain `{domain}` extends unknown domain `{parent}`")
+            }
+            Diagnostic::CyclicExtends(domains) => {
+                write!(f, "cyclic `:extends` involving ")?;
+                for (i, domain) in domains.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "`{domain}`")?;
+                }
+                Ok(())
+            }
+            Diagnostic::ConflictingDefinition {
+                kind,
+                name,
+                domain,
+                previous,
+            } => write!(
+                f,
+                "{kind} `{name}` of domain `{domain}` conflicts with its definition in `{previous}`"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_works() {
+        assert_eq!(
+            Diagnostic::ArityMismatch {
+                symbol: "at".into(),
+                expected: 1,
+                found: 2
+            }
+            .to_string(),
+            "`at` expects 1 argument, found 2"
+        );
+        assert_eq!(
+            Diagnostic::UndeclaredType {
+                object: "home".into(),
+                r#type: "place".into()
+            }
+            .to_string(),
+            "object `home` has undeclared type `place`"
+        );
+        assert_eq!(
+            Diagnostic::TypeMismatch {
+                predicate: "at".into(),
+                argument: Term::Variable("x".into()),
+                expected: "location".into(),
+                found: vec!["truck", "train"].into()
+            }
+            .to_string(),
+            "argument `?x` of `at` has type `(either truck train)`, expected `location`"
+        );
+        assert_eq!(
+            Diagnostic::TypeCycle(vec!["a".into(), "b".into()]).to_string(),
+            "cyclic type hierarchy involving `a`, `b`"
+        );
+        assert_eq!(
+            Diagnostic::NegativeDerivedCycle(vec!["a".into()]).to_string(),
+            "negative cycle among derived predicates `a`"
+        );
+        assert_eq!(
+            Diagnostic::ConflictingDefinition {
+                kind: SymbolKind::Predicate,
+                name: "at".into(),
+                domain: "trucks".into(),
+                previous: "vehicles".into()
+            }
+            .to_string(),
+            "predicate `at` of domain `trucks` conflicts with its definition in `vehicles`"
+        );
+    }
+}
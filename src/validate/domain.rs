@@ -0,0 +1,545 @@
+//! Checks that a [`Domain`] is well-formed.
+
+use crate::index::DomainIndex;
+use crate::types::{
+    ActionDefinition, AtomicFormula, AtomicFormulaSkeleton, AtomicFunctionSkeleton, ConGD,
+    DerivedPredicate, DerivedPredicateGraph, Domain, DurativeActionDefinition,
+    DurativeActionEffect, DurativeActionGoalDefinition, FHead, ForallCEffect, FunctionTerm,
+    GoalDefinition, Name, PreconditionGoalDefinition, Requirement, StructureDef, Term, Type, Typed,
+    TypedVariables, Variable,
+};
+use crate::validate::signatures::{check_function, check_predicate};
+use crate::validate::Diagnostic;
+use crate::visit::{self, Visit};
+use std::collections::HashSet;
+
+/// The name of the `?duration` variable of durative actions.
+const DURATION: &str = "duration";
+
+/// Checks that the `domain` is well-formed on its own.
+///
+/// The following issues are reported, each at most once:
+/// - variables in actions, durative actions and derived predicates that are bound neither
+///   by the parameters nor by an enclosing `forall` or `exists`,
+/// - predicates and functions that are not declared, or used with a different number
+///   of arguments than declared,
+/// - predicate arguments whose type is not a subtype of the declared parameter type,
+///   if the domain requires [`:typing`](Requirement::Typing),
+/// - action, predicate or type names that are declared more than once,
+/// - types declared with an undeclared parent type, and types that are their own ancestors,
+/// - derived predicates that depend negatively on themselves and thus cannot be stratified,
+/// - uses of `?duration` outside of durative actions.
+///
+/// An empty result means that no issues were found.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser};
+/// use pddl::validate::{validate_domain, Diagnostic};
+///
+/// let domain = Domain::from_str(r#"(define (domain logistics)
+///     (:requirements :typing)
+///     (:types truck location)
+///     (:predicates (at ?t - truck ?l - location))
+///     (:action drive
+///         :parameters (?t - truck ?from - location)
+///         :precondition (at ?t ?from)
+///         :effect (and (not (at ?t ?from)) (at ?t ?to))))"#).unwrap();
+///
+/// assert_eq!(validate_domain(&domain), [Diagnostic::FreeVariable {
+///     variable: "to".into(),
+///     structure: "drive".into(),
+/// }]);
+/// ```
+pub fn validate_domain(domain: &Domain) -> Vec<Diagnostic> {
+    let mut checker = DomainChecker::new(domain);
+    checker.check_declarations(domain);
+    checker.visit_domain(domain);
+    checker.diagnostics
+}
+
+/// Checks the structure definitions of a domain against its declarations.
+struct DomainChecker<'ast> {
+    index: DomainIndex,
+    /// Whether argument types are checked.
+    typing: bool,
+    /// The variables in scope, innermost last.
+    bound: Vec<&'ast Typed<Variable>>,
+    /// The name of the structure being checked and whether it is a durative action.
+    structure: Option<(&'ast Name, bool)>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> DomainChecker<'ast> {
+    fn new(domain: &'ast Domain) -> Self {
+        Self {
+            index: DomainIndex::new(domain),
+            typing: domain
+                .requirements()
+                .to_effective()
+                .contains(&Requirement::Typing),
+            bound: Vec::new(),
+            structure: None,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Adds the `diagnostic` unless it was already reported.
+    fn report(&mut self, diagnostic: Diagnostic) {
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Reports duplicate action, predicate and type names, as well as inconsistent types.
+    fn check_declarations(&mut self, domain: &Domain) {
+        let mut actions = HashSet::new();
+        for def in domain.structure().iter() {
+            let name: &Name = match def {
+                StructureDef::Action(action) => action.symbol(),
+                StructureDef::DurativeAction(action) => action.symbol(),
+                StructureDef::Derived(_) => continue,
+            };
+            if !actions.insert(name) {
+                self.report(Diagnostic::DuplicateAction(name.clone()));
+            }
+        }
+
+        let mut predicates = HashSet::new();
+        for predicate in domain.predicates().iter() {
+            let name: &Name = predicate.predicate();
+            if !predicates.insert(name) {
+                self.report(Diagnostic::DuplicatePredicate(name.clone()));
+            }
+        }
+
+        let mut types = HashSet::new();
+        for r#type in domain.types().iter() {
+            if !types.insert(r#type.value()) {
+                self.report(Diagnostic::DuplicateType(r#type.value().clone()));
+            }
+        }
+
+        let hierarchy = self.index.hierarchy();
+        let undeclared = hierarchy
+            .undeclared_parents()
+            .into_iter()
+            .map(|(r#type, parent)| Diagnostic::UndeclaredParentType {
+                r#type: r#type.clone(),
+                parent: parent.clone(),
+            });
+        let cycles = hierarchy
+            .cycles()
+            .into_iter()
+            .map(|cycle| Diagnostic::TypeCycle(cycle.into_iter().cloned().collect()));
+        let derived = DerivedPredicateGraph::new(domain.structure());
+        let negative_cycles = derived
+            .negative_cycles()
+            .into_iter()
+            .map(|cycle| Diagnostic::NegativeDerivedCycle(cycle.into_iter().cloned().collect()));
+        let diagnostics: Vec<_> = undeclared.chain(cycles).chain(negative_cycles).collect();
+        for diagnostic in diagnostics {
+            self.report(diagnostic);
+        }
+    }
+
+    /// Runs `f` with the `variables` in scope.
+    fn with_bound<F: FnOnce(&mut Self)>(&mut self, variables: &'ast TypedVariables, f: F) {
+        let depth = self.bound.len();
+        self.bound.extend(variables.iter());
+        f(self);
+        self.bound.truncate(depth);
+    }
+
+    /// Runs `f` while checking the structure with the given `name` and `parameters`.
+    fn with_structure<F: FnOnce(&mut Self)>(
+        &mut self,
+        name: &'ast Name,
+        durative: bool,
+        parameters: &'ast TypedVariables,
+        f: F,
+    ) {
+        self.structure = Some((name, durative));
+        self.with_bound(parameters, f);
+        self.structure = None;
+    }
+
+    /// Gets the declared type of a variable in scope.
+    fn variable_type(&self, variable: &Variable) -> Option<&Type> {
+        self.bound
+            .iter()
+            .rev()
+            .find(|bound| bound.value() == variable)
+            .map(|bound| bound.type_())
+    }
+
+    /// Gets the declared type of a term, if known.
+    fn term_type(&self, term: &Term) -> Option<&Type> {
+        match term {
+            Term::Name(name) => self.index.constant_type(name),
+            Term::Variable(variable) => self.variable_type(variable),
+            Term::Function(_) => None,
+        }
+    }
+
+    /// Checks the declaration, arity and argument types of a predicate.
+    fn use_predicate(&mut self, predicate: &Name, arguments: &[Term]) {
+        if let Some(diagnostic) = check_predicate(&self.index, predicate, arguments.len()) {
+            self.report(diagnostic);
+            return;
+        }
+        if !self.typing {
+            return;
+        }
+
+        let Some(signature) = self.index.predicates().lookup(predicate) else {
+            return;
+        };
+        let mismatches: Vec<_> = arguments
+            .iter()
+            .zip(signature.parameters())
+            .filter_map(|(argument, expected)| {
+                let found = self.term_type(argument)?;
+                (!self.index.hierarchy().is_assignable(found, expected)).then(|| {
+                    Diagnostic::TypeMismatch {
+                        predicate: predicate.clone(),
+                        argument: argument.clone(),
+                        expected: expected.clone(),
+                        found: found.clone(),
+                    }
+                })
+            })
+            .collect();
+        for diagnostic in mismatches {
+            self.report(diagnostic);
+        }
+    }
+
+    fn use_function(&mut self, function: &Name, arity: usize) {
+        if let Some(diagnostic) = check_function(&self.index, function, arity) {
+            self.report(diagnostic);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for DomainChecker<'ast> {
+    // Declarations are not uses.
+    fn visit_atomic_formula_skeleton(&mut self, _node: &'ast AtomicFormulaSkeleton) {}
+    fn visit_atomic_function_skeleton(&mut self, _node: &'ast AtomicFunctionSkeleton) {}
+
+    fn visit_action_definition(&mut self, node: &'ast ActionDefinition) {
+        self.with_structure(node.symbol(), false, node.parameters(), |checker| {
+            visit::walk_action_definition(checker, node)
+        });
+    }
+
+    fn visit_durative_action_definition(&mut self, node: &'ast DurativeActionDefinition) {
+        self.with_structure(node.symbol(), true, node.parameters(), |checker| {
+            visit::walk_durative_action_definition(checker, node)
+        });
+    }
+
+    fn visit_derived_predicate(&mut self, node: &'ast DerivedPredicate) {
+        let predicate = node.predicate();
+        self.with_structure(
+            predicate.predicate(),
+            false,
+            predicate.variables(),
+            |checker| visit::walk_derived_predicate(checker, node),
+        );
+    }
+
+    fn visit_typed_variables(&mut self, _node: &'ast TypedVariables) {
+        // Variable declarations are brought into scope by their binders.
+    }
+
+    fn visit_variable(&mut self, node: &'ast Variable) {
+        let Some((structure, durative)) = self.structure else {
+            return;
+        };
+        if self.variable_type(node).is_some() {
+            return;
+        }
+
+        if **node == DURATION {
+            if !durative {
+                self.report(Diagnostic::DurationOutsideDurativeAction {
+                    structure: structure.clone(),
+                });
+            }
+        } else {
+            self.report(Diagnostic::FreeVariable {
+                variable: node.clone(),
+                structure: structure.clone(),
+            });
+        }
+    }
+
+    fn visit_goal_definition(&mut self, node: &'ast GoalDefinition) {
+        match node {
+            GoalDefinition::Exists(variables, gd) | GoalDefinition::ForAll(variables, gd) => {
+                self.with_bound(variables, |checker| checker.visit_goal_definition(gd))
+            }
+            _ => visit::walk_goal_definition(self, node),
+        }
+    }
+
+    fn visit_precondition_goal_definition(&mut self, node: &'ast PreconditionGoalDefinition) {
+        match node {
+            PreconditionGoalDefinition::Forall(variables, gds) => self
+                .with_bound(variables, |checker| {
+                    checker.visit_precondition_goal_definitions(gds)
+                }),
+            _ => visit::walk_precondition_goal_definition(self, node),
+        }
+    }
+
+    fn visit_forall_c_effect(&mut self, node: &'ast ForallCEffect) {
+        self.with_bound(&node.variables, |checker| {
+            checker.visit_effects(&node.effects)
+        });
+    }
+
+    fn visit_durative_action_goal_definition(&mut self, node: &'ast DurativeActionGoalDefinition) {
+        match node {
+            DurativeActionGoalDefinition::Forall(variables, gd) => self
+                .with_bound(variables, |checker| {
+                    checker.visit_durative_action_goal_definition(gd)
+                }),
+            _ => visit::walk_durative_action_goal_definition(self, node),
+        }
+    }
+
+    fn visit_durative_action_effect(&mut self, node: &'ast DurativeActionEffect) {
+        match node {
+            DurativeActionEffect::Forall(variables, effect) => self
+                .with_bound(variables, |checker| {
+                    checker.visit_durative_action_effect(effect)
+                }),
+            _ => visit::walk_durative_action_effect(self, node),
+        }
+    }
+
+    fn visit_con_gd(&mut self, node: &'ast ConGD) {
+        match node {
+            ConGD::Forall(variables, gd) => {
+                self.with_bound(variables, |checker| checker.visit_con_gd(gd))
+            }
+            _ => visit::walk_con_gd(self, node),
+        }
+    }
+
+    fn visit_term_atomic_formula(&mut self, node: &'ast AtomicFormula<Term>) {
+        if let AtomicFormula::Predicate(formula) = node {
+            self.use_predicate(formula.predicate(), formula.values());
+        }
+        visit::walk_term_atomic_formula(self, node)
+    }
+
+    fn visit_name_atomic_formula(&mut self, node: &'ast AtomicFormula<Name>) {
+        if let AtomicFormula::Predicate(formula) = node {
+            let arguments: Vec<_> = formula.values().iter().cloned().map(Term::Name).collect();
+            self.use_predicate(formula.predicate(), &arguments);
+        }
+    }
+
+    fn visit_function_term(&mut self, node: &'ast FunctionTerm) {
+        self.use_function(node.symbol(), node.terms().len());
+        visit::walk_function_term(self, node)
+    }
+
+    fn visit_f_head(&mut self, node: &'ast FHead) {
+        match node {
+            FHead::Simple(symbol) => self.use_function(symbol, 0),
+            FHead::WithTerms(symbol, terms) => self.use_function(symbol, terms.len()),
+        }
+        visit::walk_f_head(self, node)
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn accepts_well_formed_domain() {
+        let domain = Domain::from_str(
+            r#"(define (domain logistics)
+                (:requirements :typing :fluents :durative-actions :conditional-effects)
+                (:types truck train - vehicle vehicle package - object location)
+                (:constants depot - location)
+                (:predicates (at ?v - (either vehicle package) ?l - location)
+                             (in ?p - package ?v - vehicle))
+                (:functions (distance ?from ?to - location))
+                (:action drive
+                    :parameters (?t - truck ?from ?to - location)
+                    :precondition (and (at ?t ?from) (exists (?p - package) (in ?p ?t)))
+                    :effect (and (not (at ?t ?from)) (at ?t ?to)
+                                 (forall (?p - package)
+                                     (when (in ?p ?t) (at ?p ?to)))))
+                (:durative-action ship
+                    :parameters (?t - train ?to - location)
+                    :duration (= ?duration (distance depot ?to))
+                    :condition (at start (at ?t depot))
+                    :effect (at end (at ?t ?to))))"#,
+        )
+        .unwrap();
+
+        assert_eq!(validate_domain(&domain), []);
+    }
+
+    #[test]
+    fn reports_free_variables_outside_their_scope() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:predicates (p ?x) (q ?x ?y))
+                (:action a
+                    :parameters (?x)
+                    :precondition (and (exists (?y) (q ?x ?y)) (p ?y))
+                    :effect (forall (?z) (q ?z ?w)))
+                (:derived (p ?x) (q ?x ?v)))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate_domain(&domain),
+            [
+                Diagnostic::FreeVariable {
+                    variable: "y".into(),
+                    structure: "a".into()
+                },
+                Diagnostic::FreeVariable {
+                    variable: "w".into(),
+                    structure: "a".into()
+                },
+                Diagnostic::FreeVariable {
+                    variable: "v".into(),
+                    structure: "p".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_arity_and_type_mismatches() {
+        let domain = Domain::from_str(
+            r#"(define (domain logistics)
+                (:requirements :typing)
+                (:types truck location)
+                (:constants depot - location)
+                (:predicates (at ?t - truck ?l - location))
+                (:action drive
+                    :parameters (?t - truck ?l - location)
+                    :precondition (and (at ?t) (at ?l ?t))
+                    :effect (at depot depot)))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate_domain(&domain),
+            [
+                Diagnostic::ArityMismatch {
+                    symbol: "at".into(),
+                    expected: 2,
+                    found: 1
+                },
+                Diagnostic::TypeMismatch {
+                    predicate: "at".into(),
+                    argument: Term::Variable("l".into()),
+                    expected: "truck".into(),
+                    found: "location".into(),
+                },
+                Diagnostic::TypeMismatch {
+                    predicate: "at".into(),
+                    argument: Term::Variable("t".into()),
+                    expected: "location".into(),
+                    found: "truck".into(),
+                },
+                Diagnostic::TypeMismatch {
+                    predicate: "at".into(),
+                    argument: Term::Name("depot".into()),
+                    expected: "truck".into(),
+                    found: "location".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_duplicates() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:types a b a)
+                (:predicates (p) (p ?x))
+                (:action go :parameters () :effect (p))
+                (:durative-action go :parameters () :duration (= ?duration 1)
+                    :condition (and) :effect (at end (p))))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate_domain(&domain),
+            [
+                Diagnostic::DuplicateAction("go".into()),
+                Diagnostic::DuplicatePredicate("p".into()),
+                Diagnostic::DuplicateType("a".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_inconsistent_types() {
+        let domain =
+            Domain::from_str("(define (domain d) (:types truck - vehicle a - b b - a))").unwrap();
+
+        assert_eq!(
+            validate_domain(&domain),
+            [
+                Diagnostic::UndeclaredParentType {
+                    r#type: "truck".into(),
+                    parent: "vehicle".into()
+                },
+                Diagnostic::TypeCycle(vec!["a".into(), "b".into()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_negative_derived_cycles() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:predicates (p) (a) (b))
+                (:derived (a) (imply (b) (p)))
+                (:derived (b) (a)))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate_domain(&domain),
+            [Diagnostic::NegativeDerivedCycle(vec![
+                "a".into(),
+                "b".into()
+            ])]
+        );
+    }
+
+    #[test]
+    fn reports_duration_outside_durative_actions() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:predicates (p ?x))
+                (:action a :parameters () :precondition (p ?duration))
+                (:durative-action b :parameters () :duration (= ?duration 1)
+                    :condition (at start (p ?duration)) :effect (and)))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate_domain(&domain),
+            [Diagnostic::DurationOutsideDurativeAction {
+                structure: "a".into()
+            }]
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Provides semantic checks of parsed domains and problems.
+//!
+//! The parser only verifies that its input is syntactically valid PDDL. The functions in
+//! this module check the parsed values for consistency, e.g. whether the predicates used
+//! in a [`Problem`](crate::Problem) are declared by its [`Domain`](crate::Domain), and
+//! report each issue as a [`Diagnostic`].
+
+mod diagnostic;
+mod domain;
+mod problem;
+mod requirements;
+mod signatures;
+
+pub use diagnostic::{Diagnostic, SymbolKind};
+pub use domain::validate_domain;
+pub use problem::validate;
+pub use requirements::{
+    check_problem_requirements, check_requirements, infer_problem_requirements, infer_requirements,
+};
@@ -0,0 +1,290 @@
+//! Validates a [`Problem`] against its [`Domain`].
+
+use crate::index::DomainIndex;
+use crate::types::{
+    AtomicFormula, BasicFunctionTerm, Domain, FHead, FunctionTerm, InitElement, Name, Problem,
+    Term, TYPE_OBJECT,
+};
+use crate::validate::signatures::{check_function, check_predicate};
+use crate::validate::Diagnostic;
+use crate::visit::{self, Visit};
+use std::collections::HashSet;
+
+/// Checks that the `problem` is consistent with the `domain` it refers to.
+///
+/// The following issues are reported, each at most once:
+/// - a `:domain` name that differs from the name of the `domain`,
+/// - predicates and functions used in `:init` or `:goal` that the `domain` does not declare,
+/// - predicates and functions used with a different number of arguments than declared,
+/// - objects declared with a type that is not declared in the `domain`'s `:types`,
+/// - objects used in `:init` that are declared neither as `:objects` nor as domain `:constants`.
+///
+/// An empty result means that no issues were found.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Problem};
+/// use pddl::validate::{validate, Diagnostic};
+///
+/// let domain = Domain::from_str(r#"(define (domain briefcase-world)
+///     (:types location physob)
+///     (:predicates (at ?x - physob ?l - location)))"#).unwrap();
+///
+/// let problem = Problem::from_str(r#"(define (problem get-paid)
+///     (:domain briefcase-world)
+///     (:objects home office - location P - physob)
+///     (:init (at P home) (in P))
+///     (:goal (at P office home)))"#).unwrap();
+///
+/// assert_eq!(validate(&domain, &problem), [
+///     Diagnostic::UndeclaredPredicate("in".into()),
+///     Diagnostic::ArityMismatch { symbol: "at".into(), expected: 2, found: 3 },
+/// ]);
+/// ```
+pub fn validate(domain: &Domain, problem: &Problem) -> Vec<Diagnostic> {
+    let mut validator = ProblemValidator::new(domain, problem);
+
+    if domain.name() != problem.domain() {
+        validator.report(Diagnostic::DomainNameMismatch {
+            expected: domain.name().clone(),
+            found: problem.domain().clone(),
+        });
+    }
+
+    validator.check_object_types(domain, problem);
+    validator.visit_init_elements(problem.init());
+    validator.visit_goal_def(problem.goal());
+    validator.diagnostics
+}
+
+/// Collects the declarations of a domain and problem and checks their uses against them.
+struct ProblemValidator<'ast> {
+    index: DomainIndex,
+    /// The declared objects and constants.
+    objects: HashSet<&'ast Name>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'ast> ProblemValidator<'ast> {
+    fn new(domain: &'ast Domain, problem: &'ast Problem) -> Self {
+        let objects = domain
+            .constants()
+            .iter()
+            .chain(problem.objects().iter())
+            .map(|object| object.value())
+            .collect();
+
+        Self {
+            index: DomainIndex::new(domain),
+            objects,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Adds the `diagnostic` unless it was already reported.
+    fn report(&mut self, diagnostic: Diagnostic) {
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Reports objects whose types are neither declared nor the implicit `object` type.
+    fn check_object_types(&mut self, domain: &Domain, problem: &Problem) {
+        let object = TYPE_OBJECT;
+        let types: HashSet<&Name> = domain
+            .types()
+            .iter()
+            .map(|r#type| r#type.value())
+            .chain([object.name()])
+            .collect();
+
+        for object in problem.objects().iter() {
+            for r#type in object.type_().primitives() {
+                if !types.contains(r#type.name()) {
+                    self.report(Diagnostic::UndeclaredType {
+                        object: object.value().clone(),
+                        r#type: r#type.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn use_predicate(&mut self, predicate: &Name, arity: usize) {
+        if let Some(diagnostic) = check_predicate(&self.index, predicate, arity) {
+            self.report(diagnostic);
+        }
+    }
+
+    fn use_function(&mut self, function: &Name, arity: usize) {
+        if let Some(diagnostic) = check_function(&self.index, function, arity) {
+            self.report(diagnostic);
+        }
+    }
+
+    fn use_object(&mut self, object: &Name) {
+        if !self.objects.contains(object) {
+            self.report(Diagnostic::UndeclaredObject(object.clone()));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ProblemValidator<'ast> {
+    fn visit_init_element(&mut self, node: &'ast InitElement) {
+        if let InitElement::IsObject(_, value) = node {
+            self.use_object(value);
+        }
+        visit::walk_init_element(self, node)
+    }
+
+    fn visit_basic_function_term(&mut self, node: &'ast BasicFunctionTerm) {
+        self.use_function(node.symbol(), node.names().len());
+        for name in node.names() {
+            self.use_object(name);
+        }
+    }
+
+    fn visit_name_atomic_formula(&mut self, node: &'ast AtomicFormula<Name>) {
+        match node {
+            AtomicFormula::Equality(formula) => {
+                self.use_object(formula.first());
+                self.use_object(formula.second());
+            }
+            AtomicFormula::Predicate(formula) => {
+                self.use_predicate(formula.predicate(), formula.values().len());
+                for name in formula.values() {
+                    self.use_object(name);
+                }
+            }
+        }
+    }
+
+    fn visit_term_atomic_formula(&mut self, node: &'ast AtomicFormula<Term>) {
+        if let AtomicFormula::Predicate(formula) = node {
+            self.use_predicate(formula.predicate(), formula.values().len());
+        }
+        visit::walk_term_atomic_formula(self, node)
+    }
+
+    fn visit_function_term(&mut self, node: &'ast FunctionTerm) {
+        self.use_function(node.symbol(), node.terms().len());
+        visit::walk_function_term(self, node)
+    }
+
+    fn visit_f_head(&mut self, node: &'ast FHead) {
+        match node {
+            FHead::Simple(symbol) => self.use_function(symbol, 0),
+            FHead::WithTerms(symbol, terms) => self.use_function(symbol, terms.len()),
+        }
+        visit::walk_f_head(self, node)
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    const DOMAIN: &str = r#"(define (domain briefcase-world)
+        (:requirements :strips :typing :fluents)
+        (:types location physob)
+        (:constants B - physob)
+        (:predicates (at ?x - physob ?y - location) (in ?x - physob))
+        (:functions (distance ?from ?to - location) (total-cost)))"#;
+
+    #[test]
+    fn accepts_consistent_problem() {
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem get-paid)
+                (:domain briefcase-world)
+                (:objects home office - location P - physob)
+                (:init (at B home) (at P home) (in P)
+                       (= (distance home office) 10) (= (total-cost) 0))
+                (:goal (and (at B office) (< (distance home office) (total-cost)))))"#,
+        )
+        .unwrap();
+
+        assert_eq!(validate(&domain, &problem), []);
+    }
+
+    #[test]
+    fn reports_domain_name_mismatch() {
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        let problem =
+            Problem::from_str("(define (problem p) (:domain logistics) (:init) (:goal (and)))")
+                .unwrap();
+
+        assert_eq!(
+            validate(&domain, &problem),
+            [Diagnostic::DomainNameMismatch {
+                expected: "briefcase-world".into(),
+                found: "logistics".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_undeclared_symbols_and_arity() {
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem get-paid)
+                (:domain briefcase-world)
+                (:objects home office - location P - physob)
+                (:init (at P) (on P home) (on P office) (= (speed P) 1))
+                (:goal (and (in P office) (> (distance home) 0))))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate(&domain, &problem),
+            [
+                Diagnostic::ArityMismatch {
+                    symbol: "at".into(),
+                    expected: 2,
+                    found: 1
+                },
+                Diagnostic::UndeclaredPredicate("on".into()),
+                Diagnostic::UndeclaredFunction("speed".into()),
+                Diagnostic::ArityMismatch {
+                    symbol: "in".into(),
+                    expected: 1,
+                    found: 2
+                },
+                Diagnostic::ArityMismatch {
+                    symbol: "distance".into(),
+                    expected: 2,
+                    found: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_undeclared_types_and_objects() {
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem get-paid)
+                (:domain briefcase-world)
+                (:objects home - place P - (either physob thing) D)
+                (:init (at P home) (at D office) (at B office))
+                (:goal (at P office)))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            validate(&domain, &problem),
+            [
+                Diagnostic::UndeclaredType {
+                    object: "home".into(),
+                    r#type: "place".into()
+                },
+                Diagnostic::UndeclaredType {
+                    object: "P".into(),
+                    r#type: "thing".into()
+                },
+                Diagnostic::UndeclaredObject("office".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,585 @@
+//! Infers the requirements a [`Domain`] or [`Problem`] actually uses.
+
+use crate::types::{
+    AssignOp, AtomicFormula, ConGD, DOp, Domain, DurationConstraint, DurationValue,
+    DurativeActionEffect, DurativeActionGoalDefinition, FComp, FExp, FExpDa, FExpT, FHead,
+    FunctionType, GoalDefinition, InitElement, MetricSpec, Name, Optimization, PEffect,
+    PreconditionGoalDefinition, PrefConGD, PrefConGDs, PrefTimedGD, Preference, Problem,
+    Requirement, Requirements, StructureDef, Term, TimedEffect, Type, Typed, Types, Variable,
+};
+use crate::types::{CEffect, MetricFExp};
+use crate::validate::Diagnostic;
+use crate::visit::{self, Visit};
+use std::collections::BTreeSet;
+
+/// The function whose use alone does not require [`:numeric-fluents`](Requirement::NumericFluents).
+const TOTAL_COST: &str = "total-cost";
+
+/// The duration of a plan, which metrics may use without numeric fluents.
+const TOTAL_TIME: &str = "total-time";
+
+/// Computes the requirements that the content of the `domain` needs.
+///
+/// The result only contains fine-grained requirements, i.e. never shorthands such as
+/// [`:adl`](Requirement::Adl) or [`:fluents`](Requirement::Fluents). The implicit
+/// [`:strips`](Requirement::Strips) requirement is never included. Numeric fluents that
+/// only keep track of the `total-cost` as described by
+/// [`:action-costs`](Requirement::ActionCosts) yield that requirement instead of
+/// [`:numeric-fluents`](Requirement::NumericFluents).
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Requirement};
+/// use pddl::validate::infer_requirements;
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:predicates (p ?x) (q ?x))
+///     (:action a
+///         :parameters (?x)
+///         :precondition (or (p ?x) (not (q ?x)))
+///         :effect (when (p ?x) (q ?x))))"#).unwrap();
+///
+/// assert!(infer_requirements(&domain).into_iter().eq([
+///     Requirement::NegativePreconditions,
+///     Requirement::DisjunctivePreconditions,
+///     Requirement::ConditionalEffects,
+/// ]));
+/// ```
+pub fn infer_requirements(domain: &Domain) -> BTreeSet<Requirement> {
+    let mut inference = RequirementInference::default();
+    inference.visit_domain(domain);
+    inference.finish()
+}
+
+/// Computes the requirements that the content of the `problem` needs.
+///
+/// See [`infer_requirements`] for the requirements of domains.
+pub fn infer_problem_requirements(problem: &Problem) -> BTreeSet<Requirement> {
+    let mut inference = RequirementInference::default();
+    inference.visit_problem(problem);
+    inference.finish()
+}
+
+/// Compares the declared requirements of the `domain` with the ones it uses.
+///
+/// Reports each used requirement that no declared requirement covers as
+/// [`Diagnostic::UndeclaredRequirement`], followed by each declared requirement
+/// that covers no used requirement as [`Diagnostic::UnusedRequirement`].
+/// An explicit [`:strips`](Requirement::Strips) requirement is never reported as unused.
+///
+/// ## Example
+/// ```
+/// # use pddl::{Domain, Parser, Requirement};
+/// use pddl::validate::{check_requirements, Diagnostic};
+///
+/// let domain = Domain::from_str(r#"(define (domain d)
+///     (:requirements :strips :typing :equality)
+///     (:predicates (p ?x) (q ?x))
+///     (:action a
+///         :parameters (?x ?y)
+///         :precondition (and (p ?x) (not (= ?x ?y)))
+///         :effect (q ?y)))"#).unwrap();
+///
+/// assert_eq!(check_requirements(&domain), [
+///     Diagnostic::UndeclaredRequirement(Requirement::NegativePreconditions),
+///     Diagnostic::UnusedRequirement(Requirement::Typing),
+/// ]);
+/// ```
+pub fn check_requirements(domain: &Domain) -> Vec<Diagnostic> {
+    let used = infer_requirements(domain);
+    compare(domain.requirements(), &used, &used)
+}
+
+/// Compares the requirements declared by the `problem` and its `domain` with the ones
+/// the `problem` uses.
+///
+/// Reports requirements used by the `problem` that neither the `problem` nor the
+/// `domain` declares, and requirements declared by the `problem` that neither uses.
+/// Use [`check_requirements`] to check the `domain` itself.
+pub fn check_problem_requirements(domain: &Domain, problem: &Problem) -> Vec<Diagnostic> {
+    let used = infer_problem_requirements(problem);
+    let mut all = infer_requirements(domain);
+    all.extend(used.iter().copied());
+
+    let mut declared = domain.requirements().clone();
+    declared.0.extend(problem.requirements().iter().copied());
+
+    compare(&declared, &used, &all)
+        .into_iter()
+        .filter(|diagnostic| match diagnostic {
+            Diagnostic::UnusedRequirement(requirement) => {
+                problem.requirements().contains(requirement)
+            }
+            _ => true,
+        })
+        .collect()
+}
+
+/// Reports the `used` requirements that are not `declared`, and the `declared` requirements
+/// not needed for any of the `needed` requirements.
+fn compare(
+    declared: &Requirements,
+    used: &BTreeSet<Requirement>,
+    needed: &BTreeSet<Requirement>,
+) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<_> = used
+        .iter()
+        .filter(|&&used| !declared.iter().any(|declared| covers(*declared, used)))
+        .map(|&used| Diagnostic::UndeclaredRequirement(used))
+        .collect();
+
+    for &declared in declared.iter() {
+        if declared == Requirement::Strips
+            || needed.iter().any(|&used| covers(declared, used))
+            || diagnostics.contains(&Diagnostic::UnusedRequirement(declared))
+        {
+            continue;
+        }
+        diagnostics.push(Diagnostic::UnusedRequirement(declared));
+    }
+    diagnostics
+}
+
+/// Determines whether declaring the `declared` requirement permits the `used` one.
+fn covers(declared: Requirement, used: Requirement) -> bool {
+    declared.expand().into_iter().any(|declared| {
+        declared == used
+            || matches!(
+                (declared, used),
+                (Requirement::NumericFluents, Requirement::ActionCosts)
+                    | (
+                        Requirement::TimedInitialLiterals,
+                        Requirement::DurativeActions
+                    )
+            )
+    })
+}
+
+/// Collects the requirements of the visited constructs.
+#[derive(Default)]
+struct RequirementInference {
+    used: BTreeSet<Requirement>,
+    /// Whether numeric fluents are used at all.
+    numeric: bool,
+    /// Whether numeric fluents are used beyond what `:action-costs` permits.
+    beyond_action_costs: bool,
+}
+
+impl RequirementInference {
+    fn require(&mut self, requirement: Requirement) {
+        self.used.insert(requirement);
+    }
+
+    /// Records a use of numeric fluents, which is only permitted by `:action-costs`
+    /// if `cost_only` is set.
+    fn use_numeric(&mut self, cost_only: bool) {
+        self.numeric = true;
+        self.beyond_action_costs |= !cost_only;
+    }
+
+    fn finish(mut self) -> BTreeSet<Requirement> {
+        if self.numeric {
+            if self.beyond_action_costs {
+                self.require(Requirement::NumericFluents);
+            } else {
+                self.require(Requirement::ActionCosts);
+            }
+        }
+        self.used
+    }
+}
+
+/// Determines whether the `head` is the `(total-cost)` function.
+fn is_total_cost(head: &FHead) -> bool {
+    match head {
+        FHead::Simple(symbol) => **symbol == TOTAL_COST,
+        FHead::WithTerms(symbol, terms) => terms.is_empty() && **symbol == TOTAL_COST,
+    }
+}
+
+/// Determines whether the `node` is a type other than the implicit `object`.
+fn is_explicit_type(node: &Type) -> bool {
+    node != &Type::OBJECT
+}
+
+impl<'ast> Visit<'ast> for RequirementInference {
+    fn visit_requirements(&mut self, _node: &'ast Requirements) {
+        // Declared requirements are compared against the inferred ones, not part of them.
+    }
+
+    fn visit_types(&mut self, node: &'ast Types) {
+        if !node.is_empty() {
+            self.require(Requirement::Typing);
+        }
+        visit::walk_types(self, node)
+    }
+
+    fn visit_typed_name(&mut self, node: &'ast Typed<Name>) {
+        if is_explicit_type(node.type_()) {
+            self.require(Requirement::Typing);
+        }
+    }
+
+    fn visit_typed_variable(&mut self, node: &'ast Typed<Variable>) {
+        if is_explicit_type(node.type_()) {
+            self.require(Requirement::Typing);
+        }
+    }
+
+    fn visit_function_type(&mut self, node: &'ast FunctionType) {
+        if **node == Type::NUMBER {
+            self.use_numeric(true);
+        } else {
+            self.require(Requirement::ObjectFluents);
+            self.require(Requirement::Typing);
+        }
+    }
+
+    fn visit_con_gd(&mut self, node: &'ast ConGD) {
+        if !node.is_empty() {
+            self.require(Requirement::Constraints);
+        }
+        visit::walk_con_gd(self, node)
+    }
+
+    fn visit_pref_con_gds(&mut self, node: &'ast PrefConGDs) {
+        if !node.is_empty() {
+            self.require(Requirement::Constraints);
+        }
+        visit::walk_pref_con_gds(self, node)
+    }
+
+    fn visit_pref_con_gd(&mut self, node: &'ast PrefConGD) {
+        match node {
+            PrefConGD::Goal(_) => {}
+            PrefConGD::Forall(_, _) => self.require(Requirement::UniversalPreconditions),
+            PrefConGD::Preference(_, _) => self.require(Requirement::Preferences),
+        }
+        visit::walk_pref_con_gd(self, node)
+    }
+
+    fn visit_structure_def(&mut self, node: &'ast StructureDef) {
+        match node {
+            StructureDef::Action(_) => {}
+            StructureDef::DurativeAction(_) => self.require(Requirement::DurativeActions),
+            StructureDef::Derived(_) => self.require(Requirement::DerivedPredicates),
+        }
+        visit::walk_structure_def(self, node)
+    }
+
+    fn visit_init_element(&mut self, node: &'ast InitElement) {
+        match node {
+            InitElement::Literal(_) => {}
+            InitElement::At(_, _) => self.require(Requirement::TimedInitialLiterals),
+            InitElement::IsValue(_, _) => self.use_numeric(true),
+            InitElement::IsObject(_, _) => self.require(Requirement::ObjectFluents),
+        }
+        visit::walk_init_element(self, node)
+    }
+
+    fn visit_metric_spec(&mut self, node: &'ast MetricSpec) {
+        match (node.optimization(), node.expression()) {
+            (Optimization::Minimize, MetricFExp::TotalTime) => {}
+            (Optimization::Minimize, MetricFExp::Function(symbol, names))
+                if names.is_empty() && **symbol == TOTAL_TIME => {}
+            (Optimization::Minimize, MetricFExp::Function(symbol, names))
+                if names.is_empty() && **symbol == TOTAL_COST =>
+            {
+                self.use_numeric(true)
+            }
+            _ => self.use_numeric(false),
+        }
+        visit::walk_metric_spec(self, node)
+    }
+
+    fn visit_metric_f_exp(&mut self, node: &'ast MetricFExp) {
+        if let MetricFExp::IsViolated(_) = node {
+            self.require(Requirement::Preferences);
+        }
+        visit::walk_metric_f_exp(self, node)
+    }
+
+    fn visit_precondition_goal_definition(&mut self, node: &'ast PreconditionGoalDefinition) {
+        if let PreconditionGoalDefinition::Forall(_, _) = node {
+            self.require(Requirement::UniversalPreconditions);
+        }
+        visit::walk_precondition_goal_definition(self, node)
+    }
+
+    fn visit_preference(&mut self, node: &'ast Preference) {
+        self.require(Requirement::Preferences);
+        visit::walk_preference(self, node)
+    }
+
+    fn visit_goal_definition(&mut self, node: &'ast GoalDefinition) {
+        match node {
+            GoalDefinition::Not(gd) if matches!(**gd, GoalDefinition::AtomicFormula(_)) => {
+                self.require(Requirement::NegativePreconditions)
+            }
+            GoalDefinition::Literal(_) => self.require(Requirement::NegativePreconditions),
+            GoalDefinition::Or(_) | GoalDefinition::Not(_) | GoalDefinition::Imply(_, _) => {
+                self.require(Requirement::DisjunctivePreconditions)
+            }
+            GoalDefinition::Exists(_, _) => self.require(Requirement::ExistentialPreconditions),
+            GoalDefinition::ForAll(_, _) => self.require(Requirement::UniversalPreconditions),
+            GoalDefinition::AtomicFormula(_)
+            | GoalDefinition::And(_)
+            | GoalDefinition::FComp(_) => {}
+        }
+        visit::walk_goal_definition(self, node)
+    }
+
+    fn visit_term_atomic_formula(&mut self, node: &'ast AtomicFormula<Term>) {
+        if let AtomicFormula::Equality(_) = node {
+            self.require(Requirement::Equality);
+        }
+        visit::walk_term_atomic_formula(self, node)
+    }
+
+    fn visit_name_atomic_formula(&mut self, node: &'ast AtomicFormula<Name>) {
+        if let AtomicFormula::Equality(_) = node {
+            self.require(Requirement::Equality);
+        }
+        visit::walk_name_atomic_formula(self, node)
+    }
+
+    fn visit_term(&mut self, node: &'ast Term) {
+        if let Term::Function(_) = node {
+            self.require(Requirement::ObjectFluents);
+        }
+        visit::walk_term(self, node)
+    }
+
+    fn visit_f_comp(&mut self, node: &'ast FComp) {
+        self.use_numeric(false);
+        visit::walk_f_comp(self, node)
+    }
+
+    fn visit_c_effect(&mut self, node: &'ast CEffect) {
+        match node {
+            CEffect::Effect(_) => {}
+            CEffect::Forall(_) | CEffect::When(_) => self.require(Requirement::ConditionalEffects),
+        }
+        visit::walk_c_effect(self, node)
+    }
+
+    fn visit_p_effect(&mut self, node: &'ast PEffect) {
+        match node {
+            PEffect::AtomicFormula(_) | PEffect::NotAtomicFormula(_) => {}
+            PEffect::AssignNumericFluent(op, head, exp) => self.use_numeric(
+                *op == AssignOp::Increase
+                    && is_total_cost(head)
+                    && matches!(exp, FExp::Number(_) | FExp::Function(_)),
+            ),
+            PEffect::AssignObjectFluent(_, _) => self.require(Requirement::ObjectFluents),
+        }
+        visit::walk_p_effect(self, node)
+    }
+
+    fn visit_duration_constraint(&mut self, node: &'ast DurationConstraint) {
+        if let DurationConstraint::All(_) = node {
+            self.require(Requirement::DurationInequalities);
+        }
+        visit::walk_duration_constraint(self, node)
+    }
+
+    fn visit_d_op(&mut self, node: &'ast DOp) {
+        if *node != DOp::Equal {
+            self.require(Requirement::DurationInequalities);
+        }
+    }
+
+    fn visit_duration_value(&mut self, node: &'ast DurationValue) {
+        if let DurationValue::FExp(_) = node {
+            self.use_numeric(false);
+        }
+        visit::walk_duration_value(self, node)
+    }
+
+    fn visit_durative_action_goal_definition(&mut self, node: &'ast DurativeActionGoalDefinition) {
+        if let DurativeActionGoalDefinition::Forall(_, _) = node {
+            self.require(Requirement::UniversalPreconditions);
+        }
+        visit::walk_durative_action_goal_definition(self, node)
+    }
+
+    fn visit_pref_timed_gd(&mut self, node: &'ast PrefTimedGD) {
+        if let PrefTimedGD::Preference(_, _) = node {
+            self.require(Requirement::Preferences);
+        }
+        visit::walk_pref_timed_gd(self, node)
+    }
+
+    fn visit_durative_action_effect(&mut self, node: &'ast DurativeActionEffect) {
+        match node {
+            DurativeActionEffect::Timed(_) | DurativeActionEffect::All(_) => {}
+            DurativeActionEffect::Forall(_, _) | DurativeActionEffect::When(_, _) => {
+                self.require(Requirement::ConditionalEffects)
+            }
+        }
+        visit::walk_durative_action_effect(self, node)
+    }
+
+    fn visit_timed_effect(&mut self, node: &'ast TimedEffect) {
+        match node {
+            TimedEffect::Conditional(_, _) => {}
+            TimedEffect::NumericFluent(_, _) => self.use_numeric(false),
+            TimedEffect::ContinuousEffect(_, _, _) => {
+                self.require(Requirement::ContinuousEffects);
+                self.use_numeric(false);
+            }
+        }
+        visit::walk_timed_effect(self, node)
+    }
+
+    fn visit_f_exp_da(&mut self, node: &'ast FExpDa) {
+        if let FExpDa::Duration = node {
+            self.require(Requirement::DurationInequalities);
+        }
+        visit::walk_f_exp_da(self, node)
+    }
+
+    fn visit_f_exp_t(&mut self, node: &'ast FExpT) {
+        self.require(Requirement::ContinuousEffects);
+        visit::walk_f_exp_t(self, node)
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn infers_adl_requirements() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:types block)
+                (:predicates (on ?x ?y - block) (clear ?x - block))
+                (:action move
+                    :parameters (?x ?y - block)
+                    :precondition (and (not (= ?x ?y))
+                                       (forall (?z - block) (not (on ?z ?x)))
+                                       (exists (?z - block) (imply (on ?x ?z) (clear ?y))))
+                    :effect (forall (?z - block) (when (on ?x ?z) (not (on ?x ?z))))))"#,
+        )
+        .unwrap();
+
+        assert!(infer_requirements(&domain).into_iter().eq([
+            Requirement::Typing,
+            Requirement::NegativePreconditions,
+            Requirement::DisjunctivePreconditions,
+            Requirement::Equality,
+            Requirement::ExistentialPreconditions,
+            Requirement::UniversalPreconditions,
+            Requirement::ConditionalEffects,
+        ]));
+    }
+
+    #[test]
+    fn distinguishes_action_costs_from_numeric_fluents() {
+        let costs = Domain::from_str(
+            r#"(define (domain d)
+                (:predicates (p))
+                (:functions (total-cost) - number (cost) - number)
+                (:action a :parameters () :effect (and (p) (increase (total-cost) (cost)))))"#,
+        )
+        .unwrap();
+        assert!(infer_requirements(&costs)
+            .into_iter()
+            .eq([Requirement::ActionCosts]));
+
+        let fluents = Domain::from_str(
+            r#"(define (domain d)
+                (:functions (fuel) - number)
+                (:action a :parameters () :precondition (> (fuel) 0)
+                    :effect (decrease (fuel) 1)))"#,
+        )
+        .unwrap();
+        assert!(infer_requirements(&fluents)
+            .into_iter()
+            .eq([Requirement::NumericFluents]));
+        assert_eq!(
+            check_requirements(&fluents),
+            [Diagnostic::UndeclaredRequirement(
+                Requirement::NumericFluents
+            )]
+        );
+    }
+
+    #[test]
+    fn infers_temporal_requirements() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:predicates (p) (q))
+                (:durative-action a :parameters ()
+                    :duration (<= ?duration 5)
+                    :condition (at start (p))
+                    :effect (at end (q)))
+                (:derived (q) (p)))"#,
+        )
+        .unwrap();
+        assert!(infer_requirements(&domain).into_iter().eq([
+            Requirement::DurativeActions,
+            Requirement::DurationInequalities,
+            Requirement::DerivedPredicates,
+        ]));
+
+        let problem = Problem::from_str(
+            r#"(define (problem p) (:domain d)
+                (:init (at 10 (p)))
+                (:goal (q))
+                (:metric minimize (total-time)))"#,
+        )
+        .unwrap();
+        assert!(infer_problem_requirements(&problem)
+            .into_iter()
+            .eq([Requirement::TimedInitialLiterals]));
+    }
+
+    #[test]
+    fn shorthands_cover_their_expansion() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :adl :fluents :durative-actions)
+                (:predicates (p ?x))
+                (:functions (total-cost) - number)
+                (:action a :parameters (?x) :precondition (exists (?y) (p ?y))
+                    :effect (increase (total-cost) 1)))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            check_requirements(&domain),
+            [Diagnostic::UnusedRequirement(Requirement::DurativeActions)]
+        );
+    }
+
+    #[test]
+    fn checks_problem_requirements_against_both_declarations() {
+        let domain = Domain::from_str(
+            r#"(define (domain d)
+                (:requirements :strips :durative-actions)
+                (:predicates (p))
+                (:durative-action a :parameters () :duration (= ?duration 1)
+                    :condition (at start (p)) :effect (at end (not (p)))))"#,
+        )
+        .unwrap();
+        let problem = Problem::from_str(
+            r#"(define (problem p) (:domain d)
+                (:requirements :timed-initial-literals :preferences)
+                (:objects x - thing)
+                (:init (at 10 (p)))
+                (:goal (p)))"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            check_problem_requirements(&domain, &problem),
+            [
+                Diagnostic::UndeclaredRequirement(Requirement::Typing),
+                Diagnostic::UnusedRequirement(Requirement::Preferences),
+            ]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! Checks uses of predicates and functions against their declarations in a [`DomainIndex`].
+
+use crate::index::DomainIndex;
+use crate::types::Name;
+use crate::validate::Diagnostic;
+
+/// Checks that the `predicate` is declared with `arity` parameters.
+pub(crate) fn check_predicate(
+    index: &DomainIndex,
+    predicate: &Name,
+    arity: usize,
+) -> Option<Diagnostic> {
+    match index.predicates().lookup(predicate) {
+        None => Some(Diagnostic::UndeclaredPredicate(predicate.clone())),
+        Some(signature) => check_arity(predicate, signature.arity(), arity),
+    }
+}
+
+/// Checks that the `function` is declared with `arity` parameters.
+pub(crate) fn check_function(
+    index: &DomainIndex,
+    function: &Name,
+    arity: usize,
+) -> Option<Diagnostic> {
+    match index.functions().lookup(function) {
+        None => Some(Diagnostic::UndeclaredFunction(function.clone())),
+        Some(signature) => check_arity(function, signature.arity(), arity),
+    }
+}
+
+fn check_arity(symbol: &Name, expected: usize, found: usize) -> Option<Diagnostic> {
+    (expected != found).then(|| Diagnostic::ArityMismatch {
+        symbol: symbol.clone(),
+        expected,
+        found,
+    })
+}
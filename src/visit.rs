@@ -0,0 +1,1428 @@
+//! Provides the [`Visit`] trait to traverse domains and problems.
+//!
+//! Every node type of the syntax tree has a `visit_*` method on [`Visit`]. By default,
+//! these methods call the corresponding `walk_*` function, which visits the children of
+//! the node. Implementations therefore only override the methods of the nodes they are
+//! interested in, and call the `walk_*` function from the override to keep descending.
+//!
+//! ## Example
+//! ```
+//! # use pddl::{Domain, Name, Parser, Predicate};
+//! use pddl::visit::{self, Visit};
+//!
+//! /// Collects the predicates used in goals, preconditions and effects.
+//! #[derive(Default)]
+//! struct UsedPredicates<'ast>(Vec<&'ast Name>);
+//!
+//! impl<'ast> Visit<'ast> for UsedPredicates<'ast> {
+//!     // Skip the predicate definitions of the domain.
+//!     fn visit_atomic_formula_skeleton(&mut self, _node: &'ast pddl::AtomicFormulaSkeleton) {}
+//!
+//!     fn visit_predicate(&mut self, node: &'ast Predicate) {
+//!         self.0.push(node);
+//!         visit::walk_predicate(self, node);
+//!     }
+//! }
+//!
+//! let domain = Domain::from_str(r#"(define (domain briefcase-world)
+//!     (:predicates (at ?x ?y) (in ?x))
+//!     (:action put-in
+//!         :parameters (?x ?l)
+//!         :precondition (and (at ?x ?l) (at B ?l))
+//!         :effect (in ?x)))"#).unwrap();
+//!
+//! let mut visitor = UsedPredicates::default();
+//! visitor.visit_domain(&domain);
+//! assert_eq!(visitor.0, ["at", "at", "in"]);
+//! ```
+
+use crate::types::{
+    ActionDefinition, ActionSymbol, AssignOp, AssignOpT, AtomicFormula, AtomicFormulaSkeleton,
+    AtomicFunctionSkeleton, BasicFunctionTerm, BinaryComp, BinaryOp, CEffect, Con2GD, ConGD,
+    ConditionalEffect, Constants, DOp, DerivedPredicate, Domain, DurationConstraint, DurationValue,
+    DurativeActionDefinition, DurativeActionEffect, DurativeActionGoalDefinition,
+    DurativeActionSymbol, Effects, FAssignDa, FComp, FExp, FExpDa, FExpT, FHead, ForallCEffect,
+    FunctionSymbol, FunctionTerm, FunctionType, Functions, GoalDef, GoalDefinition, InitElement,
+    InitElements, Interval, LengthSpec, Literal, MetricFExp, MetricSpec, MultiOp, Name,
+    NameLiteral, Number, Objects, Optimization, PEffect, PreconditionGoalDefinition,
+    PreconditionGoalDefinitions, Predicate, PredicateDefinitions, PrefConGD, PrefConGDs,
+    PrefTimedGD, Preference, PreferenceGD, PreferenceName, PrimitiveType, Problem, Requirement,
+    Requirements, SimpleDurationConstraint, StructureDef, StructureDefs, Term, TermLiteral,
+    TimeSpecifier, TimedEffect, TimedGD, Timeless, Type, Typed, TypedNames, TypedVariables, Types,
+    Variable, WhenCEffect,
+};
+
+/// Traverses the syntax tree of a [`Domain`] or [`Problem`] by shared reference.
+///
+/// See the [module documentation](self) for an example.
+pub trait Visit<'ast> {
+    // Domains and problems.
+
+    /// Visits a [`Domain`].
+    fn visit_domain(&mut self, node: &'ast Domain) {
+        walk_domain(self, node)
+    }
+
+    /// Visits a [`Problem`].
+    fn visit_problem(&mut self, node: &'ast Problem) {
+        walk_problem(self, node)
+    }
+
+    // Domain sections.
+
+    /// Visits a list of [`Requirements`].
+    fn visit_requirements(&mut self, node: &'ast Requirements) {
+        walk_requirements(self, node)
+    }
+
+    /// Visits a [`Requirement`].
+    fn visit_requirement(&mut self, _node: &'ast Requirement) {}
+
+    /// Visits the [`Types`] declaration of a domain.
+    fn visit_types(&mut self, node: &'ast Types) {
+        walk_types(self, node)
+    }
+
+    /// Visits the [`Constants`] declaration of a domain.
+    fn visit_constants(&mut self, node: &'ast Constants) {
+        walk_constants(self, node)
+    }
+
+    /// Visits the [`PredicateDefinitions`] of a domain.
+    fn visit_predicate_definitions(&mut self, node: &'ast PredicateDefinitions) {
+        walk_predicate_definitions(self, node)
+    }
+
+    /// Visits an [`AtomicFormulaSkeleton`], i.e. a predicate definition.
+    fn visit_atomic_formula_skeleton(&mut self, node: &'ast AtomicFormulaSkeleton) {
+        walk_atomic_formula_skeleton(self, node)
+    }
+
+    /// Visits the [`Functions`] declaration of a domain.
+    fn visit_functions(&mut self, node: &'ast Functions) {
+        walk_functions(self, node)
+    }
+
+    /// Visits an [`AtomicFunctionSkeleton`], i.e. a function definition.
+    fn visit_atomic_function_skeleton(&mut self, node: &'ast AtomicFunctionSkeleton) {
+        walk_atomic_function_skeleton(self, node)
+    }
+
+    /// Visits a [`FunctionType`].
+    fn visit_function_type(&mut self, node: &'ast FunctionType) {
+        walk_function_type(self, node)
+    }
+
+    /// Visits the [`Timeless`] predicates of a domain.
+    fn visit_timeless(&mut self, node: &'ast Timeless) {
+        walk_timeless(self, node)
+    }
+
+    /// Visits the [`StructureDefs`] of a domain.
+    fn visit_structure_defs(&mut self, node: &'ast StructureDefs) {
+        walk_structure_defs(self, node)
+    }
+
+    /// Visits a [`StructureDef`].
+    fn visit_structure_def(&mut self, node: &'ast StructureDef) {
+        walk_structure_def(self, node)
+    }
+
+    /// Visits an [`ActionDefinition`].
+    fn visit_action_definition(&mut self, node: &'ast ActionDefinition) {
+        walk_action_definition(self, node)
+    }
+
+    /// Visits a [`DurativeActionDefinition`].
+    fn visit_durative_action_definition(&mut self, node: &'ast DurativeActionDefinition) {
+        walk_durative_action_definition(self, node)
+    }
+
+    /// Visits a [`DerivedPredicate`].
+    fn visit_derived_predicate(&mut self, node: &'ast DerivedPredicate) {
+        walk_derived_predicate(self, node)
+    }
+
+    // Problem sections.
+
+    /// Visits the [`Objects`] declaration of a problem.
+    fn visit_objects(&mut self, node: &'ast Objects) {
+        walk_objects(self, node)
+    }
+
+    /// Visits the [`InitElements`] of a problem.
+    fn visit_init_elements(&mut self, node: &'ast InitElements) {
+        walk_init_elements(self, node)
+    }
+
+    /// Visits an [`InitElement`].
+    fn visit_init_element(&mut self, node: &'ast InitElement) {
+        walk_init_element(self, node)
+    }
+
+    /// Visits a [`BasicFunctionTerm`].
+    fn visit_basic_function_term(&mut self, node: &'ast BasicFunctionTerm) {
+        walk_basic_function_term(self, node)
+    }
+
+    /// Visits the [`GoalDef`] of a problem.
+    fn visit_goal_def(&mut self, node: &'ast GoalDef) {
+        walk_goal_def(self, node)
+    }
+
+    /// Visits a [`MetricSpec`].
+    fn visit_metric_spec(&mut self, node: &'ast MetricSpec) {
+        walk_metric_spec(self, node)
+    }
+
+    /// Visits an [`Optimization`].
+    fn visit_optimization(&mut self, _node: &Optimization) {}
+
+    /// Visits a [`MetricFExp`].
+    fn visit_metric_f_exp(&mut self, node: &'ast MetricFExp) {
+        walk_metric_f_exp(self, node)
+    }
+
+    /// Visits a [`LengthSpec`].
+    fn visit_length_spec(&mut self, _node: &'ast LengthSpec) {}
+
+    // Names and types.
+
+    /// Visits a [`Name`].
+    fn visit_name(&mut self, _node: &'ast Name) {}
+
+    /// Visits a [`Variable`].
+    fn visit_variable(&mut self, node: &'ast Variable) {
+        walk_variable(self, node)
+    }
+
+    /// Visits a [`Predicate`].
+    fn visit_predicate(&mut self, node: &'ast Predicate) {
+        walk_predicate(self, node)
+    }
+
+    /// Visits a [`FunctionSymbol`].
+    fn visit_function_symbol(&mut self, node: &'ast FunctionSymbol) {
+        walk_function_symbol(self, node)
+    }
+
+    /// Visits an [`ActionSymbol`].
+    fn visit_action_symbol(&mut self, node: &'ast ActionSymbol) {
+        walk_action_symbol(self, node)
+    }
+
+    /// Visits a [`DurativeActionSymbol`].
+    fn visit_durative_action_symbol(&mut self, node: &'ast DurativeActionSymbol) {
+        walk_durative_action_symbol(self, node)
+    }
+
+    /// Visits a [`PreferenceName`].
+    fn visit_preference_name(&mut self, node: &'ast PreferenceName) {
+        walk_preference_name(self, node)
+    }
+
+    /// Visits a list of [`TypedNames`], e.g. object declarations.
+    fn visit_typed_names(&mut self, node: &'ast TypedNames) {
+        walk_typed_names(self, node)
+    }
+
+    /// Visits a [`Typed`] [`Name`].
+    fn visit_typed_name(&mut self, node: &'ast Typed<Name>) {
+        walk_typed_name(self, node)
+    }
+
+    /// Visits a list of [`TypedVariables`], e.g. action parameters.
+    fn visit_typed_variables(&mut self, node: &'ast TypedVariables) {
+        walk_typed_variables(self, node)
+    }
+
+    /// Visits a [`Typed`] [`Variable`].
+    fn visit_typed_variable(&mut self, node: &'ast Typed<Variable>) {
+        walk_typed_variable(self, node)
+    }
+
+    /// Visits a [`Type`].
+    fn visit_type(&mut self, node: &'ast Type) {
+        walk_type(self, node)
+    }
+
+    /// Visits a [`PrimitiveType`].
+    fn visit_primitive_type(&mut self, node: &'ast PrimitiveType) {
+        walk_primitive_type(self, node)
+    }
+
+    /// Visits a [`Number`].
+    fn visit_number(&mut self, _node: &'ast Number) {}
+
+    // Goals.
+
+    /// Visits a list of [`PreconditionGoalDefinitions`].
+    fn visit_precondition_goal_definitions(&mut self, node: &'ast PreconditionGoalDefinitions) {
+        walk_precondition_goal_definitions(self, node)
+    }
+
+    /// Visits a [`PreconditionGoalDefinition`].
+    fn visit_precondition_goal_definition(&mut self, node: &'ast PreconditionGoalDefinition) {
+        walk_precondition_goal_definition(self, node)
+    }
+
+    /// Visits a [`PreferenceGD`].
+    fn visit_preference_gd(&mut self, node: &'ast PreferenceGD) {
+        walk_preference_gd(self, node)
+    }
+
+    /// Visits a [`Preference`].
+    fn visit_preference(&mut self, node: &'ast Preference) {
+        walk_preference(self, node)
+    }
+
+    /// Visits a [`GoalDefinition`].
+    fn visit_goal_definition(&mut self, node: &'ast GoalDefinition) {
+        walk_goal_definition(self, node)
+    }
+
+    /// Visits an [`AtomicFormula`] over [`Term`]s, as used in goals and effects.
+    fn visit_term_atomic_formula(&mut self, node: &'ast AtomicFormula<Term>) {
+        walk_term_atomic_formula(self, node)
+    }
+
+    /// Visits an [`AtomicFormula`] over [`Name`]s, as used in initial states.
+    fn visit_name_atomic_formula(&mut self, node: &'ast AtomicFormula<Name>) {
+        walk_name_atomic_formula(self, node)
+    }
+
+    /// Visits a [`TermLiteral`].
+    fn visit_term_literal(&mut self, node: &'ast TermLiteral) {
+        walk_term_literal(self, node)
+    }
+
+    /// Visits a [`NameLiteral`].
+    fn visit_name_literal(&mut self, node: &'ast NameLiteral) {
+        walk_name_literal(self, node)
+    }
+
+    /// Visits a [`Term`].
+    fn visit_term(&mut self, node: &'ast Term) {
+        walk_term(self, node)
+    }
+
+    /// Visits a [`FunctionTerm`].
+    fn visit_function_term(&mut self, node: &'ast FunctionTerm) {
+        walk_function_term(self, node)
+    }
+
+    /// Visits an [`FComp`].
+    fn visit_f_comp(&mut self, node: &'ast FComp) {
+        walk_f_comp(self, node)
+    }
+
+    /// Visits a [`BinaryComp`].
+    fn visit_binary_comp(&mut self, _node: &'ast BinaryComp) {}
+
+    /// Visits an [`FExp`].
+    fn visit_f_exp(&mut self, node: &'ast FExp) {
+        walk_f_exp(self, node)
+    }
+
+    /// Visits an [`FHead`].
+    fn visit_f_head(&mut self, node: &'ast FHead) {
+        walk_f_head(self, node)
+    }
+
+    /// Visits a [`BinaryOp`].
+    fn visit_binary_op(&mut self, _node: &'ast BinaryOp) {}
+
+    /// Visits a [`MultiOp`].
+    fn visit_multi_op(&mut self, _node: &'ast MultiOp) {}
+
+    // Effects.
+
+    /// Visits [`Effects`].
+    fn visit_effects(&mut self, node: &'ast Effects) {
+        walk_effects(self, node)
+    }
+
+    /// Visits a [`CEffect`].
+    fn visit_c_effect(&mut self, node: &'ast CEffect) {
+        walk_c_effect(self, node)
+    }
+
+    /// Visits a [`ForallCEffect`].
+    fn visit_forall_c_effect(&mut self, node: &'ast ForallCEffect) {
+        walk_forall_c_effect(self, node)
+    }
+
+    /// Visits a [`WhenCEffect`].
+    fn visit_when_c_effect(&mut self, node: &'ast WhenCEffect) {
+        walk_when_c_effect(self, node)
+    }
+
+    /// Visits a [`ConditionalEffect`].
+    fn visit_conditional_effect(&mut self, node: &'ast ConditionalEffect) {
+        walk_conditional_effect(self, node)
+    }
+
+    /// Visits a [`PEffect`].
+    fn visit_p_effect(&mut self, node: &'ast PEffect) {
+        walk_p_effect(self, node)
+    }
+
+    /// Visits an [`AssignOp`].
+    fn visit_assign_op(&mut self, _node: &'ast AssignOp) {}
+
+    // Durative actions.
+
+    /// Visits a [`DurationConstraint`].
+    fn visit_duration_constraint(&mut self, node: &'ast DurationConstraint) {
+        walk_duration_constraint(self, node)
+    }
+
+    /// Visits a [`SimpleDurationConstraint`].
+    fn visit_simple_duration_constraint(&mut self, node: &'ast SimpleDurationConstraint) {
+        walk_simple_duration_constraint(self, node)
+    }
+
+    /// Visits a [`DOp`].
+    fn visit_d_op(&mut self, _node: &'ast DOp) {}
+
+    /// Visits a [`DurationValue`].
+    fn visit_duration_value(&mut self, node: &'ast DurationValue) {
+        walk_duration_value(self, node)
+    }
+
+    /// Visits a [`DurativeActionGoalDefinition`].
+    fn visit_durative_action_goal_definition(&mut self, node: &'ast DurativeActionGoalDefinition) {
+        walk_durative_action_goal_definition(self, node)
+    }
+
+    /// Visits a [`PrefTimedGD`].
+    fn visit_pref_timed_gd(&mut self, node: &'ast PrefTimedGD) {
+        walk_pref_timed_gd(self, node)
+    }
+
+    /// Visits a [`TimedGD`].
+    fn visit_timed_gd(&mut self, node: &'ast TimedGD) {
+        walk_timed_gd(self, node)
+    }
+
+    /// Visits a [`TimeSpecifier`].
+    fn visit_time_specifier(&mut self, _node: &'ast TimeSpecifier) {}
+
+    /// Visits an [`Interval`].
+    fn visit_interval(&mut self, _node: &'ast Interval) {}
+
+    /// Visits a [`DurativeActionEffect`].
+    fn visit_durative_action_effect(&mut self, node: &'ast DurativeActionEffect) {
+        walk_durative_action_effect(self, node)
+    }
+
+    /// Visits a [`TimedEffect`].
+    fn visit_timed_effect(&mut self, node: &'ast TimedEffect) {
+        walk_timed_effect(self, node)
+    }
+
+    /// Visits an [`FAssignDa`].
+    fn visit_f_assign_da(&mut self, node: &'ast FAssignDa) {
+        walk_f_assign_da(self, node)
+    }
+
+    /// Visits an [`FExpDa`].
+    fn visit_f_exp_da(&mut self, node: &'ast FExpDa) {
+        walk_f_exp_da(self, node)
+    }
+
+    /// Visits an [`AssignOpT`].
+    fn visit_assign_op_t(&mut self, _node: &'ast AssignOpT) {}
+
+    /// Visits an [`FExpT`].
+    fn visit_f_exp_t(&mut self, node: &'ast FExpT) {
+        walk_f_exp_t(self, node)
+    }
+
+    // Constraints.
+
+    /// Visits a [`ConGD`].
+    fn visit_con_gd(&mut self, node: &'ast ConGD) {
+        walk_con_gd(self, node)
+    }
+
+    /// Visits a [`Con2GD`].
+    fn visit_con2_gd(&mut self, node: &'ast Con2GD) {
+        walk_con2_gd(self, node)
+    }
+
+    /// Visits a list of [`PrefConGDs`].
+    fn visit_pref_con_gds(&mut self, node: &'ast PrefConGDs) {
+        walk_pref_con_gds(self, node)
+    }
+
+    /// Visits a [`PrefConGD`].
+    fn visit_pref_con_gd(&mut self, node: &'ast PrefConGD) {
+        walk_pref_con_gd(self, node)
+    }
+}
+
+/// Visits the name, sections and structure definitions of a [`Domain`].
+pub fn walk_domain<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Domain) {
+    visitor.visit_name(node.name());
+    for name in node.extends() {
+        visitor.visit_name(name);
+    }
+    visitor.visit_requirements(node.requirements());
+    visitor.visit_types(node.types());
+    visitor.visit_constants(node.constants());
+    visitor.visit_predicate_definitions(node.predicates());
+    visitor.visit_timeless(node.timeless());
+    visitor.visit_functions(node.functions());
+    visitor.visit_con_gd(node.constraints());
+    visitor.visit_structure_defs(node.structure());
+}
+
+/// Visits the names and sections of a [`Problem`].
+pub fn walk_problem<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Problem) {
+    visitor.visit_name(node.name());
+    visitor.visit_name(node.domain());
+    visitor.visit_requirements(node.requirements());
+    visitor.visit_objects(node.objects());
+    visitor.visit_init_elements(node.init());
+    visitor.visit_goal_def(node.goal());
+    visitor.visit_pref_con_gds(node.constraints());
+    if let Some(metric) = node.metric_spec() {
+        visitor.visit_metric_spec(metric);
+    }
+    if let Some(length) = node.length_spec() {
+        visitor.visit_length_spec(length);
+    }
+}
+
+/// Visits each [`Requirement`].
+pub fn walk_requirements<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Requirements) {
+    for requirement in node.iter() {
+        visitor.visit_requirement(requirement);
+    }
+}
+
+/// Visits the declared types.
+pub fn walk_types<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Types) {
+    visitor.visit_typed_names(node.values());
+}
+
+/// Visits the declared constants.
+pub fn walk_constants<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Constants) {
+    visitor.visit_typed_names(node);
+}
+
+/// Visits each predicate definition.
+pub fn walk_predicate_definitions<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PredicateDefinitions,
+) {
+    for predicate in node.iter() {
+        visitor.visit_atomic_formula_skeleton(predicate);
+    }
+}
+
+/// Visits the predicate and parameters of an [`AtomicFormulaSkeleton`].
+pub fn walk_atomic_formula_skeleton<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AtomicFormulaSkeleton,
+) {
+    visitor.visit_predicate(node.predicate());
+    visitor.visit_typed_variables(node.variables());
+}
+
+/// Visits each function definition along with its type.
+pub fn walk_functions<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Functions) {
+    for function in node.iter() {
+        visitor.visit_atomic_function_skeleton(function.value_ref());
+        visitor.visit_function_type(function.type_ref());
+    }
+}
+
+/// Visits the symbol and parameters of an [`AtomicFunctionSkeleton`].
+pub fn walk_atomic_function_skeleton<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AtomicFunctionSkeleton,
+) {
+    visitor.visit_function_symbol(node.symbol());
+    visitor.visit_typed_variables(node.variables());
+}
+
+/// Visits the [`Type`] of a [`FunctionType`].
+pub fn walk_function_type<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionType,
+) {
+    visitor.visit_type(node);
+}
+
+/// Visits each timeless literal.
+pub fn walk_timeless<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Timeless) {
+    for literal in node.iter() {
+        visitor.visit_name_literal(literal);
+    }
+}
+
+/// Visits each [`StructureDef`].
+pub fn walk_structure_defs<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast StructureDefs,
+) {
+    for def in node.iter() {
+        visitor.visit_structure_def(def);
+    }
+}
+
+/// Visits the action, durative action or derived predicate.
+pub fn walk_structure_def<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast StructureDef,
+) {
+    match node {
+        StructureDef::Action(action) => visitor.visit_action_definition(action),
+        StructureDef::DurativeAction(action) => visitor.visit_durative_action_definition(action),
+        StructureDef::Derived(derived) => visitor.visit_derived_predicate(derived),
+    }
+}
+
+/// Visits the symbol, parameters, precondition and effect of an [`ActionDefinition`].
+pub fn walk_action_definition<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ActionDefinition,
+) {
+    visitor.visit_action_symbol(node.symbol());
+    visitor.visit_typed_variables(node.parameters());
+    visitor.visit_precondition_goal_definitions(node.precondition());
+    if let Some(effect) = node.effect() {
+        visitor.visit_effects(effect);
+    }
+}
+
+/// Visits the symbol, parameters, duration, condition and effect of a
+/// [`DurativeActionDefinition`].
+pub fn walk_durative_action_definition<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DurativeActionDefinition,
+) {
+    visitor.visit_durative_action_symbol(node.symbol());
+    visitor.visit_typed_variables(node.parameters());
+    if let Some(duration) = node.duration() {
+        visitor.visit_duration_constraint(duration);
+    }
+    if let Some(condition) = node.condition() {
+        visitor.visit_durative_action_goal_definition(condition);
+    }
+    if let Some(effect) = node.effect() {
+        visitor.visit_durative_action_effect(effect);
+    }
+}
+
+/// Visits the predicate and the defining expression of a [`DerivedPredicate`].
+pub fn walk_derived_predicate<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DerivedPredicate,
+) {
+    visitor.visit_atomic_formula_skeleton(node.predicate());
+    visitor.visit_goal_definition(node.expression());
+}
+
+/// Visits the declared objects.
+pub fn walk_objects<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Objects) {
+    visitor.visit_typed_names(node.values());
+}
+
+/// Visits each [`InitElement`].
+pub fn walk_init_elements<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast InitElements,
+) {
+    for element in node.iter() {
+        visitor.visit_init_element(element);
+    }
+}
+
+/// Visits the literal or function assignment of an [`InitElement`].
+pub fn walk_init_element<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast InitElement) {
+    match node {
+        InitElement::Literal(literal) => visitor.visit_name_literal(literal),
+        InitElement::At(time, literal) => {
+            visitor.visit_number(time);
+            visitor.visit_name_literal(literal);
+        }
+        InitElement::IsValue(term, value) => {
+            visitor.visit_basic_function_term(term);
+            visitor.visit_number(value);
+        }
+        InitElement::IsObject(term, value) => {
+            visitor.visit_basic_function_term(term);
+            visitor.visit_name(value);
+        }
+    }
+}
+
+/// Visits the symbol and names of a [`BasicFunctionTerm`].
+pub fn walk_basic_function_term<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast BasicFunctionTerm,
+) {
+    visitor.visit_function_symbol(node.symbol());
+    for name in node.names() {
+        visitor.visit_name(name);
+    }
+}
+
+/// Visits the goals of a [`GoalDef`].
+pub fn walk_goal_def<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast GoalDef) {
+    visitor.visit_precondition_goal_definitions(node.value());
+}
+
+/// Visits the optimization and expression of a [`MetricSpec`].
+pub fn walk_metric_spec<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast MetricSpec) {
+    visitor.visit_optimization(&node.optimization());
+    visitor.visit_metric_f_exp(node.expression());
+}
+
+/// Visits the operands of a [`MetricFExp`].
+pub fn walk_metric_f_exp<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast MetricFExp) {
+    match node {
+        MetricFExp::BinaryOp(op, lhs, rhs) => {
+            visitor.visit_binary_op(op);
+            visitor.visit_metric_f_exp(lhs);
+            visitor.visit_metric_f_exp(rhs);
+        }
+        MetricFExp::MultiOp(op, lhs, rhs) => {
+            visitor.visit_multi_op(op);
+            visitor.visit_metric_f_exp(lhs);
+            for exp in rhs {
+                visitor.visit_metric_f_exp(exp);
+            }
+        }
+        MetricFExp::Negative(exp) => visitor.visit_metric_f_exp(exp),
+        MetricFExp::Number(number) => visitor.visit_number(number),
+        MetricFExp::Function(symbol, names) => {
+            visitor.visit_function_symbol(symbol);
+            for name in names {
+                visitor.visit_name(name);
+            }
+        }
+        MetricFExp::TotalTime => {}
+        MetricFExp::IsViolated(name) => visitor.visit_preference_name(name),
+    }
+}
+
+/// Visits the [`Name`] of a [`Variable`].
+pub fn walk_variable<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Variable) {
+    visitor.visit_name(node);
+}
+
+/// Visits the [`Name`] of a [`Predicate`].
+pub fn walk_predicate<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Predicate) {
+    visitor.visit_name(node);
+}
+
+/// Visits the [`Name`] of a [`FunctionSymbol`].
+pub fn walk_function_symbol<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionSymbol,
+) {
+    visitor.visit_name(node);
+}
+
+/// Visits the [`Name`] of an [`ActionSymbol`].
+pub fn walk_action_symbol<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ActionSymbol,
+) {
+    visitor.visit_name(node);
+}
+
+/// Visits the [`Name`] of a [`DurativeActionSymbol`].
+pub fn walk_durative_action_symbol<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DurativeActionSymbol,
+) {
+    visitor.visit_name(node);
+}
+
+/// Visits the [`Name`] of a [`PreferenceName`].
+pub fn walk_preference_name<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PreferenceName,
+) {
+    visitor.visit_name(node);
+}
+
+/// Visits each typed name.
+pub fn walk_typed_names<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast TypedNames) {
+    for name in node.iter() {
+        visitor.visit_typed_name(name);
+    }
+}
+
+/// Visits the name and type of a typed name.
+pub fn walk_typed_name<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Typed<Name>) {
+    visitor.visit_name(node.value());
+    visitor.visit_type(node.type_());
+}
+
+/// Visits each typed variable.
+pub fn walk_typed_variables<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypedVariables,
+) {
+    for variable in node.iter() {
+        visitor.visit_typed_variable(variable);
+    }
+}
+
+/// Visits the variable and type of a typed variable.
+pub fn walk_typed_variable<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Typed<Variable>,
+) {
+    visitor.visit_variable(node.value());
+    visitor.visit_type(node.type_());
+}
+
+/// Visits each [`PrimitiveType`] of a [`Type`].
+pub fn walk_type<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Type) {
+    match node {
+        Type::Exactly(r#type) => visitor.visit_primitive_type(r#type),
+        Type::EitherOf(types) => {
+            for r#type in types {
+                visitor.visit_primitive_type(r#type);
+            }
+        }
+    }
+}
+
+/// Visits the [`Name`] of a [`PrimitiveType`].
+pub fn walk_primitive_type<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PrimitiveType,
+) {
+    visitor.visit_name(node.name());
+}
+
+/// Visits each [`PreconditionGoalDefinition`].
+pub fn walk_precondition_goal_definitions<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PreconditionGoalDefinitions,
+) {
+    for gd in node.iter() {
+        visitor.visit_precondition_goal_definition(gd);
+    }
+}
+
+/// Visits the preference or the quantified goals of a [`PreconditionGoalDefinition`].
+pub fn walk_precondition_goal_definition<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PreconditionGoalDefinition,
+) {
+    match node {
+        PreconditionGoalDefinition::Preference(pref) => visitor.visit_preference_gd(pref),
+        PreconditionGoalDefinition::Forall(variables, gds) => {
+            visitor.visit_typed_variables(variables);
+            visitor.visit_precondition_goal_definitions(gds);
+        }
+    }
+}
+
+/// Visits the goal or the preference of a [`PreferenceGD`].
+pub fn walk_preference_gd<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PreferenceGD,
+) {
+    match node {
+        PreferenceGD::Goal(gd) => visitor.visit_goal_definition(gd),
+        PreferenceGD::Preference(pref) => visitor.visit_preference(pref),
+    }
+}
+
+/// Visits the optional name and the goal of a [`Preference`].
+pub fn walk_preference<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Preference) {
+    if let Some(name) = node.name() {
+        visitor.visit_preference_name(name);
+    }
+    visitor.visit_goal_definition(node.goal());
+}
+
+/// Visits the formulas, subgoals and quantified variables of a [`GoalDefinition`].
+pub fn walk_goal_definition<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast GoalDefinition,
+) {
+    match node {
+        GoalDefinition::AtomicFormula(formula) => visitor.visit_term_atomic_formula(formula),
+        GoalDefinition::Literal(literal) => visitor.visit_term_literal(literal),
+        GoalDefinition::And(gds) | GoalDefinition::Or(gds) => {
+            for gd in gds {
+                visitor.visit_goal_definition(gd);
+            }
+        }
+        GoalDefinition::Not(gd) => visitor.visit_goal_definition(gd),
+        GoalDefinition::Imply(antecedent, consequent) => {
+            visitor.visit_goal_definition(antecedent);
+            visitor.visit_goal_definition(consequent);
+        }
+        GoalDefinition::Exists(variables, gd) | GoalDefinition::ForAll(variables, gd) => {
+            visitor.visit_typed_variables(variables);
+            visitor.visit_goal_definition(gd);
+        }
+        GoalDefinition::FComp(comp) => visitor.visit_f_comp(comp),
+    }
+}
+
+/// Visits the predicate and terms of an [`AtomicFormula`] over [`Term`]s.
+pub fn walk_term_atomic_formula<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AtomicFormula<Term>,
+) {
+    match node {
+        AtomicFormula::Equality(formula) => {
+            visitor.visit_term(formula.first());
+            visitor.visit_term(formula.second());
+        }
+        AtomicFormula::Predicate(formula) => {
+            visitor.visit_predicate(formula.predicate());
+            for term in formula.values() {
+                visitor.visit_term(term);
+            }
+        }
+    }
+}
+
+/// Visits the predicate and names of an [`AtomicFormula`] over [`Name`]s.
+pub fn walk_name_atomic_formula<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AtomicFormula<Name>,
+) {
+    match node {
+        AtomicFormula::Equality(formula) => {
+            visitor.visit_name(formula.first());
+            visitor.visit_name(formula.second());
+        }
+        AtomicFormula::Predicate(formula) => {
+            visitor.visit_predicate(formula.predicate());
+            for name in formula.values() {
+                visitor.visit_name(name);
+            }
+        }
+    }
+}
+
+/// Visits the atomic formula of a [`TermLiteral`].
+pub fn walk_term_literal<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast TermLiteral) {
+    match node {
+        Literal::AtomicFormula(formula) | Literal::NotAtomicFormula(formula) => {
+            visitor.visit_term_atomic_formula(formula)
+        }
+    }
+}
+
+/// Visits the atomic formula of a [`NameLiteral`].
+pub fn walk_name_literal<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast NameLiteral) {
+    match node {
+        Literal::AtomicFormula(formula) | Literal::NotAtomicFormula(formula) => {
+            visitor.visit_name_atomic_formula(formula)
+        }
+    }
+}
+
+/// Visits the name, variable or function term of a [`Term`].
+pub fn walk_term<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Term) {
+    match node {
+        Term::Name(name) => visitor.visit_name(name),
+        Term::Variable(variable) => visitor.visit_variable(variable),
+        Term::Function(function) => visitor.visit_function_term(function),
+    }
+}
+
+/// Visits the symbol and terms of a [`FunctionTerm`].
+pub fn walk_function_term<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FunctionTerm,
+) {
+    visitor.visit_function_symbol(node.symbol());
+    for term in node.terms() {
+        visitor.visit_term(term);
+    }
+}
+
+/// Visits the comparison and operands of an [`FComp`].
+pub fn walk_f_comp<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast FComp) {
+    visitor.visit_binary_comp(node.comparison());
+    visitor.visit_f_exp(node.first());
+    visitor.visit_f_exp(node.second());
+}
+
+/// Visits the operands of an [`FExp`].
+pub fn walk_f_exp<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast FExp) {
+    match node {
+        FExp::Number(number) => visitor.visit_number(number),
+        FExp::Function(head) => visitor.visit_f_head(head),
+        FExp::Negative(exp) => visitor.visit_f_exp(exp),
+        FExp::BinaryOp(op, lhs, rhs) => {
+            visitor.visit_binary_op(op);
+            visitor.visit_f_exp(lhs);
+            visitor.visit_f_exp(rhs);
+        }
+        FExp::MultiOp(op, lhs, rhs) => {
+            visitor.visit_multi_op(op);
+            visitor.visit_f_exp(lhs);
+            for exp in rhs {
+                visitor.visit_f_exp(exp);
+            }
+        }
+    }
+}
+
+/// Visits the symbol and terms of an [`FHead`].
+pub fn walk_f_head<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast FHead) {
+    match node {
+        FHead::Simple(symbol) => visitor.visit_function_symbol(symbol),
+        FHead::WithTerms(symbol, terms) => {
+            visitor.visit_function_symbol(symbol);
+            for term in terms {
+                visitor.visit_term(term);
+            }
+        }
+    }
+}
+
+/// Visits each [`CEffect`].
+pub fn walk_effects<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Effects) {
+    for effect in node.iter() {
+        visitor.visit_c_effect(effect);
+    }
+}
+
+/// Visits the primitive, universal or conditional effect of a [`CEffect`].
+pub fn walk_c_effect<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast CEffect) {
+    match node {
+        CEffect::Effect(effect) => visitor.visit_p_effect(effect),
+        CEffect::Forall(effect) => visitor.visit_forall_c_effect(effect),
+        CEffect::When(effect) => visitor.visit_when_c_effect(effect),
+    }
+}
+
+/// Visits the variables and effects of a [`ForallCEffect`].
+pub fn walk_forall_c_effect<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ForallCEffect,
+) {
+    visitor.visit_typed_variables(&node.variables);
+    visitor.visit_effects(&node.effects);
+}
+
+/// Visits the condition and effect of a [`WhenCEffect`].
+pub fn walk_when_c_effect<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast WhenCEffect) {
+    visitor.visit_goal_definition(&node.condition);
+    visitor.visit_conditional_effect(&node.effect);
+}
+
+/// Visits each [`PEffect`] of a [`ConditionalEffect`].
+pub fn walk_conditional_effect<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ConditionalEffect,
+) {
+    match node {
+        ConditionalEffect::Single(effect) => visitor.visit_p_effect(effect),
+        ConditionalEffect::All(effects) => {
+            for effect in effects {
+                visitor.visit_p_effect(effect);
+            }
+        }
+    }
+}
+
+/// Visits the formula or the assignment of a [`PEffect`].
+pub fn walk_p_effect<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast PEffect) {
+    match node {
+        PEffect::AtomicFormula(formula) | PEffect::NotAtomicFormula(formula) => {
+            visitor.visit_term_atomic_formula(formula)
+        }
+        PEffect::AssignNumericFluent(op, head, exp) => {
+            visitor.visit_assign_op(op);
+            visitor.visit_f_head(head);
+            visitor.visit_f_exp(exp);
+        }
+        PEffect::AssignObjectFluent(function, term) => {
+            visitor.visit_function_term(function);
+            if let Some(term) = term {
+                visitor.visit_term(term);
+            }
+        }
+    }
+}
+
+/// Visits each [`SimpleDurationConstraint`] of a [`DurationConstraint`].
+pub fn walk_duration_constraint<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DurationConstraint,
+) {
+    match node {
+        DurationConstraint::Single(constraint) => {
+            visitor.visit_simple_duration_constraint(constraint)
+        }
+        DurationConstraint::All(constraints) => {
+            for constraint in constraints {
+                visitor.visit_simple_duration_constraint(constraint);
+            }
+        }
+    }
+}
+
+/// Visits the operation and value, or the time specifier and nested constraint,
+/// of a [`SimpleDurationConstraint`].
+pub fn walk_simple_duration_constraint<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SimpleDurationConstraint,
+) {
+    match node {
+        SimpleDurationConstraint::Op(op, value) => {
+            visitor.visit_d_op(op);
+            visitor.visit_duration_value(value);
+        }
+        SimpleDurationConstraint::At(time, constraint) => {
+            visitor.visit_time_specifier(time);
+            visitor.visit_simple_duration_constraint(constraint);
+        }
+    }
+}
+
+/// Visits the number or expression of a [`DurationValue`].
+pub fn walk_duration_value<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DurationValue,
+) {
+    match node {
+        DurationValue::Number(number) => visitor.visit_number(number),
+        DurationValue::FExp(exp) => visitor.visit_f_exp(exp),
+    }
+}
+
+/// Visits the timed goals, subgoals and quantified variables of a
+/// [`DurativeActionGoalDefinition`].
+pub fn walk_durative_action_goal_definition<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DurativeActionGoalDefinition,
+) {
+    match node {
+        DurativeActionGoalDefinition::Timed(gd) => visitor.visit_pref_timed_gd(gd),
+        DurativeActionGoalDefinition::And(gds) => {
+            for gd in gds {
+                visitor.visit_durative_action_goal_definition(gd);
+            }
+        }
+        DurativeActionGoalDefinition::Forall(variables, gd) => {
+            visitor.visit_typed_variables(variables);
+            visitor.visit_durative_action_goal_definition(gd);
+        }
+    }
+}
+
+/// Visits the optional preference name and the timed goal of a [`PrefTimedGD`].
+pub fn walk_pref_timed_gd<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast PrefTimedGD) {
+    match node {
+        PrefTimedGD::Required(gd) => visitor.visit_timed_gd(gd),
+        PrefTimedGD::Preference(name, gd) => {
+            if let Some(name) = name {
+                visitor.visit_preference_name(name);
+            }
+            visitor.visit_timed_gd(gd);
+        }
+    }
+}
+
+/// Visits the time specifier or interval and the goal of a [`TimedGD`].
+pub fn walk_timed_gd<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast TimedGD) {
+    match node {
+        TimedGD::At(time, gd) => {
+            visitor.visit_time_specifier(time);
+            visitor.visit_goal_definition(gd);
+        }
+        TimedGD::Over(interval, gd) => {
+            visitor.visit_interval(interval);
+            visitor.visit_goal_definition(gd);
+        }
+    }
+}
+
+/// Visits the timed effects, conditions and quantified variables of a
+/// [`DurativeActionEffect`].
+pub fn walk_durative_action_effect<'ast, V: Visit<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast DurativeActionEffect,
+) {
+    match node {
+        DurativeActionEffect::Timed(effect) => visitor.visit_timed_effect(effect),
+        DurativeActionEffect::All(effects) => {
+            for effect in effects {
+                visitor.visit_durative_action_effect(effect);
+            }
+        }
+        DurativeActionEffect::Forall(variables, effect) => {
+            visitor.visit_typed_variables(variables);
+            visitor.visit_durative_action_effect(effect);
+        }
+        DurativeActionEffect::When(condition, effect) => {
+            visitor.visit_durative_action_goal_definition(condition);
+            visitor.visit_timed_effect(effect);
+        }
+    }
+}
+
+/// Visits the time specifier and effect, or the continuous effect, of a [`TimedEffect`].
+pub fn walk_timed_effect<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast TimedEffect) {
+    match node {
+        TimedEffect::Conditional(time, effect) => {
+            visitor.visit_time_specifier(time);
+            visitor.visit_conditional_effect(effect);
+        }
+        TimedEffect::NumericFluent(time, assignment) => {
+            visitor.visit_time_specifier(time);
+            visitor.visit_f_assign_da(assignment);
+        }
+        TimedEffect::ContinuousEffect(op, head, exp) => {
+            visitor.visit_assign_op_t(op);
+            visitor.visit_f_head(head);
+            visitor.visit_f_exp_t(exp);
+        }
+    }
+}
+
+/// Visits the operation, function and expression of an [`FAssignDa`].
+pub fn walk_f_assign_da<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast FAssignDa) {
+    visitor.visit_assign_op(node.operation());
+    visitor.visit_f_head(node.function());
+    visitor.visit_f_exp_da(node.function_expr());
+}
+
+/// Visits the operands of an [`FExpDa`].
+pub fn walk_f_exp_da<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast FExpDa) {
+    match node {
+        FExpDa::Assign(op, head, exp) => {
+            visitor.visit_assign_op(op);
+            visitor.visit_f_head(head);
+            visitor.visit_f_exp_da(exp);
+        }
+        FExpDa::BinaryOp(op, lhs, rhs) => {
+            visitor.visit_binary_op(op);
+            visitor.visit_f_exp_da(lhs);
+            visitor.visit_f_exp_da(rhs);
+        }
+        FExpDa::MultiOp(op, lhs, rhs) => {
+            visitor.visit_multi_op(op);
+            visitor.visit_f_exp_da(lhs);
+            for exp in rhs {
+                visitor.visit_f_exp_da(exp);
+            }
+        }
+        FExpDa::Negative(exp) => visitor.visit_f_exp_da(exp),
+        FExpDa::Duration => {}
+        FExpDa::FExp(exp) => visitor.visit_f_exp(exp),
+    }
+}
+
+/// Visits the scaled expression of an [`FExpT`].
+pub fn walk_f_exp_t<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast FExpT) {
+    match node {
+        FExpT::Now => {}
+        FExpT::Scaled(exp) => visitor.visit_f_exp(exp),
+    }
+}
+
+/// Visits the goals, nested constraints, numbers and quantified variables of a [`ConGD`].
+pub fn walk_con_gd<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast ConGD) {
+    match node {
+        ConGD::And(gds) => {
+            for gd in gds {
+                visitor.visit_con_gd(gd);
+            }
+        }
+        ConGD::Forall(variables, gd) => {
+            visitor.visit_typed_variables(variables);
+            visitor.visit_con_gd(gd);
+        }
+        ConGD::AtEnd(gd) => visitor.visit_goal_definition(gd),
+        ConGD::Always(gd) | ConGD::Sometime(gd) | ConGD::AtMostOnce(gd) => {
+            visitor.visit_con2_gd(gd)
+        }
+        ConGD::Within(number, gd) | ConGD::HoldAfter(number, gd) => {
+            visitor.visit_number(number);
+            visitor.visit_con2_gd(gd);
+        }
+        ConGD::SometimeAfter(first, second) | ConGD::SometimeBefore(first, second) => {
+            visitor.visit_con2_gd(first);
+            visitor.visit_con2_gd(second);
+        }
+        ConGD::AlwaysWithin(number, first, second) => {
+            visitor.visit_number(number);
+            visitor.visit_con2_gd(first);
+            visitor.visit_con2_gd(second);
+        }
+        ConGD::HoldDuring(start, end, gd) => {
+            visitor.visit_number(start);
+            visitor.visit_number(end);
+            visitor.visit_con2_gd(gd);
+        }
+    }
+}
+
+/// Visits the goal or nested constraint of a [`Con2GD`].
+pub fn walk_con2_gd<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast Con2GD) {
+    match node {
+        Con2GD::Goal(gd) => visitor.visit_goal_definition(gd),
+        Con2GD::Nested(gd) => visitor.visit_con_gd(gd),
+    }
+}
+
+/// Visits each [`PrefConGD`].
+pub fn walk_pref_con_gds<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast PrefConGDs) {
+    for gd in node.iter() {
+        visitor.visit_pref_con_gd(gd);
+    }
+}
+
+/// Visits the constraint, preference name and quantified variables of a [`PrefConGD`].
+pub fn walk_pref_con_gd<'ast, V: Visit<'ast> + ?Sized>(visitor: &mut V, node: &'ast PrefConGD) {
+    match node {
+        PrefConGD::Goal(gd) => visitor.visit_con_gd(gd),
+        PrefConGD::Forall(variables, gds) => {
+            visitor.visit_typed_variables(variables);
+            visitor.visit_pref_con_gds(gds);
+        }
+        PrefConGD::Preference(name, gd) => {
+            if let Some(name) = name {
+                visitor.visit_preference_name(name);
+            }
+            visitor.visit_con_gd(gd);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    const DOMAIN: &str = r#"(define (domain briefcase-world)
+        (:requirements :strips :typing :conditional-effects :durative-actions)
+        (:types location physob)
+        (:constants B - physob)
+        (:predicates (at ?x - physob ?y - location) (in ?x - physob))
+        (:functions (distance ?from ?to - location))
+        (:action mov-B
+            :parameters (?m ?l - location)
+            :precondition (and (at B ?m) (not (= ?m ?l)))
+            :effect (and (at B ?l) (not (at B ?m))
+                         (forall (?z)
+                             (when (and (in ?z) (not (= ?z B)))
+                                   (and (at ?z ?l) (not (at ?z ?m)))))))
+        (:durative-action drive
+            :parameters (?from ?to - location)
+            :duration (= ?duration (distance ?from ?to))
+            :condition (at start (at B ?from))
+            :effect (and (at start (not (at B ?from))) (at end (at B ?to)))))"#;
+
+    const PROBLEM: &str = r#"(define (problem get-paid)
+        (:domain briefcase-world)
+        (:objects home office - location P - physob)
+        (:init (at B home) (at P home) (in P) (= (distance home office) 10))
+        (:goal (and (at B office) (at P home)))
+        (:metric minimize (total-time)))"#;
+
+    /// Counts nodes by kind.
+    #[derive(Default)]
+    struct Counter {
+        names: usize,
+        variables: usize,
+        predicates: usize,
+        effects: usize,
+        numbers: usize,
+    }
+
+    impl<'ast> Visit<'ast> for Counter {
+        fn visit_name(&mut self, _node: &'ast Name) {
+            self.names += 1;
+        }
+
+        fn visit_variable(&mut self, node: &'ast Variable) {
+            self.variables += 1;
+            walk_variable(self, node);
+        }
+
+        fn visit_predicate(&mut self, node: &'ast Predicate) {
+            self.predicates += 1;
+            walk_predicate(self, node);
+        }
+
+        fn visit_p_effect(&mut self, node: &'ast PEffect) {
+            self.effects += 1;
+            walk_p_effect(self, node);
+        }
+
+        fn visit_number(&mut self, _node: &'ast Number) {
+            self.numbers += 1;
+        }
+    }
+
+    #[test]
+    fn walks_the_domain() {
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        let mut counter = Counter::default();
+        counter.visit_domain(&domain);
+
+        // 2 predicate definitions, 6 in mov-B, 3 in drive.
+        assert_eq!(counter.predicates, 2 + 6 + 3);
+        assert_eq!(counter.effects, 6);
+        assert!(counter.variables > counter.predicates);
+        assert!(counter.names > counter.variables);
+    }
+
+    #[test]
+    fn walks_the_problem() {
+        let problem = Problem::from_str(PROBLEM).unwrap();
+        let mut counter = Counter::default();
+        counter.visit_problem(&problem);
+
+        assert_eq!(counter.predicates, 5);
+        assert_eq!(counter.numbers, 1);
+        assert_eq!(counter.variables, 0);
+    }
+
+    #[test]
+    fn overrides_stop_the_descent() {
+        /// Collects the variables of action parameters only.
+        #[derive(Default)]
+        struct Parameters<'ast>(Vec<&'ast Name>);
+
+        impl<'ast> Visit<'ast> for Parameters<'ast> {
+            fn visit_atomic_formula_skeleton(&mut self, _node: &'ast AtomicFormulaSkeleton) {}
+            fn visit_atomic_function_skeleton(&mut self, _node: &'ast AtomicFunctionSkeleton) {}
+            fn visit_precondition_goal_definitions(
+                &mut self,
+                _node: &'ast PreconditionGoalDefinitions,
+            ) {
+            }
+            fn visit_effects(&mut self, _node: &'ast Effects) {}
+            fn visit_duration_constraint(&mut self, _node: &'ast DurationConstraint) {}
+            fn visit_durative_action_goal_definition(
+                &mut self,
+                _node: &'ast DurativeActionGoalDefinition,
+            ) {
+            }
+            fn visit_durative_action_effect(&mut self, _node: &'ast DurativeActionEffect) {}
+
+            fn visit_variable(&mut self, node: &'ast Variable) {
+                self.0.push(node);
+            }
+        }
+
+        let domain = Domain::from_str(DOMAIN).unwrap();
+        let mut visitor = Parameters::default();
+        visitor.visit_domain(&domain);
+        assert_eq!(visitor.0, ["m", "l", "from", "to"]);
+    }
+}
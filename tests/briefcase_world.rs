@@ -1,3 +1,4 @@
+use pddl::format::{format, FormatOptions, SectionOrder};
 use pddl::{
     AtomicFormula, Domain, GoalDefinition, Parser, PreconditionGoalDefinition, PreferenceGD,
     Problem, TermLiteral,
@@ -116,3 +117,67 @@ fn parse_problem_works() {
     }
     assert_eq!(atomic_formulas, 3);
 }
+
+#[test]
+fn printed_domain_round_trips() {
+    let domain = Domain::from_str(BRIEFCASE_WORLD).unwrap();
+    let printed = domain.to_string();
+    assert_eq!(Domain::from_str(&printed).unwrap(), domain, "{printed}");
+}
+
+#[test]
+fn printed_problem_round_trips() {
+    let problem = Problem::from_str(BRIEFCASE_WORLD_PROBLEM).unwrap();
+    let printed = problem.to_string();
+    assert_eq!(Problem::from_str(&printed).unwrap(), problem, "{printed}");
+}
+
+#[test]
+fn formatted_domain_is_unchanged() {
+    let options = FormatOptions::default().with_section_order(SectionOrder::Sorted);
+    let formatted = format(BRIEFCASE_WORLD, &options).unwrap();
+    assert!(formatted.contains("; the briefcase"));
+    assert_eq!(
+        Domain::from_str(&formatted).unwrap(),
+        Domain::from_str(BRIEFCASE_WORLD).unwrap()
+    );
+}
+
+#[test]
+fn formatted_problem_is_unchanged() {
+    let options = FormatOptions::default().with_line_width(40);
+    let formatted = format(BRIEFCASE_WORLD_PROBLEM, &options).unwrap();
+    assert!(formatted.contains("; types: locations"));
+    assert_eq!(
+        Problem::from_str(&formatted).unwrap(),
+        Problem::from_str(BRIEFCASE_WORLD_PROBLEM).unwrap()
+    );
+}
+
+#[test]
+fn validation_reports_inconsistencies() {
+    use pddl::validate::{validate, Diagnostic};
+
+    let domain = Domain::from_str(BRIEFCASE_WORLD).unwrap();
+    let problem = Problem::from_str(BRIEFCASE_WORLD_PROBLEM).unwrap();
+
+    // The problem declares locations and objects through predicates instead of `:objects`.
+    let mut expected = vec![
+        Diagnostic::UndeclaredPredicate("place".into()),
+        Diagnostic::UndeclaredObject("home".into()),
+        Diagnostic::UndeclaredObject("office".into()),
+        Diagnostic::UndeclaredPredicate("object".into()),
+    ];
+
+    // The lowercase `p`, `d` and `b` only name the constants `P`, `D` and `B` when case is folded.
+    if !cfg!(feature = "case-folding") {
+        expected.extend(["p", "d", "b"].map(|name| Diagnostic::UndeclaredObject(name.into())));
+    }
+
+    expected.push(Diagnostic::ArityMismatch {
+        symbol: "in".into(),
+        expected: 2,
+        found: 1,
+    });
+    assert_eq!(validate(&domain, &problem), expected);
+}
@@ -0,0 +1,738 @@
+//! Property tests asserting that printed PDDL parses back into the same value.
+//!
+//! The strategies below only generate values in the shape the parser produces them.
+//! Some types can be expressed in more than one way (e.g. a [`GoalDefinition::Literal`]
+//! is indistinguishable from a [`GoalDefinition::Not`] of an atomic formula once printed),
+//! and the parser picks one canonical representation for each of them.
+
+use pddl::{
+    ActionDefinition, ActionSymbol, AssignOp, AssignOpT, AtomicFormula, AtomicFormulaSkeleton,
+    AtomicFunctionSkeleton, BasicFunctionTerm, BinaryComp, BinaryOp, CEffect, Con2GD, ConGD,
+    ConditionalEffect, Constants, DOp, DerivedPredicate, Domain, DomainConstraintsDef,
+    DurationConstraint, DurationValue, DurativeActionDefinition, DurativeActionEffect,
+    DurativeActionGoalDefinition, DurativeActionSymbol, Effects, FAssignDa, FComp, FExp, FExpDa,
+    FExpT, FHead, FunctionSymbol, FunctionTerm, FunctionType, FunctionTyped, FunctionTypedList,
+    Functions, GoalDef, GoalDefinition, InitElement, InitElements, Interval, LengthSpec, Literal,
+    MetricFExp, MetricSpec, MultiOp, Name, Number, Objects, Optimization, PEffect, Parser,
+    PreconditionGoalDefinition, PreconditionGoalDefinitions, Predicate, PredicateDefinitions,
+    PrefConGD, PrefConGDs, PrefTimedGD, Preference, PreferenceGD, PreferenceName, PrimitiveType,
+    Problem, ProblemConstraintsDef, Requirement, Requirements, SimpleDurationConstraint,
+    StructureDef, StructureDefs, Term, TimeSpecifier, TimedEffect, TimedGD, Timeless, Type, Typed,
+    TypedList, Types, Variable,
+};
+use proptest::prelude::*;
+use proptest::sample::select;
+
+/// Names that have a meaning of their own in at least one position of the grammar.
+const KEYWORDS: &[&str] = &[
+    "all",
+    "always",
+    "always-within",
+    "and",
+    "assign",
+    "at",
+    "at-most-once",
+    "decrease",
+    "define",
+    "domain",
+    "duration",
+    "either",
+    "end",
+    "exists",
+    "forall",
+    "hold-after",
+    "hold-during",
+    "imply",
+    "increase",
+    "is-violated",
+    "maximize",
+    "minimize",
+    "not",
+    "number",
+    "object",
+    "or",
+    "over",
+    "preference",
+    "problem",
+    "scale-down",
+    "scale-up",
+    "sometime",
+    "sometime-after",
+    "sometime-before",
+    "start",
+    "total-time",
+    "undefined",
+    "when",
+    "within",
+];
+
+fn name() -> impl Strategy<Value = Name> {
+    "[a-z][a-z0-9]{0,4}(-[a-z0-9]{1,3})?"
+        .prop_filter("keywords are reserved", |s| !KEYWORDS.contains(&s.as_str()))
+        .prop_map(Name::new)
+}
+
+fn variable() -> impl Strategy<Value = Variable> {
+    name().prop_map(Variable::new)
+}
+
+fn number() -> impl Strategy<Value = Number> {
+    prop_oneof![
+        (0u32..1000).prop_map(Number::from),
+        (0u32..4000).prop_map(|n| Number::new(n as f32 / 8.0)),
+        (-1000i32..0).prop_map(Number::from),
+        (-4000i32..0).prop_map(|n| Number::new(n as f32 / 8.0)),
+    ]
+}
+
+fn primitive_type() -> impl Strategy<Value = PrimitiveType> {
+    prop_oneof![
+        1 => Just(PrimitiveType::from("object")),
+        4 => name().prop_map(PrimitiveType::new),
+    ]
+}
+
+fn r#type() -> impl Strategy<Value = Type> {
+    prop_oneof![
+        4 => primitive_type().prop_map(Type::Exactly),
+        1 => prop::collection::vec(primitive_type(), 1..3).prop_map(Type::EitherOf),
+    ]
+}
+
+fn typed_list<T: std::fmt::Debug + Clone, S: Strategy<Value = T>>(
+    inner: fn() -> S,
+) -> impl Strategy<Value = TypedList<T>> {
+    let typed = prop_oneof![
+        inner().prop_map(Typed::new_object),
+        (inner(), r#type()).prop_map(|(value, r#type)| Typed::new(value, r#type)),
+    ];
+    prop::collection::vec(typed, 0..4).prop_map(TypedList::new)
+}
+
+fn typed_variables() -> impl Strategy<Value = TypedList<Variable>> {
+    typed_list(variable)
+}
+
+fn term() -> impl Strategy<Value = Term> {
+    let leaf = prop_oneof![
+        name().prop_map(Term::Name),
+        variable().prop_map(Term::Variable),
+    ];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        (name(), prop::collection::vec(inner, 0..3))
+            .prop_map(|(s, terms)| Term::Function(FunctionTerm::new(FunctionSymbol::new(s), terms)))
+    })
+}
+
+fn atomic_formula<T: std::fmt::Debug + Clone, S: Strategy<Value = T>>(
+    inner: fn() -> S,
+) -> impl Strategy<Value = AtomicFormula<T>> {
+    prop_oneof![
+        (inner(), inner()).prop_map(|(a, b)| AtomicFormula::new_equality(a, b)),
+        (name(), prop::collection::vec(inner(), 0..3))
+            .prop_map(|(p, values)| AtomicFormula::new_predicate(Predicate::new(p), values)),
+    ]
+}
+
+fn literal<T: std::fmt::Debug + Clone, S: Strategy<Value = T>>(
+    inner: fn() -> S,
+) -> impl Strategy<Value = Literal<T>> {
+    prop_oneof![
+        atomic_formula(inner).prop_map(Literal::new),
+        atomic_formula(inner).prop_map(Literal::new_not),
+    ]
+}
+
+fn f_head() -> impl Strategy<Value = FHead> {
+    prop_oneof![
+        name().prop_map(|s| FHead::new(FunctionSymbol::new(s))),
+        (name(), prop::collection::vec(term(), 1..3))
+            .prop_map(|(s, terms)| FHead::new_with_terms(FunctionSymbol::new(s), terms)),
+    ]
+}
+
+fn binary_op() -> impl Strategy<Value = BinaryOp> {
+    select(vec![
+        BinaryOp::Multiplication,
+        BinaryOp::Addition,
+        BinaryOp::Subtraction,
+        BinaryOp::Division,
+    ])
+}
+
+fn multi_op() -> impl Strategy<Value = MultiOp> {
+    select(vec![MultiOp::Multiplication, MultiOp::Addition])
+}
+
+fn f_exp() -> impl Strategy<Value = FExp> {
+    let leaf = prop_oneof![
+        number().prop_map(FExp::Number),
+        f_head().prop_map(FExp::Function),
+    ];
+    leaf.prop_recursive(3, 12, 3, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(FExp::new_negative),
+            (binary_op(), inner.clone(), inner.clone())
+                .prop_map(|(op, lhs, rhs)| FExp::new_binary_op(op, lhs, rhs)),
+            (
+                multi_op(),
+                inner.clone(),
+                prop::collection::vec(inner, 2..4)
+            )
+                .prop_map(|(op, lhs, rhs)| FExp::new_multi_op(op, lhs, rhs)),
+        ]
+    })
+}
+
+fn f_comp() -> impl Strategy<Value = FComp> {
+    let comp = select(vec![
+        BinaryComp::GreaterThan,
+        BinaryComp::LessThan,
+        BinaryComp::Equal,
+        BinaryComp::GreaterOrEqual,
+        BinaryComp::LessThanOrEqual,
+    ]);
+    // `(= (f) (g))` is read as an equality of two function terms.
+    (comp, f_exp(), f_exp())
+        .prop_filter("equality of terms", |(comp, lhs, rhs)| {
+            *comp != BinaryComp::Equal
+                || !matches!(lhs, FExp::Function(_))
+                || !matches!(rhs, FExp::Function(_))
+        })
+        .prop_map(|(comp, lhs, rhs)| FComp::new(comp, lhs, rhs))
+}
+
+fn gd() -> impl Strategy<Value = GoalDefinition> {
+    let leaf = prop_oneof![
+        4 => atomic_formula(term).prop_map(GoalDefinition::new_atomic_formula),
+        1 => f_comp().prop_map(GoalDefinition::new_f_comp),
+    ];
+    leaf.prop_recursive(3, 16, 3, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..3).prop_map(GoalDefinition::new_and),
+            prop::collection::vec(inner.clone(), 0..3).prop_map(GoalDefinition::new_or),
+            inner.clone().prop_map(GoalDefinition::new_not),
+            (inner.clone(), inner.clone()).prop_map(|(a, b)| GoalDefinition::new_imply(a, b)),
+            (typed_variables(), inner.clone())
+                .prop_map(|(vars, gd)| GoalDefinition::new_exists(vars, gd)),
+            (typed_variables(), inner).prop_map(|(vars, gd)| GoalDefinition::new_forall(vars, gd)),
+        ]
+    })
+}
+
+fn preference_name() -> impl Strategy<Value = Option<PreferenceName>> {
+    prop::option::of(name().prop_map(PreferenceName::new))
+}
+
+fn pre_gd() -> impl Strategy<Value = PreconditionGoalDefinition> {
+    // Conjunctions and universal quantifiers are folded into the surrounding list.
+    let goal = gd()
+        .prop_filter("lifted by the parser", |gd| {
+            !matches!(gd, GoalDefinition::And(_) | GoalDefinition::ForAll(_, _))
+        })
+        .prop_map(PreferenceGD::Goal);
+    let preference = (preference_name(), gd())
+        .prop_map(|(name, gd)| PreferenceGD::Preference(Preference::new(name, gd)));
+    let leaf = prop_oneof![goal, preference].prop_map(PreconditionGoalDefinition::new_preference);
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        (typed_variables(), prop::collection::vec(inner, 0..3)).prop_map(|(vars, gds)| {
+            PreconditionGoalDefinition::new_forall(vars, PreconditionGoalDefinitions::new(gds))
+        })
+    })
+}
+
+fn pre_gds() -> impl Strategy<Value = PreconditionGoalDefinitions> {
+    prop::collection::vec(pre_gd(), 0..4).prop_map(PreconditionGoalDefinitions::new)
+}
+
+fn assign_op() -> impl Strategy<Value = AssignOp> {
+    select(vec![
+        AssignOp::Assign,
+        AssignOp::ScaleUp,
+        AssignOp::ScaleDown,
+        AssignOp::Increase,
+        AssignOp::Decrease,
+    ])
+}
+
+fn p_effect() -> impl Strategy<Value = PEffect> {
+    // `(assign (f) (g))` is read as an object fluent assignment.
+    let numeric = (assign_op(), f_head(), f_exp())
+        .prop_filter("object fluent", |(op, _, exp)| {
+            *op != AssignOp::Assign || !matches!(exp, FExp::Function(_))
+        })
+        .prop_map(|(op, head, exp)| PEffect::new_numeric_fluent(op, head, exp));
+    let object = (
+        name(),
+        prop::collection::vec(term(), 0..3),
+        prop::option::of(term()),
+    )
+        .prop_map(|(s, terms, value)| {
+            PEffect::new_object_fluent(FunctionTerm::new(FunctionSymbol::new(s), terms), value)
+        });
+    prop_oneof![
+        3 => atomic_formula(term).prop_map(PEffect::new),
+        3 => atomic_formula(term).prop_map(PEffect::new_not),
+        1 => numeric,
+        1 => object,
+    ]
+}
+
+fn conditional_effect() -> impl Strategy<Value = ConditionalEffect> {
+    prop_oneof![
+        p_effect().prop_map(ConditionalEffect::new),
+        prop::collection::vec(p_effect(), 0..3).prop_map(ConditionalEffect::new_and),
+    ]
+}
+
+fn c_effect() -> impl Strategy<Value = CEffect> {
+    let leaf = prop_oneof![
+        3 => p_effect().prop_map(CEffect::new_p_effect),
+        1 => (gd(), conditional_effect())
+            .prop_map(|(condition, effect)| CEffect::new_when(condition, effect)),
+    ];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        (typed_variables(), prop::collection::vec(inner, 0..3))
+            .prop_map(|(vars, effects)| CEffect::new_forall(vars, Effects::new_and(effects)))
+    })
+}
+
+fn effects() -> impl Strategy<Value = Effects> {
+    prop::collection::vec(c_effect(), 0..4).prop_map(Effects::new_and)
+}
+
+fn time_specifier() -> impl Strategy<Value = TimeSpecifier> {
+    select(vec![TimeSpecifier::Start, TimeSpecifier::End])
+}
+
+fn timed_gd() -> impl Strategy<Value = TimedGD> {
+    prop_oneof![
+        (time_specifier(), gd()).prop_map(|(time, gd)| TimedGD::new_at(time, gd)),
+        gd().prop_map(|gd| TimedGD::new_over(Interval::All, gd)),
+    ]
+}
+
+fn da_gd() -> impl Strategy<Value = DurativeActionGoalDefinition> {
+    let leaf = prop_oneof![
+        timed_gd().prop_map(PrefTimedGD::new_required),
+        (preference_name(), timed_gd())
+            .prop_map(|(name, gd)| PrefTimedGD::new_preference(name, gd)),
+    ]
+    .prop_map(DurativeActionGoalDefinition::new_timed);
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..3)
+                .prop_map(DurativeActionGoalDefinition::new_and),
+            (typed_variables(), inner)
+                .prop_map(|(vars, gd)| DurativeActionGoalDefinition::new_forall(vars, gd)),
+        ]
+    })
+}
+
+fn f_exp_da() -> impl Strategy<Value = FExpDa> {
+    // Compound numeric expressions are always read as their duration-aware counterparts.
+    let leaf = prop_oneof![
+        Just(FExpDa::new_duration()),
+        number().prop_map(|n| FExpDa::new_f_exp(FExp::Number(n))),
+        f_head().prop_map(|head| FExpDa::new_f_exp(FExp::Function(head))),
+    ];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(FExpDa::new_negative),
+            (binary_op(), inner.clone(), inner.clone())
+                .prop_map(|(op, lhs, rhs)| FExpDa::new_binary_op(op, lhs, rhs)),
+            (
+                multi_op(),
+                inner.clone(),
+                prop::collection::vec(inner, 2..4)
+            )
+                .prop_map(|(op, lhs, rhs)| FExpDa::new_multi_op(op, lhs, rhs)),
+        ]
+    })
+}
+
+fn timed_effect() -> impl Strategy<Value = TimedEffect> {
+    // A single fluent assignment is read as a numeric fluent effect.
+    let conditional = (time_specifier(), conditional_effect())
+        .prop_filter("numeric fluent", |(_, effect)| {
+            !matches!(
+                effect,
+                ConditionalEffect::Single(
+                    PEffect::AssignNumericFluent(..) | PEffect::AssignObjectFluent(..)
+                )
+            )
+        })
+        .prop_map(|(time, effect)| TimedEffect::new_conditional(time, effect));
+    let fluent =
+        (time_specifier(), assign_op(), f_head(), f_exp_da()).prop_map(|(time, op, head, exp)| {
+            TimedEffect::new_fluent(time, FAssignDa::new(op, head, exp))
+        });
+    let f_exp_t = prop_oneof![Just(FExpT::new()), f_exp().prop_map(FExpT::new_scaled)];
+    let continuous = (
+        select(vec![AssignOpT::Increase, AssignOpT::Decrease]),
+        f_head(),
+        f_exp_t,
+    )
+        .prop_map(|(op, head, exp)| TimedEffect::new_continuous(op, head, exp));
+    prop_oneof![conditional, fluent, continuous]
+}
+
+fn da_effect() -> impl Strategy<Value = DurativeActionEffect> {
+    let leaf = prop_oneof![
+        3 => timed_effect().prop_map(DurativeActionEffect::new_timed),
+        1 => (da_gd(), timed_effect())
+            .prop_map(|(gd, effect)| DurativeActionEffect::new_when(gd, effect)),
+    ];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..3).prop_map(DurativeActionEffect::new_and),
+            (typed_variables(), inner)
+                .prop_map(|(vars, effect)| DurativeActionEffect::new_forall(vars, effect)),
+        ]
+    })
+}
+
+fn simple_duration_constraint() -> impl Strategy<Value = SimpleDurationConstraint> {
+    let value = prop_oneof![
+        number().prop_map(DurationValue::new_number),
+        f_exp()
+            .prop_filter("numbers are read as such", |exp| {
+                !matches!(exp, FExp::Number(_))
+            })
+            .prop_map(DurationValue::new_f_exp),
+    ];
+    let op = (
+        select(vec![DOp::Equal, DOp::LessThanOrEqual, DOp::GreaterOrEqual]),
+        value,
+    )
+        .prop_map(|(op, value)| SimpleDurationConstraint::new_op(op, value));
+    op.prop_recursive(2, 4, 1, |inner| {
+        (time_specifier(), inner)
+            .prop_map(|(time, constraint)| SimpleDurationConstraint::new_at(time, constraint))
+    })
+}
+
+fn duration_constraint() -> impl Strategy<Value = DurationConstraint> {
+    prop_oneof![
+        simple_duration_constraint().prop_map(DurationConstraint::new),
+        prop::collection::vec(simple_duration_constraint(), 1..3)
+            .prop_map(DurationConstraint::new_all),
+    ]
+}
+
+fn structure_def() -> impl Strategy<Value = StructureDef> {
+    let action = (
+        name(),
+        typed_variables(),
+        pre_gds(),
+        prop::option::of(effects()),
+    )
+        .prop_map(|(name, parameters, precondition, effect)| {
+            StructureDef::new_action(ActionDefinition::new(
+                ActionSymbol::new(name),
+                parameters,
+                precondition,
+                effect,
+            ))
+        });
+    let durative_action = (
+        name(),
+        typed_variables(),
+        prop::option::of(duration_constraint()),
+        prop::option::of(da_gd()),
+        prop::option::of(da_effect()),
+    )
+        .prop_map(|(name, parameters, duration, condition, effect)| {
+            StructureDef::new_durative_action(DurativeActionDefinition::new(
+                DurativeActionSymbol::new(name),
+                parameters,
+                duration,
+                condition,
+                effect,
+            ))
+        });
+    let derived = (atomic_formula_skeleton(), gd())
+        .prop_map(|(skeleton, gd)| StructureDef::new_derived(DerivedPredicate::new(skeleton, gd)));
+    prop_oneof![
+        3 => action,
+        2 => durative_action,
+        1 => derived,
+    ]
+}
+
+fn atomic_formula_skeleton() -> impl Strategy<Value = AtomicFormulaSkeleton> {
+    (name(), typed_variables())
+        .prop_map(|(p, vars)| AtomicFormulaSkeleton::new(Predicate::new(p), vars))
+}
+
+fn functions() -> impl Strategy<Value = Functions> {
+    let skeleton = || {
+        (name(), typed_variables())
+            .prop_map(|(s, vars)| AtomicFunctionSkeleton::new(FunctionSymbol::new(s), vars))
+    };
+    let typed = prop_oneof![
+        skeleton().prop_map(FunctionTyped::new_number),
+        (skeleton(), r#type())
+            .prop_map(|(skeleton, r#type)| FunctionTyped::new(skeleton, FunctionType::new(r#type))),
+    ];
+    prop::collection::vec(typed, 0..3)
+        .prop_map(|functions| Functions::new(FunctionTypedList::new(functions)))
+}
+
+fn con2_gd() -> impl Strategy<Value = Con2GD> {
+    // Nested constraints are indistinguishable from atomic formulas once printed.
+    gd().prop_map(Con2GD::new_goal)
+}
+
+fn con_gd() -> impl Strategy<Value = ConGD> {
+    let leaf = prop_oneof![
+        gd().prop_map(ConGD::new_at_end),
+        con2_gd().prop_map(ConGD::new_always),
+        con2_gd().prop_map(ConGD::new_sometime),
+        (number(), con2_gd()).prop_map(|(n, gd)| ConGD::new_within(n, gd)),
+        con2_gd().prop_map(ConGD::new_at_most_once),
+        (con2_gd(), con2_gd()).prop_map(|(a, b)| ConGD::new_sometime_after(a, b)),
+        (con2_gd(), con2_gd()).prop_map(|(a, b)| ConGD::new_sometime_before(a, b)),
+        (number(), con2_gd(), con2_gd()).prop_map(|(n, a, b)| ConGD::new_always_within(n, a, b)),
+        (number(), number(), con2_gd())
+            .prop_map(|(from, to, gd)| ConGD::new_hold_during(from, to, gd)),
+        (number(), con2_gd()).prop_map(|(n, gd)| ConGD::new_hold_after(n, gd)),
+    ];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..3).prop_map(ConGD::new_and),
+            (typed_variables(), inner).prop_map(|(vars, gd)| ConGD::new_forall(vars, gd)),
+        ]
+    })
+}
+
+fn pref_con_gd() -> impl Strategy<Value = PrefConGD> {
+    // Conjunctions and universal quantifiers are folded into the surrounding list.
+    let goal = con_gd()
+        .prop_filter("lifted by the parser", |gd| {
+            !matches!(gd, ConGD::And(_) | ConGD::Forall(_, _))
+        })
+        .prop_map(PrefConGD::new_goal);
+    let preference =
+        (preference_name(), con_gd()).prop_map(|(name, gd)| PrefConGD::new_preference(name, gd));
+    let leaf = prop_oneof![goal, preference];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        (typed_variables(), prop::collection::vec(inner, 0..3))
+            .prop_map(|(vars, gds)| PrefConGD::new_forall(vars, PrefConGDs::new(gds)))
+    })
+}
+
+fn pref_con_gds() -> impl Strategy<Value = PrefConGDs> {
+    prop::collection::vec(pref_con_gd(), 0..3).prop_map(PrefConGDs::new)
+}
+
+fn init_element() -> impl Strategy<Value = InitElement> {
+    let term = || {
+        (name(), prop::collection::vec(name(), 0..3))
+            .prop_map(|(s, names)| BasicFunctionTerm::new(FunctionSymbol::new(s), names))
+    };
+    prop_oneof![
+        literal(name).prop_map(InitElement::new_literal),
+        (number(), literal(name)).prop_map(|(time, lit)| InitElement::new_at(time, lit)),
+        (term(), number()).prop_map(|(term, n)| InitElement::new_is_value(term, n)),
+        (term(), name()).prop_map(|(term, name)| InitElement::new_is_object(term, name)),
+    ]
+}
+
+fn metric_f_exp() -> impl Strategy<Value = MetricFExp> {
+    let leaf = prop_oneof![
+        number().prop_map(MetricFExp::new_number),
+        (name(), prop::collection::vec(name(), 0..3))
+            .prop_map(|(s, names)| MetricFExp::new_function(FunctionSymbol::new(s), names)),
+        Just(MetricFExp::new_total_time()),
+        name().prop_map(|n| MetricFExp::new_is_violated(PreferenceName::new(n))),
+    ];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            inner.clone().prop_map(MetricFExp::new_negative),
+            (binary_op(), inner.clone(), inner.clone())
+                .prop_map(|(op, lhs, rhs)| MetricFExp::new_binary_op(op, lhs, rhs)),
+            (
+                multi_op(),
+                inner.clone(),
+                prop::collection::vec(inner, 2..4)
+            )
+                .prop_map(|(op, lhs, rhs)| MetricFExp::new_multi_op(op, lhs, rhs)),
+        ]
+    })
+}
+
+fn metric_spec() -> impl Strategy<Value = MetricSpec> {
+    (
+        select(vec![Optimization::Minimize, Optimization::Maximize]),
+        metric_f_exp(),
+    )
+        .prop_map(|(optimization, exp)| MetricSpec::new(optimization, exp))
+}
+
+fn requirements() -> impl Strategy<Value = Requirements> {
+    let requirement = select(vec![
+        Requirement::Strips,
+        Requirement::Typing,
+        Requirement::NegativePreconditions,
+        Requirement::DisjunctivePreconditions,
+        Requirement::Equality,
+        Requirement::ExistentialPreconditions,
+        Requirement::UniversalPreconditions,
+        Requirement::QuantifiedPreconditions,
+        Requirement::ConditionalEffects,
+        Requirement::Fluents,
+        Requirement::NumericFluents,
+        Requirement::ObjectFluents,
+        Requirement::Adl,
+        Requirement::DurativeActions,
+        Requirement::DurationInequalities,
+        Requirement::ContinuousEffects,
+        Requirement::DerivedPredicates,
+        Requirement::TimedInitialLiterals,
+        Requirement::Preferences,
+        Requirement::Constraints,
+        Requirement::ActionCosts,
+    ]);
+    prop::collection::vec(requirement, 0..4).prop_map(Requirements::new)
+}
+
+fn domain() -> impl Strategy<Value = Domain> {
+    (
+        (
+            name(),
+            prop::collection::vec(name(), 0..2),
+            requirements(),
+            typed_list(name),
+            typed_list(name),
+        ),
+        (
+            prop::collection::vec(atomic_formula_skeleton(), 0..3),
+            prop::collection::vec(literal(name), 0..3),
+            functions(),
+            prop::option::of(con_gd()),
+            prop::collection::vec(structure_def(), 0..3),
+        ),
+    )
+        .prop_map(
+            |(
+                (name, extends, requirements, types, constants),
+                (predicates, timeless, functions, constraints, structure),
+            )| {
+                let mut domain = Domain::builder(name, StructureDefs::new(structure))
+                    .with_extends(extends)
+                    .with_requirements(requirements)
+                    .with_types(Types::new(types))
+                    .with_constants(Constants::new(constants))
+                    .with_predicates(PredicateDefinitions::new(predicates))
+                    .with_functions(functions)
+                    .with_timeless(Timeless::new(timeless));
+                if let Some(constraints) = constraints {
+                    domain = domain.with_constraints(DomainConstraintsDef::new(constraints));
+                }
+                domain
+            },
+        )
+}
+
+fn problem() -> impl Strategy<Value = Problem> {
+    (
+        (name(), name(), requirements(), typed_list(name)),
+        (
+            prop::collection::vec(init_element(), 0..4),
+            pre_gds(),
+            pref_con_gds(),
+            prop::option::of(metric_spec()),
+            prop::option::of((prop::option::of(0u64..100), prop::option::of(0u64..100))),
+        ),
+    )
+        .prop_map(
+            |((name, domain, requirements, objects), (init, goal, constraints, metric, length))| {
+                let mut problem =
+                    Problem::builder(name, domain, InitElements::new(init), GoalDef::new(goal))
+                        .with_requirements(requirements)
+                        .with_objects(Objects::new(objects))
+                        .with_constraints(ProblemConstraintsDef::new(constraints));
+                if let Some(metric) = metric {
+                    problem = problem.with_metric_spec(metric);
+                }
+                if let Some((serial, parallel)) = length {
+                    problem = problem.with_length_spec(LengthSpec::new(serial, parallel));
+                }
+                problem
+            },
+        )
+}
+
+/// Asserts that printing a value and parsing it again yields the same value.
+macro_rules! assert_round_trip {
+    ($type:ty, $value:expr) => {{
+        let value = $value;
+        let printed = value.to_string();
+        let parsed = <$type>::from_str(&printed);
+        prop_assert!(parsed.is_ok(), "failed to parse {printed}");
+        prop_assert_eq!(parsed.unwrap(), value, "printed as {}", printed);
+    }};
+}
+
+proptest! {
+    #[test]
+    fn gd_round_trips(value in gd()) {
+        assert_round_trip!(GoalDefinition, value);
+    }
+
+    #[test]
+    fn pre_gd_round_trips(value in pre_gds()) {
+        assert_round_trip!(PreconditionGoalDefinitions, value);
+    }
+
+    #[test]
+    fn effects_round_trip(value in effects()) {
+        assert_round_trip!(Effects, value);
+    }
+
+    #[test]
+    fn da_gd_round_trips(value in da_gd()) {
+        assert_round_trip!(DurativeActionGoalDefinition, value);
+    }
+
+    #[test]
+    fn da_effect_round_trips(value in da_effect()) {
+        assert_round_trip!(DurativeActionEffect, value);
+    }
+
+    #[test]
+    fn structure_def_round_trips(value in structure_def()) {
+        assert_round_trip!(StructureDef, value);
+    }
+
+    #[test]
+    fn con_gd_round_trips(value in con_gd()) {
+        assert_round_trip!(ConGD, value);
+    }
+
+    #[test]
+    fn pref_con_gds_round_trip(value in pref_con_gds()) {
+        assert_round_trip!(PrefConGDs, value);
+    }
+
+    #[test]
+    fn init_element_round_trips(value in init_element()) {
+        assert_round_trip!(InitElement, value);
+    }
+
+    #[test]
+    fn metric_spec_round_trips(value in metric_spec()) {
+        assert_round_trip!(MetricSpec, value);
+    }
+
+    #[test]
+    fn domain_round_trips(value in domain()) {
+        assert_round_trip!(Domain, value);
+    }
+
+    #[test]
+    fn problem_round_trips(value in problem()) {
+        assert_round_trip!(Problem, value);
+    }
+}